@@ -5,6 +5,11 @@ pub const INCLUSION_PROGRAM: &[u8] = include_bytes!("../artifacts/inclusion-prog
 
 pub const EPOCH_CHANGE_PROGRAM: &[u8] = include_bytes!("../artifacts/epoch-change-program");
 
+pub const EVENT_INCLUSION_PROGRAM: &[u8] = include_bytes!("../artifacts/event-inclusion-program");
+
+pub const TRANSACTION_INCLUSION_PROGRAM: &[u8] =
+    include_bytes!("../artifacts/transaction-inclusion-program");
+
 pub mod bench {
     pub const SIGNATURE_VERIFICATION_PROGRAM: &[u8] =
         include_bytes!("../artifacts/benchmarks/signature-verification-program");
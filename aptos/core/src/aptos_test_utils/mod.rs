@@ -17,6 +17,7 @@ mod test {
     use crate::types::AccountAddress;
     use bls12_381::{G1Affine, G1Projective};
     use std::ops::Add;
+    use std::str::FromStr;
 
     #[test]
     fn test_serde_ledger_info_w_sig() {
@@ -211,4 +212,74 @@ mod test {
             _ => panic!("Expected epoch change"),
         }
     }
+
+    #[test]
+    fn test_ratchet_from_waypoint() {
+        let mut aptos_wrapper = AptosWrapper::new(4, 130, 95).unwrap();
+        let intern_trusted_state: TrustedState =
+            bcs::from_bytes(&bcs::to_bytes(aptos_wrapper.trusted_state()).unwrap()).unwrap();
+
+        aptos_wrapper.generate_traffic().unwrap();
+        aptos_wrapper.commit_new_epoch().unwrap();
+        aptos_wrapper.generate_traffic().unwrap();
+
+        let state_proof = aptos_wrapper
+            .new_state_proof(intern_trusted_state.version())
+            .unwrap();
+
+        let intern_epoch_change_proof: EpochChangeProof =
+            bcs::from_bytes(&bcs::to_bytes(state_proof.epoch_changes()).unwrap()).unwrap();
+        let first_epoch_change_li = intern_epoch_change_proof
+            .ledger_info_with_sigs
+            .first()
+            .unwrap();
+        let latest_li = intern_epoch_change_proof
+            .ledger_info_with_sigs
+            .last()
+            .unwrap();
+
+        // Bootstrap from a waypoint committing to the first epoch change
+        let waypoint = Waypoint::new_epoch_boundary(first_epoch_change_li.ledger_info()).unwrap();
+        waypoint
+            .verify(first_epoch_change_li.ledger_info())
+            .expect("Waypoint should match its ledger info");
+
+        let trusted_state = TrustedState::from(waypoint);
+        assert_eq!(trusted_state.version(), waypoint.version());
+        assert_eq!(trusted_state.epoch(), None);
+
+        match trusted_state
+            .verify_and_ratchet_inner(&intern_epoch_change_proof)
+            .expect("Failed to ratchet from waypoint")
+        {
+            Epoch {
+                new_state,
+                latest_epoch_change_li,
+            } => {
+                assert_eq!(latest_epoch_change_li, latest_li);
+                match new_state {
+                    TrustedState::EpochState { epoch_state, .. } => {
+                        assert_eq!(
+                            latest_li.ledger_info().next_epoch_state().unwrap(),
+                            &epoch_state,
+                            "expected epoch state match after ratcheting"
+                        );
+                    }
+                    _ => panic!("Expected epoch state"),
+                }
+            }
+            _ => panic!("Expected epoch change"),
+        }
+
+        // A waypoint with the right version but another value is rejected
+        let wrong_waypoint = Waypoint::from_str(&format!(
+            "{}:{}",
+            waypoint.version(),
+            "00".repeat(crate::crypto::hash::HASH_LENGTH)
+        ))
+        .unwrap();
+        assert!(TrustedState::from(wrong_waypoint)
+            .verify_and_ratchet_inner(&intern_epoch_change_proof)
+            .is_err());
+    }
 }
@@ -6,24 +6,29 @@
 //! It is primarily used for testing purposes.
 use crate::aptos_test_utils::error::AptosError;
 use aptos_crypto::bls12381::Signature;
-use aptos_crypto::hash::{CryptoHash, TransactionAccumulatorHasher};
+use aptos_crypto::hash::{
+    CryptoHash, EventAccumulatorHasher, TransactionAccumulatorHasher, ACCUMULATOR_PLACEHOLDER_HASH,
+};
 use aptos_crypto::HashValue;
 use aptos_executor::block_executor::BlockExecutor;
 use aptos_executor_test_helpers::gen_block_id;
 use aptos_executor_test_helpers::integration_test_impl::create_db_and_executor;
 use aptos_executor_types::BlockExecutorTrait;
+use aptos_sdk::move_types::language_storage::StructTag;
 use aptos_sdk::move_types::move_resource::MoveStructType;
 use aptos_sdk::transaction_builder::aptos_stdlib::version_set_version;
 use aptos_sdk::transaction_builder::{aptos_stdlib, TransactionFactory};
 use aptos_sdk::types::{AccountKey, LocalAccount};
 use aptos_storage_interface::DbReaderWriter;
+use aptos_types::account_address::AccountAddress;
 use aptos_types::account_config::{aptos_test_root_address, AccountResource};
 use aptos_types::aggregate_signature::PartialSignatures;
 use aptos_types::block_info::BlockInfo;
 use aptos_types::block_metadata::BlockMetadata;
 use aptos_types::chain_id::ChainId;
+use aptos_types::contract_event::ContractEvent;
 use aptos_types::ledger_info::LedgerInfoWithSignatures;
-use aptos_types::proof::{AccumulatorProof, SparseMerkleProof};
+use aptos_types::proof::{AccumulatorProof, MerkleTreeInternalNode, SparseMerkleProof};
 use aptos_types::state_proof::StateProof;
 use aptos_types::state_store::state_key::StateKey;
 use aptos_types::state_store::state_value::StateValue;
@@ -44,6 +49,7 @@ use rand::prelude::SliceRandom;
 use rand::SeedableRng;
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
+use std::str::FromStr;
 
 /// Multiplier to fund accounts, so that they can interact with the chain without
 /// worrying about it.
@@ -55,6 +61,8 @@ const BALANCE_MUILTIPLIER: u64 = 1_000_000_000;
 pub struct SparseMerkleProofAssets {
     /// Proof for the account inclusion
     state_proof: SparseMerkleProof,
+    /// Account state key
+    state_key: StateKey,
     /// Account leaf key
     key: HashValue,
     /// Account state value
@@ -79,6 +87,40 @@ impl SparseMerkleProofAssets {
     }
 }
 
+/// Structure containing an `AccumulatorProof` for an event emitted by a transaction, along
+/// with the parameters to verify it.
+#[derive(Getters, Clone, Debug, Serialize, Deserialize)]
+#[getset(get = "pub")]
+pub struct EventProofAssets {
+    /// Emitted event
+    event: ContractEvent,
+    /// Index of the event among the ones emitted by the transaction
+    event_index: u64,
+    /// Proof for the event inclusion in the transaction event accumulator
+    event_proof: AccumulatorProof<EventAccumulatorHasher>,
+    /// Proof for the transaction inclusion
+    transaction_proof: AccumulatorProof<TransactionAccumulatorHasher>,
+    /// Hashed representation of the transaction
+    transaction: TransactionInfo,
+    /// Transaction version
+    transaction_version: u64,
+}
+
+/// Structure containing an `AccumulatorProof` for a committed user transaction, along with the
+/// parameters to verify it.
+#[derive(Getters, Clone, Debug, Serialize, Deserialize)]
+#[getset(get = "pub")]
+pub struct TransactionProofAssets {
+    /// Committed user transaction
+    user_transaction: Transaction,
+    /// Proof for the transaction inclusion
+    transaction_proof: AccumulatorProof<TransactionAccumulatorHasher>,
+    /// Hashed representation of the transaction
+    transaction: TransactionInfo,
+    /// Transaction version
+    transaction_version: u64,
+}
+
 /// Wrapper around the Aptos execution layer for testing purposes.
 ///
 /// This struct provides methods for creating a simulated Aptos blockchain,
@@ -510,16 +552,88 @@ impl AptosWrapper {
         &self,
         account_idx: usize,
     ) -> Result<SparseMerkleProofAssets, AptosError> {
-        // Create a state key to get the info
-        let account_0_resource_path = StateKey::resource(
-            &self
-                .accounts()
+        self.get_latest_proof_address(
+            self.accounts()
                 .get(account_idx)
                 .ok_or(AptosError::UnexpectedNone("get accounts".into()))?
                 .address(),
-            &AccountResource::struct_tag(),
         )
-        .map_err(|e| AptosError::Internal { source: e.into() })?;
+    }
+
+    /// Returns a `SparseMerkleProofAssets` proving that there is no account at an address that
+    /// has never been used on chain.
+    ///
+    /// # Returns
+    ///
+    /// * `SparseMerkleProofAssets` - The `SparseMerkleProofAssets` for the absent account.
+    pub fn get_latest_proof_absent_account(&self) -> Result<SparseMerkleProofAssets, AptosError> {
+        self.get_latest_proof_address(AccountAddress::new([u8::MAX; AccountAddress::LENGTH]))
+    }
+
+    /// Returns a `SparseMerkleProofAssets` for the Aptos coin store resource of a specified account.
+    ///
+    /// # Arguments
+    ///
+    /// * `account_idx` - The index of the account for which to get the `SparseMerkleProofAssets`.
+    ///
+    /// # Returns
+    ///
+    /// * `SparseMerkleProofAssets` - The `SparseMerkleProofAssets` for the coin store of the account.
+    pub fn get_latest_proof_coin_store(
+        &self,
+        account_idx: usize,
+    ) -> Result<SparseMerkleProofAssets, AptosError> {
+        let coin_store_tag =
+            StructTag::from_str("0x1::coin::CoinStore<0x1::aptos_coin::AptosCoin>")
+                .map_err(|e| AptosError::Internal { source: e.into() })?;
+
+        self.get_latest_proof_resource(
+            self.accounts()
+                .get(account_idx)
+                .ok_or(AptosError::UnexpectedNone("get accounts".into()))?
+                .address(),
+            &coin_store_tag,
+        )
+    }
+
+    /// Returns a `SparseMerkleProofAssets` for the account resource at a specified address. The
+    /// address does not have to exist on chain, in which case the proof is a non-inclusion proof
+    /// and the state value is `None`.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - The address of the account for which to get the `SparseMerkleProofAssets`.
+    ///
+    /// # Returns
+    ///
+    /// * `SparseMerkleProofAssets` - The `SparseMerkleProofAssets` for the specified address.
+    pub fn get_latest_proof_address(
+        &self,
+        address: AccountAddress,
+    ) -> Result<SparseMerkleProofAssets, AptosError> {
+        self.get_latest_proof_resource(address, &AccountResource::struct_tag())
+    }
+
+    /// Returns a `SparseMerkleProofAssets` for a resource at a specified address. The resource
+    /// does not have to exist on chain, in which case the proof is a non-inclusion proof and the
+    /// state value is `None`.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - The address of the account holding the resource.
+    /// * `struct_tag` - The type of the resource.
+    ///
+    /// # Returns
+    ///
+    /// * `SparseMerkleProofAssets` - The `SparseMerkleProofAssets` for the specified resource.
+    pub fn get_latest_proof_resource(
+        &self,
+        address: AccountAddress,
+        struct_tag: &StructTag,
+    ) -> Result<SparseMerkleProofAssets, AptosError> {
+        // Create a state key to get the info
+        let account_0_resource_path = StateKey::resource(&address, struct_tag)
+            .map_err(|e| AptosError::Internal { source: e.into() })?;
 
         // Get the state proof for the current version
         let (state_value, state_proof) = self
@@ -546,6 +660,7 @@ impl AptosWrapper {
         Ok(SparseMerkleProofAssets {
             state_proof,
             key: account_0_resource_path.hash(),
+            state_key: account_0_resource_path,
             state_value,
             root_hash: txn_info
                 .state_checkpoint_hash()
@@ -557,6 +672,119 @@ impl AptosWrapper {
             transaction_version,
         })
     }
+
+    /// Returns a `TransactionProofAssets` for the latest user transaction.
+    ///
+    /// # Returns
+    ///
+    /// * `TransactionProofAssets` - The `TransactionProofAssets` for the latest user transaction.
+    ///
+    /// # Note
+    ///
+    /// The latest transaction of a block is its state checkpoint. The user transaction is thus
+    /// taken right before it.
+    pub fn get_latest_proof_transaction(&self) -> Result<TransactionProofAssets, AptosError> {
+        let transaction_version = *self.current_version() - 1;
+
+        // Get the transaction with proof for the previous version
+        let txn_w_proof = self
+            .db()
+            .reader
+            .get_transaction_by_version(transaction_version, *self.current_version(), false)
+            .map_err(|e| AptosError::Internal { source: e.into() })?;
+
+        Ok(TransactionProofAssets {
+            user_transaction: txn_w_proof.transaction,
+            transaction_proof: txn_w_proof.proof.ledger_info_to_transaction_info_proof,
+            transaction: txn_w_proof.proof.transaction_info,
+            transaction_version,
+        })
+    }
+
+    /// Returns an `EventProofAssets` for an event emitted by the latest user transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `event_idx` - The index of the event among the ones emitted by the transaction.
+    ///
+    /// # Returns
+    ///
+    /// * `EventProofAssets` - The `EventProofAssets` for the specified event.
+    ///
+    /// # Note
+    ///
+    /// The latest transaction of a block is its state checkpoint, which emits no event. The
+    /// event is thus taken from the transaction right before it.
+    pub fn get_latest_proof_event(&self, event_idx: usize) -> Result<EventProofAssets, AptosError> {
+        let transaction_version = *self.current_version() - 1;
+
+        // Get the transaction with its events and proof for the previous version
+        let txn_w_proof = self
+            .db()
+            .reader
+            .get_transaction_by_version(transaction_version, *self.current_version(), true)
+            .map_err(|e| AptosError::Internal { source: e.into() })?;
+
+        let events = txn_w_proof
+            .events
+            .ok_or(AptosError::UnexpectedNone("events".to_string()))?;
+        let event = events
+            .get(event_idx)
+            .ok_or(AptosError::UnexpectedNone("get event".to_string()))?
+            .clone();
+        let event_hashes = events.iter().map(CryptoHash::hash).collect::<Vec<_>>();
+
+        Ok(EventProofAssets {
+            event,
+            event_index: event_idx as u64,
+            event_proof: AccumulatorProof::new(event_accumulator_siblings(
+                &event_hashes,
+                event_idx,
+            )),
+            transaction_proof: txn_w_proof.proof.ledger_info_to_transaction_info_proof,
+            transaction: txn_w_proof.proof.transaction_info,
+            transaction_version,
+        })
+    }
+}
+
+/// Computes the siblings of a leaf in the in-memory event accumulator built from the given
+/// event hashes, ordered from the bottom level to the root level.
+///
+/// # Arguments
+///
+/// * `event_hashes` - The hashes of all the events emitted by a transaction.
+/// * `event_idx` - The index of the event for which to compute the siblings.
+///
+/// # Returns
+///
+/// * `Vec<HashValue>` - The siblings of the event in the accumulator.
+fn event_accumulator_siblings(event_hashes: &[HashValue], mut event_idx: usize) -> Vec<HashValue> {
+    let mut level = event_hashes.to_vec();
+    level.resize(
+        event_hashes.len().next_power_of_two(),
+        *ACCUMULATOR_PLACEHOLDER_HASH,
+    );
+
+    let mut siblings = vec![];
+    while level.len() > 1 {
+        siblings.push(level[event_idx ^ 1]);
+        level = level
+            .chunks(2)
+            .map(|pair| {
+                if pair[0] == *ACCUMULATOR_PLACEHOLDER_HASH
+                    && pair[1] == *ACCUMULATOR_PLACEHOLDER_HASH
+                {
+                    *ACCUMULATOR_PLACEHOLDER_HASH
+                } else {
+                    MerkleTreeInternalNode::<EventAccumulatorHasher>::new(pair[0], pair[1]).hash()
+                }
+            })
+            .collect();
+        event_idx /= 2;
+    }
+
+    siblings
 }
 
 /// Generates a specified number of local accounts.
@@ -597,6 +825,29 @@ fn test_aptos_wrapper() {
     aptos_wrapper.generate_traffic().unwrap();
     assert_eq!(aptos_wrapper.trusted_state().version(), 22);
 
+    // Get the transaction proof for the latest user transaction
+    let transaction_proof_assets = aptos_wrapper.get_latest_proof_transaction().unwrap();
+    assert!(matches!(
+        transaction_proof_assets.user_transaction(),
+        UserTransaction(_)
+    ));
+    assert_eq!(
+        transaction_proof_assets.transaction().transaction_hash(),
+        transaction_proof_assets.user_transaction().hash()
+    );
+    assert!(transaction_proof_assets.transaction().status().is_success());
+
+    // Get the event proof for the latest user transaction
+    let event_proof_assets = aptos_wrapper.get_latest_proof_event(0).unwrap();
+    event_proof_assets
+        .event_proof()
+        .verify(
+            event_proof_assets.transaction().event_root_hash(),
+            event_proof_assets.event().hash(),
+            *event_proof_assets.event_index(),
+        )
+        .unwrap();
+
     assert_eq!(*aptos_wrapper.current_epoch(), 1);
     assert_eq!(*aptos_wrapper.major_version(), 100);
     assert_eq!(*aptos_wrapper.current_round(), 2);
@@ -629,4 +880,15 @@ fn test_multiple_signers() {
 
     aptos_wrapper.generate_traffic().unwrap();
     assert_eq!(aptos_wrapper.trusted_state().version(), 22);
+
+    // Get the event proof for the latest user transaction
+    let event_proof_assets = aptos_wrapper.get_latest_proof_event(0).unwrap();
+    event_proof_assets
+        .event_proof()
+        .verify(
+            event_proof_assets.transaction().event_root_hash(),
+            event_proof_assets.event().hash(),
+            *event_proof_assets.event_index(),
+        )
+        .unwrap();
 }
@@ -143,6 +143,22 @@ impl HashValue {
     pub fn to_vec(&self) -> Vec<u8> {
         self.hash.to_vec()
     }
+
+    /// Returns the length of the common prefix of the bits of this `HashValue` and another one.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The `HashValue` to compare with.
+    ///
+    /// # Returns
+    ///
+    /// The number of leading bits shared by both `HashValue`s.
+    pub fn common_prefix_bits_len(&self, other: HashValue) -> usize {
+        self.iter_bits()
+            .zip(other.iter_bits())
+            .take_while(|(x, y)| x == y)
+            .count()
+    }
 }
 
 impl AsRef<[u8; HASH_LENGTH]> for HashValue {
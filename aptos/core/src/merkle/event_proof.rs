@@ -0,0 +1,186 @@
+// Copyright (c) Argument Computer Corporation
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Event Proof Module
+//!
+//! This module provides the structures and functions
+//! necessary for handling Event Accumulator Proofs
+//! from the Aptos chain.
+//!
+//! ## Usage
+//!
+//! The `EventAccumulatorProof` structure is
+//! used to authenticate whether a given event was emitted
+//! by a transaction or not. The events emitted by a transaction
+//! are accumulated in a Merkle Tree whose root hash is the
+//! `event_root_hash` of the `TransactionInfo`. The proof contains
+//! a list of sibling nodes, ordered from the bottom level to the
+//! root level of the Merkle Tree.
+
+// SPDX-License-Identifier: Apache-2.0
+use crate::crypto::hash::{HashValue, HASH_LENGTH};
+use crate::merkle::node::{accumulator_root_hash, EventAccumulatorHasher};
+use crate::merkle::transaction_proof::MAX_ACCUMULATOR_PROOF_DEPTH;
+use crate::serde_error;
+use crate::types::error::TypesError;
+use crate::types::utils::{read_leb128, write_leb128};
+use anyhow::{ensure, Result};
+use bytes::{Buf, BufMut, BytesMut};
+use serde::{Deserialize, Serialize};
+
+/// A proof that can be used authenticate an event in the
+/// event accumulator of a transaction given its trusted root hash.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EventAccumulatorProof {
+    /// All siblings in this proof, including the default ones. Siblings
+    /// are ordered from the bottom level to the root level.
+    siblings: Vec<HashValue>,
+}
+
+impl EventAccumulatorProof {
+    /// Verifies an event whose hash is `event_hash` and index is `event_index` exists in
+    /// the accumulator whose root hash is `expected_root_hash` using the provided proof.
+    ///
+    /// # Arguments
+    ///
+    /// * `expected_root_hash: HashValue` - The expected root hash of the Event Accumulator.
+    /// * `event_hash: HashValue` - The hash of the event to verify.
+    /// * `event_index: u64` - The index of the event among the ones emitted by the transaction.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok` if the event exists in the Event Accumulator and the proof is valid, and `Err` otherwise.
+    pub fn verify(
+        &self,
+        expected_root_hash: HashValue,
+        event_hash: HashValue,
+        event_index: u64,
+    ) -> Result<()> {
+        ensure!(
+            self.siblings.len() <= MAX_ACCUMULATOR_PROOF_DEPTH,
+            "Accumulator proof has more than {} ({}) siblings.",
+            MAX_ACCUMULATOR_PROOF_DEPTH,
+            self.siblings.len()
+        );
+
+        let actual_root_hash = accumulator_root_hash::<EventAccumulatorHasher>(
+            event_hash,
+            event_index,
+            &self.siblings,
+        );
+        ensure!(
+            actual_root_hash == expected_root_hash,
+            "{}: Root hashes do not match.",
+            "EventAccumulatorProof",
+        );
+
+        Ok(())
+    }
+
+    /// Converts the `EventAccumulatorProof` to a byte vector.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<u8>` representing the `EventAccumulatorProof`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = BytesMut::new();
+        bytes.put_slice(&write_leb128(self.siblings.len() as u64));
+        for sibling in &self.siblings {
+            bytes.put_slice(sibling.as_ref());
+        }
+        bytes.to_vec()
+    }
+
+    /// Creates an `EventAccumulatorProof` from a byte slice.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes: &[u8]` - A byte slice from which to create the `EventAccumulatorProof`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok` if the `EventAccumulatorProof` could be successfully created, and `Err` otherwise.
+    pub fn from_bytes(bytes: &[u8]) -> std::result::Result<Self, TypesError> {
+        let mut buf = BytesMut::from(bytes);
+        let (len, read_bytes) = read_leb128(&buf)
+            .map_err(|_| serde_error!("EventAccumulatorProof", "Not enough data for length"))?;
+        buf.advance(read_bytes);
+        let mut siblings = Vec::with_capacity(len as usize);
+        for _ in 0..len {
+            if buf.remaining() < HASH_LENGTH {
+                return Err(serde_error!(
+                    "EventAccumulatorProof",
+                    "Not enough bytes to read HashValue"
+                ));
+            }
+            let mut hash_value = [0u8; HASH_LENGTH];
+            buf.copy_to_slice(&mut hash_value);
+            siblings.push(
+                HashValue::from_slice(hash_value)
+                    .map_err(|e| serde_error!("EventAccumulatorProof", e))?,
+            );
+        }
+
+        if buf.remaining() != 0 {
+            return Err(serde_error!(
+                "EventAccumulatorProof",
+                "Unexpected data after completing deserialization"
+            ));
+        }
+
+        Ok(Self { siblings })
+    }
+}
+
+#[cfg(all(test, feature = "aptos"))]
+mod test {
+    #[test]
+    fn test_bytes_conversion_event_accumulator_proof() {
+        use crate::aptos_test_utils::wrapper::AptosWrapper;
+        use crate::merkle::event_proof::EventAccumulatorProof;
+
+        let mut aptos_wrapper = AptosWrapper::new(40, 1, 1).unwrap();
+        aptos_wrapper.generate_traffic().unwrap();
+
+        let proof_assets = aptos_wrapper.get_latest_proof_event(0).unwrap();
+
+        let aptos_proof = proof_assets.event_proof();
+        let aptos_proof_bytes = bcs::to_bytes(aptos_proof).unwrap();
+
+        let lc_event_proof = EventAccumulatorProof::from_bytes(&aptos_proof_bytes).unwrap();
+
+        let lc_event_proof_bytes = lc_event_proof.to_bytes();
+
+        assert_eq!(aptos_proof_bytes, lc_event_proof_bytes);
+    }
+
+    #[test]
+    fn test_event_accumulator() {
+        use crate::aptos_test_utils::wrapper::AptosWrapper;
+        use crate::crypto::hash::HashValue;
+        use crate::merkle::event_proof::EventAccumulatorProof;
+        use aptos_crypto::hash::CryptoHash;
+
+        let mut aptos_wrapper = AptosWrapper::new(40, 1, 1).unwrap();
+        aptos_wrapper.generate_traffic().unwrap();
+
+        let proof_assets = aptos_wrapper.get_latest_proof_event(0).unwrap();
+
+        let expected_root_hash =
+            HashValue::from_slice(proof_assets.transaction().event_root_hash().as_ref()).unwrap();
+        let event_hash = HashValue::from_slice(proof_assets.event().hash().as_ref()).unwrap();
+        let event_index = *proof_assets.event_index();
+        let proof =
+            EventAccumulatorProof::from_bytes(&bcs::to_bytes(proof_assets.event_proof()).unwrap())
+                .unwrap();
+
+        proof
+            .verify(expected_root_hash, event_hash, event_index)
+            .unwrap();
+
+        // The proof does not hold for another event
+        assert!(proof
+            .verify(expected_root_hash, HashValue::default(), event_index)
+            .is_err());
+    }
+}
@@ -257,6 +257,63 @@ impl NodeHasher for TransactionAccumulatorHasher {
     }
 }
 
+/// `EventAccumulatorHasher` is a structure representing
+/// the hasher for event accumulator in order to prove
+/// an event inclusion in a `TransactionInfo`.
+#[derive(Clone, Debug, Default)]
+pub struct EventAccumulatorHasher {}
+
+impl NodeHasher for EventAccumulatorHasher {
+    /// Returns the prefix used for hashing in the context of
+    /// an Event Accumulator.
+    ///
+    /// # Returns
+    ///
+    /// A static string slice representing the prefix.
+    fn prefix(&self) -> &'static str {
+        "EventAccumulator"
+    }
+}
+
+/// Computes the root hash of a Merkle accumulator from an element
+/// and its siblings, ordered from the bottom level to the root level.
+///
+/// # Arguments
+///
+/// * `element_hash: HashValue` - The hash of the element.
+/// * `element_index: u64` - The index of the element in the accumulator.
+/// * `siblings: &[HashValue]` - The siblings of the element.
+///
+/// # Returns
+///
+/// The root hash of the accumulator.
+pub(crate) fn accumulator_root_hash<H: NodeHasher + Default>(
+    element_hash: HashValue,
+    element_index: u64,
+    siblings: &[HashValue],
+) -> HashValue {
+    siblings
+        .iter()
+        .fold(
+            (element_hash, element_index),
+            // `index` denotes the index of the ancestor of the element at the current level.
+            |(hash, index), sibling_hash| {
+                (
+                    if index % 2 == 0 {
+                        // the current node is a left child.
+                        MerkleInternalNode::<H>::new(hash, *sibling_hash).hash()
+                    } else {
+                        // the current node is a right child.
+                        MerkleInternalNode::<H>::new(*sibling_hash, hash).hash()
+                    },
+                    // The index of the parent at its level.
+                    index / 2,
+                )
+            },
+        )
+        .0
+}
+
 #[cfg(all(test, feature = "aptos"))]
 mod test {
     #[test]
@@ -15,10 +15,11 @@
 //! root level of the Sparse Merkle Tree.
 //!
 //! The `SparseMerkleProof` structure provides methods
-//! for verifying the proof (`verify_by_hash`), converting
+//! for verifying the proof (`verify_by_hash` for existing
+//! elements, `verify_non_inclusion` for absent ones), converting
 //! the proof to bytes (`to_bytes`), and creating a proof
 //! from bytes (`from_bytes`). These methods are used
-//! to authenticate the existence of a leaf in the Sparse
+//! to authenticate the existence or absence of a leaf in the Sparse
 //! Merkle Tree, serialize the proof for storage or
 //! transmission, and deserialize the proof for verification,
 //! respectively.
@@ -29,11 +30,18 @@ use crate::merkle::node::{MerkleInternalNode, SparseMerkleInternalHasher, Sparse
 use crate::serde_error;
 use crate::types::error::TypesError;
 use crate::types::utils::{read_leb128, write_leb128};
-use anyhow::{ensure, Result};
+use anyhow::{anyhow, ensure, Result};
 use bytes::{Buf, BufMut, BytesMut};
 use getset::Getters;
 use serde::{Deserialize, Serialize};
 
+/// Hash of an empty subtree in the Sparse Merkle Tree. It is the literal
+/// `SPARSE_MERKLE_PLACEHOLDER_HASH` right padded with zeroes.
+pub const SPARSE_MERKLE_PLACEHOLDER_HASH: HashValue = HashValue::new([
+    b'S', b'P', b'A', b'R', b'S', b'E', b'_', b'M', b'E', b'R', b'K', b'L', b'E', b'_', b'P', b'L',
+    b'A', b'C', b'E', b'H', b'O', b'L', b'D', b'E', b'R', b'_', b'H', b'A', b'S', b'H', 0, 0,
+]);
+
 /// `SparseMerkleProof` is a structure representing a proof
 /// in a Sparse Merkle Tree.
 ///
@@ -48,8 +56,10 @@ pub struct SparseMerkleProof {
     ///     - If this is `Some(leaf_node)`
     ///         - If `leaf_node.key` equals requested key, this is an inclusion proof and
     ///           `leaf_node.value_hash` equals the hash of the corresponding account blob.
-    ///         - Otherwise this is a non-inclusion proof, which we do not handle.
-    ///     - If this is `None`, this is also a non-inclusion proof, which we do not handle in the light client.
+    ///         - Otherwise this is a non-inclusion proof: the leaf occupies the path of the
+    ///           requested key, which is therefore absent.
+    ///     - If this is `None`, this is also a non-inclusion proof: the requested key would be in
+    ///       an empty subtree.
     leaf: Option<SparseMerkleLeafNode>,
 
     /// All siblings in this proof, including the default ones. Siblings are ordered from the bottom
@@ -73,22 +83,16 @@ impl SparseMerkleProof {
     /// A `Result` which is `Ok` if the element exists in
     /// the Sparse Merkle Tree and the proof is valid, and
     /// `Err` otherwise.
-    #[allow(dead_code)]
     pub fn verify_by_hash(
         &self,
         expected_root_hash: HashValue,
         element_key: HashValue,
         element_hash: HashValue,
     ) -> Result<HashValue> {
-        ensure!(
-            self.siblings.len() <= HASH_LENGTH * 8,
-            "Sparse Merkle Tree proof has more than {} ({}) siblings.",
-            256,
-            self.siblings.len(),
-        );
-
         // Proof need to contain leaf if proof of inclusion
-        let leaf = self.leaf.unwrap();
+        let leaf = self
+            .leaf
+            .ok_or_else(|| anyhow!("Expected inclusion proof. Found non-inclusion proof."))?;
         ensure!(
             element_key == leaf.key(),
             "Keys do not match. Key in proof: {:x}. Expected key: {:x}. \
@@ -105,6 +109,81 @@ impl SparseMerkleProof {
             element_key
         );
 
+        self.verify_root(expected_root_hash, element_key)
+    }
+
+    /// Verifies that no element whose key is `element_key`
+    /// exists in the Sparse Merkle Tree using the provided proof.
+    ///
+    /// The proof either ends on an empty subtree, or on a leaf
+    /// with another key that is the only leaf of the subtree
+    /// in which `element_key` would be.
+    ///
+    /// # Arguments
+    ///
+    /// * `expected_root_hash: HashValue` - The expected root hash of the Sparse Merkle Tree.
+    /// * `element_key: HashValue` - The key of the element to verify.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok` if the element does not exist
+    /// in the Sparse Merkle Tree and the proof is valid, and
+    /// `Err` otherwise.
+    pub fn verify_non_inclusion(
+        &self,
+        expected_root_hash: HashValue,
+        element_key: HashValue,
+    ) -> Result<HashValue> {
+        if let Some(leaf) = self.leaf {
+            // Another leaf occupies the path of the element
+            ensure!(
+                element_key != leaf.key(),
+                "Expected non-inclusion proof, but key exists in proof. Key: {:x}.",
+                element_key
+            );
+            ensure!(
+                element_key.common_prefix_bits_len(leaf.key()) >= self.siblings.len(),
+                "Key would not have ended up in the subtree where the provided key in proof \
+                 is the only existing key, if it existed. So this is not a valid \
+                 non-inclusion proof. Key: {:x}. Key in proof: {:x}.",
+                element_key,
+                leaf.key()
+            );
+        }
+
+        self.verify_root(expected_root_hash, element_key)
+    }
+
+    /// Reconstructs the root hash of the Sparse Merkle Tree from
+    /// the leaf of the proof, or the empty subtree placeholder if
+    /// there is none, along the path of `element_key` and checks
+    /// it against the expected one.
+    ///
+    /// # Arguments
+    ///
+    /// * `expected_root_hash: HashValue` - The expected root hash of the Sparse Merkle Tree.
+    /// * `element_key: HashValue` - The key of the element to verify.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the reconstructed root hash if
+    /// it matches the expected one, and `Err` otherwise.
+    fn verify_root(
+        &self,
+        expected_root_hash: HashValue,
+        element_key: HashValue,
+    ) -> Result<HashValue> {
+        ensure!(
+            self.siblings.len() <= HASH_LENGTH * 8,
+            "Sparse Merkle Tree proof has more than {} ({}) siblings.",
+            256,
+            self.siblings.len(),
+        );
+
+        let leaf_hash = self
+            .leaf
+            .map_or(SPARSE_MERKLE_PLACEHOLDER_HASH, |leaf| leaf.hash());
+
         let reconstructed_root = self
             .siblings
             .iter()
@@ -115,7 +194,7 @@ impl SparseMerkleProof {
                     .rev()
                     .skip(HASH_LENGTH * 8 - self.siblings.len()),
             )
-            .fold(leaf.hash(), accumulator_update);
+            .fold(leaf_hash, accumulator_update);
 
         ensure!(
             reconstructed_root == expected_root_hash,
@@ -231,7 +310,7 @@ mod test {
     use crate::merkle::node::{
         MerkleInternalNode, SparseMerkleInternalHasher, SparseMerkleLeafNode,
     };
-    use crate::merkle::sparse_proof::SparseMerkleProof;
+    use crate::merkle::sparse_proof::{SparseMerkleProof, SPARSE_MERKLE_PLACEHOLDER_HASH};
 
     #[test]
     fn test_verify_proof_simple() {
@@ -283,6 +362,91 @@ mod test {
             .unwrap();
     }
 
+    /// Computes the root hash of a Sparse Merkle Tree from a leaf hash along the path of `key`.
+    fn compute_root(leaf_hash: HashValue, key: HashValue, siblings: &[HashValue]) -> HashValue {
+        siblings
+            .iter()
+            .rev()
+            .zip(key.iter_bits().rev().skip(HASH_LENGTH * 8 - siblings.len()))
+            .fold(leaf_hash, |acc_hash, (sibling_hash, bit)| {
+                if bit {
+                    MerkleInternalNode::<SparseMerkleInternalHasher>::new(*sibling_hash, acc_hash)
+                        .hash()
+                } else {
+                    MerkleInternalNode::<SparseMerkleInternalHasher>::new(acc_hash, *sibling_hash)
+                        .hash()
+                }
+            })
+    }
+
+    /// Creates a `HashValue` with the given first byte, all other bytes being zeroes.
+    fn key_with_first_byte(first_byte: u8) -> HashValue {
+        let mut key = [0; HASH_LENGTH];
+        key[0] = first_byte;
+        HashValue::new(key)
+    }
+
+    #[test]
+    fn test_verify_non_inclusion_other_leaf() {
+        let siblings = vec![
+            HashValue::new(hash_data(&[], vec!["b".as_bytes()])),
+            HashValue::new(hash_data(&[], vec!["cd".as_bytes()])),
+        ];
+
+        // Leaf with key 0b1000_0000...
+        let leaf_node = SparseMerkleLeafNode::new(
+            key_with_first_byte(0b1000_0000),
+            HashValue::new(hash_data(&[], vec!["a".as_bytes()])),
+        );
+        let proof = SparseMerkleProof {
+            leaf: Some(leaf_node),
+            siblings: siblings.clone(),
+        };
+        let root_hash = compute_root(leaf_node.hash(), leaf_node.key(), &siblings);
+
+        // Key sharing the path of the leaf in the tree, but not the leaf key itself
+        proof
+            .verify_non_inclusion(root_hash, key_with_first_byte(0b1010_0000))
+            .unwrap();
+
+        // The leaf key is included in the tree
+        assert!(proof
+            .verify_non_inclusion(root_hash, leaf_node.key())
+            .is_err());
+
+        // Key that would be in another subtree than the one of the leaf
+        assert!(proof
+            .verify_non_inclusion(root_hash, key_with_first_byte(0b1100_0000))
+            .is_err());
+    }
+
+    #[test]
+    fn test_verify_non_inclusion_empty_subtree() {
+        let siblings = vec![
+            HashValue::new(hash_data(&[], vec!["b".as_bytes()])),
+            HashValue::new(hash_data(&[], vec!["cd".as_bytes()])),
+        ];
+
+        let proof = SparseMerkleProof {
+            leaf: None,
+            siblings: siblings.clone(),
+        };
+        let key = key_with_first_byte(0b1000_0000);
+        let root_hash = compute_root(SPARSE_MERKLE_PLACEHOLDER_HASH, key, &siblings);
+
+        proof.verify_non_inclusion(root_hash, key).unwrap();
+
+        // Wrong root hash
+        assert!(proof
+            .verify_non_inclusion(HashValue::new([1; HASH_LENGTH]), key)
+            .is_err());
+
+        // An inclusion proof can not be verified without a leaf
+        assert!(proof
+            .verify_by_hash(root_hash, key, HashValue::new([1; HASH_LENGTH]))
+            .is_err());
+    }
+
     #[cfg(feature = "aptos")]
     #[test]
     fn test_aptos_data() {
@@ -313,6 +477,26 @@ mod test {
         );
     }
 
+    #[cfg(feature = "aptos")]
+    #[test]
+    fn test_aptos_data_non_inclusion() {
+        use crate::aptos_test_utils::wrapper::AptosWrapper;
+
+        let mut aptos_wrapper = AptosWrapper::new(40, 1, 1).unwrap();
+        aptos_wrapper.generate_traffic().unwrap();
+
+        let proof_assets = aptos_wrapper.get_latest_proof_absent_account().unwrap();
+        assert!(proof_assets.state_value().is_none());
+
+        let intern_proof =
+            SparseMerkleProof::from_bytes(&bcs::to_bytes(proof_assets.state_proof()).unwrap())
+                .unwrap();
+        let key = HashValue::from_slice(proof_assets.key().to_vec()).unwrap();
+        let root_hash = HashValue::from_slice(proof_assets.root_hash().to_vec()).unwrap();
+
+        intern_proof.verify_non_inclusion(root_hash, key).unwrap();
+    }
+
     #[cfg(feature = "aptos")]
     #[test]
     fn test_bytes_conversion_sparse_merkle_proof() {
@@ -15,9 +15,8 @@
 //! ordered from the bottom level to the root level of the Merkle Tree.
 
 // SPDX-License-Identifier: Apache-2.0
-use crate::crypto::hash::{CryptoHash, HashValue, HASH_LENGTH};
-use crate::merkle::node::MerkleInternalNode;
-use crate::merkle::node::TransactionAccumulatorHasher;
+use crate::crypto::hash::{HashValue, HASH_LENGTH};
+use crate::merkle::node::{accumulator_root_hash, TransactionAccumulatorHasher};
 use crate::serde_error;
 use crate::types::error::TypesError;
 use crate::types::utils::{read_leb128, write_leb128};
@@ -62,35 +61,11 @@ impl TransactionAccumulatorProof {
             self.siblings.len()
         );
 
-        let actual_root_hash = self
-            .siblings
-            .iter()
-            .fold(
-                (element_hash, element_index),
-                // `index` denotes the index of the ancestor of the element at the current level.
-                |(hash, index), sibling_hash| {
-                    (
-                        if index % 2 == 0 {
-                            // the current node is a left child.
-                            MerkleInternalNode::<TransactionAccumulatorHasher>::new(
-                                hash,
-                                *sibling_hash,
-                            )
-                            .hash()
-                        } else {
-                            // the current node is a right child.
-                            MerkleInternalNode::<TransactionAccumulatorHasher>::new(
-                                *sibling_hash,
-                                hash,
-                            )
-                            .hash()
-                        },
-                        // The index of the parent at its level.
-                        index / 2,
-                    )
-                },
-            )
-            .0;
+        let actual_root_hash = accumulator_root_hash::<TransactionAccumulatorHasher>(
+            element_hash,
+            element_index,
+            &self.siblings,
+        );
         ensure!(
             actual_root_hash == expected_root_hash,
             "{}: Root hashes do not match.",
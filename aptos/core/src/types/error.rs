@@ -56,6 +56,10 @@ pub enum TypesError {
         expected: usize,
         actual: usize,
     },
+    #[error(
+        "Unsupported variant {variant} for {structure}, the light client might need to be updated"
+    )]
+    UnsupportedVariant { structure: String, variant: u64 },
 }
 
 /// Macro to create a `TypesError::DeserializationError` with the given structure and source.
@@ -0,0 +1,321 @@
+// Copyright (c) Argument Computer Corporation
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Event Module
+//!
+//! This module provides the `ContractEvent` structure
+//! and associated methods for handling events emitted
+//! by transactions in the Aptos Light Client.
+//!
+//! The `ContractEvent` structure represents an event
+//! emitted by a Move contract during the execution of a
+//! transaction. The events emitted by a transaction are
+//! accumulated in a Merkle Tree whose root hash is the
+//! `event_root_hash` of its `TransactionInfo`.
+
+// SPDX-License-Identifier: Apache-2.0
+use crate::crypto::hash::{hash_data, prefixed_sha3, CryptoHash, HashValue};
+use crate::serde_error;
+use crate::types::error::TypesError;
+use crate::types::language_storage::TypeTag;
+use crate::types::utils::{read_bytes, write_bytes, U64_SIZE};
+use crate::types::{AccountAddress, ACCOUNT_ADDRESS_SIZE};
+use bytes::{Buf, BufMut, BytesMut};
+use getset::{CopyGetters, Getters};
+use serde::{Deserialize, Serialize};
+
+/// `ContractEvent` is an event emitted by a Move contract
+/// during the execution of a transaction.
+///
+/// It is implemented as an enum to support both the legacy
+/// events emitted through an event handle and the module events.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum ContractEvent {
+    V1(ContractEventV1),
+    V2(ContractEventV2),
+}
+
+impl ContractEvent {
+    /// Returns the type tag of the event data.
+    ///
+    /// # Returns
+    ///
+    /// The `TypeTag` of the event data.
+    pub const fn type_tag(&self) -> &TypeTag {
+        match self {
+            ContractEvent::V1(event) => &event.type_tag,
+            ContractEvent::V2(event) => &event.type_tag,
+        }
+    }
+
+    /// Returns the BCS-serialized data of the event.
+    ///
+    /// # Returns
+    ///
+    /// The data of the event.
+    pub fn event_data(&self) -> &[u8] {
+        match self {
+            ContractEvent::V1(event) => &event.event_data,
+            ContractEvent::V2(event) => &event.event_data,
+        }
+    }
+
+    /// Returns the key of the event handle that emitted the event,
+    /// if it is a legacy event.
+    ///
+    /// # Returns
+    ///
+    /// The `EventKey` of the event, or `None` for a module event.
+    pub const fn event_key(&self) -> Option<&EventKey> {
+        match self {
+            ContractEvent::V1(event) => Some(&event.key),
+            ContractEvent::V2(_) => None,
+        }
+    }
+
+    /// Converts the `ContractEvent` to a byte vector.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<u8>` representing the `ContractEvent`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = BytesMut::new();
+        match self {
+            ContractEvent::V1(event) => {
+                bytes.put_u8(0);
+                bytes.put_slice(&event.key.to_bytes());
+                bytes.put_u64_le(event.sequence_number);
+                bytes.put_slice(&event.type_tag.to_bytes());
+                write_bytes(&mut bytes, &event.event_data);
+            }
+            ContractEvent::V2(event) => {
+                bytes.put_u8(1);
+                bytes.put_slice(&event.type_tag.to_bytes());
+                write_bytes(&mut bytes, &event.event_data);
+            }
+        }
+        bytes.to_vec()
+    }
+
+    /// Creates a `ContractEvent` from a byte slice.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes: &[u8]` - A byte slice from which to create the `ContractEvent`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok` if the `ContractEvent`
+    /// could be successfully created, and `Err` otherwise.
+    pub fn from_bytes(mut bytes: &[u8]) -> Result<Self, TypesError> {
+        if bytes.remaining() < 1 {
+            return Err(serde_error!("ContractEvent", "Not enough data for variant"));
+        }
+
+        let event = match bytes.get_u8() {
+            0 => {
+                let key =
+                    EventKey::from_bytes(bytes.chunk().get(..EVENT_KEY_SIZE).ok_or_else(
+                        || serde_error!("ContractEvent", "Not enough data for key"),
+                    )?)?;
+                bytes.advance(EVENT_KEY_SIZE);
+
+                if bytes.remaining() < U64_SIZE {
+                    return Err(serde_error!(
+                        "ContractEvent",
+                        "Not enough data for sequence number"
+                    ));
+                }
+                let sequence_number = bytes.get_u64_le();
+
+                let type_tag = TypeTag::read(&mut bytes)?;
+                let event_data = read_bytes(&mut bytes, "ContractEvent")?;
+
+                ContractEvent::V1(ContractEventV1 {
+                    key,
+                    sequence_number,
+                    type_tag,
+                    event_data,
+                })
+            }
+            1 => {
+                let type_tag = TypeTag::read(&mut bytes)?;
+                let event_data = read_bytes(&mut bytes, "ContractEvent")?;
+
+                ContractEvent::V2(ContractEventV2 {
+                    type_tag,
+                    event_data,
+                })
+            }
+            _ => return Err(serde_error!("ContractEvent", "Invalid variant")),
+        };
+
+        if bytes.remaining() != 0 {
+            return Err(serde_error!(
+                "ContractEvent",
+                "Unexpected data after completing deserialization"
+            ));
+        }
+
+        Ok(event)
+    }
+}
+
+impl CryptoHash for ContractEvent {
+    fn hash(&self) -> HashValue {
+        HashValue::new(hash_data(
+            &prefixed_sha3(b"ContractEvent"),
+            vec![&self.to_bytes()],
+        ))
+    }
+}
+
+/// `ContractEventV1` is a legacy event, emitted through
+/// an event handle.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Getters, CopyGetters)]
+pub struct ContractEventV1 {
+    /// The unique key of the event handle that emitted the event.
+    #[getset(get = "pub")]
+    key: EventKey,
+    /// The number of events emitted by the event handle before this one.
+    #[getset(get_copy = "pub")]
+    sequence_number: u64,
+    /// The type of the event data.
+    #[getset(get = "pub")]
+    type_tag: TypeTag,
+    /// The BCS-serialized event data.
+    #[getset(get = "pub")]
+    event_data: Vec<u8>,
+}
+
+/// `ContractEventV2` is a module event, only identified
+/// by its type.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Getters)]
+#[getset(get = "pub")]
+pub struct ContractEventV2 {
+    /// The type of the event data.
+    type_tag: TypeTag,
+    /// The BCS-serialized event data.
+    event_data: Vec<u8>,
+}
+
+/// Length in bytes of the serialized `EventKey`.
+pub const EVENT_KEY_SIZE: usize = U64_SIZE + ACCOUNT_ADDRESS_SIZE;
+
+/// `EventKey` uniquely identifies an event handle.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize, CopyGetters)]
+#[getset(get_copy = "pub")]
+pub struct EventKey {
+    /// The creation number of the event handle for its account.
+    creation_number: u64,
+    /// The address of the account owning the event handle.
+    account_address: AccountAddress,
+}
+
+impl EventKey {
+    /// Converts the `EventKey` to a byte vector.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<u8>` representing the `EventKey`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = BytesMut::new();
+        bytes.put_u64_le(self.creation_number);
+        bytes.put_slice(&self.account_address.to_bytes());
+        bytes.to_vec()
+    }
+
+    /// Creates an `EventKey` from a byte slice.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes: &[u8]` - A byte slice from which to create the `EventKey`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok` if the `EventKey`
+    /// could be successfully created, and `Err` otherwise.
+    pub fn from_bytes(mut bytes: &[u8]) -> Result<Self, TypesError> {
+        if bytes.remaining() != EVENT_KEY_SIZE {
+            return Err(TypesError::InvalidLength {
+                structure: "EventKey".into(),
+                expected: EVENT_KEY_SIZE,
+                actual: bytes.remaining(),
+            });
+        }
+
+        let creation_number = bytes.get_u64_le();
+        let account_address = AccountAddress::from_bytes(bytes)?;
+
+        Ok(Self {
+            creation_number,
+            account_address,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::types::language_storage::StructTag;
+
+    #[test]
+    fn test_bytes_conversion_contract_event() {
+        let type_tag = TypeTag::Struct(Box::new(StructTag::new(
+            AccountAddress::new([1; ACCOUNT_ADDRESS_SIZE]),
+            "coin",
+            "DepositEvent",
+            vec![TypeTag::Vector(Box::new(TypeTag::U8)), TypeTag::U256],
+        )));
+
+        let events = [
+            ContractEvent::V1(ContractEventV1 {
+                key: EventKey {
+                    creation_number: 2,
+                    account_address: AccountAddress::new([3; ACCOUNT_ADDRESS_SIZE]),
+                },
+                sequence_number: 4,
+                type_tag: type_tag.clone(),
+                event_data: vec![5; 8],
+            }),
+            ContractEvent::V2(ContractEventV2 {
+                type_tag,
+                event_data: vec![6; 200],
+            }),
+        ];
+
+        for event in events {
+            let bytes = bcs::to_bytes(&event).unwrap();
+            assert_eq!(event.to_bytes(), bytes);
+            assert_eq!(ContractEvent::from_bytes(&bytes).unwrap(), event);
+
+            // Truncated or extended data is rejected
+            assert!(ContractEvent::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+            assert!(ContractEvent::from_bytes(&[bytes.as_slice(), &[0]].concat()).is_err());
+        }
+    }
+
+    #[cfg(feature = "aptos")]
+    #[test]
+    fn test_hash_contract_event() {
+        use crate::aptos_test_utils::wrapper::AptosWrapper;
+        use crate::crypto::hash::CryptoHash as LcCryptoHash;
+        use aptos_crypto::hash::CryptoHash as AptosCryptoHash;
+
+        let mut aptos_wrapper = AptosWrapper::new(40, 1, 1).unwrap();
+        aptos_wrapper.generate_traffic().unwrap();
+
+        let proof_assets = aptos_wrapper.get_latest_proof_event(0).unwrap();
+
+        let aptos_event = proof_assets.event();
+        let aptos_event_bytes = bcs::to_bytes(aptos_event).unwrap();
+
+        let lc_event = ContractEvent::from_bytes(&aptos_event_bytes).unwrap();
+
+        assert_eq!(lc_event.to_bytes(), aptos_event_bytes);
+        assert_eq!(
+            LcCryptoHash::hash(&lc_event).as_ref(),
+            AptosCryptoHash::hash(aptos_event).as_ref()
+        );
+    }
+}
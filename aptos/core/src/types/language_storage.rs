@@ -0,0 +1,375 @@
+// Copyright (c) Argument Computer Corporation
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Language Storage Module
+//!
+//! This module provides the `TypeTag`, `StructTag` and `ModuleId`
+//! structures and associated methods for handling the
+//! representation of Move types and modules in the Aptos Light Client.
+//!
+//! Those types are used to identify the data of an event, the
+//! resources stored in the state of the chain, as well as the
+//! module that aborted the execution of a transaction.
+
+// SPDX-License-Identifier: Apache-2.0
+use crate::serde_error;
+use crate::types::error::TypesError;
+use crate::types::utils::{read_bytes, read_leb128, write_bytes, write_leb128};
+use crate::types::{AccountAddress, ACCOUNT_ADDRESS_SIZE};
+use bytes::{Buf, BufMut, BytesMut};
+use getset::Getters;
+use serde::{Deserialize, Serialize};
+
+/// Maximum nesting depth of a `TypeTag`, as enforced by the Move VM.
+pub const MAX_TYPE_TAG_NESTING: u8 = 8;
+
+/// `TypeTag` is the representation of a Move type.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum TypeTag {
+    Bool,
+    U8,
+    U64,
+    U128,
+    Address,
+    Signer,
+    Vector(Box<TypeTag>),
+    Struct(Box<StructTag>),
+    U16,
+    U32,
+    U256,
+}
+
+impl TypeTag {
+    /// Converts the `TypeTag` to a byte vector.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<u8>` representing the `TypeTag`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = BytesMut::new();
+        match self {
+            TypeTag::Bool => bytes.put_u8(0),
+            TypeTag::U8 => bytes.put_u8(1),
+            TypeTag::U64 => bytes.put_u8(2),
+            TypeTag::U128 => bytes.put_u8(3),
+            TypeTag::Address => bytes.put_u8(4),
+            TypeTag::Signer => bytes.put_u8(5),
+            TypeTag::Vector(type_tag) => {
+                bytes.put_u8(6);
+                bytes.put_slice(&type_tag.to_bytes());
+            }
+            TypeTag::Struct(struct_tag) => {
+                bytes.put_u8(7);
+                bytes.put_slice(&struct_tag.to_bytes());
+            }
+            TypeTag::U16 => bytes.put_u8(8),
+            TypeTag::U32 => bytes.put_u8(9),
+            TypeTag::U256 => bytes.put_u8(10),
+        }
+        bytes.to_vec()
+    }
+
+    /// Creates a `TypeTag` from a byte slice.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes: &[u8]` - A byte slice from which to create the `TypeTag`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok` if the `TypeTag`
+    /// could be successfully created, and `Err` otherwise.
+    pub fn from_bytes(mut bytes: &[u8]) -> Result<Self, TypesError> {
+        let type_tag = Self::read(&mut bytes)?;
+
+        if bytes.remaining() != 0 {
+            return Err(serde_error!(
+                "TypeTag",
+                "Unexpected data after completing deserialization"
+            ));
+        }
+
+        Ok(type_tag)
+    }
+
+    /// Reads a `TypeTag` at the start of a buffer, advancing it past the read bytes.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes: &mut &[u8]` - The buffer to read from.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok` if a `TypeTag` could be read, and `Err` otherwise.
+    pub(crate) fn read(bytes: &mut &[u8]) -> Result<Self, TypesError> {
+        Self::read_nested(bytes, 0)
+    }
+
+    /// Reads a nested `TypeTag` at the start of a buffer, advancing it past the read bytes.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes: &mut &[u8]` - The buffer to read from.
+    /// * `depth: u8` - The nesting depth of the `TypeTag` being read.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok` if a `TypeTag` could be read, and `Err` otherwise.
+    fn read_nested(bytes: &mut &[u8], depth: u8) -> Result<Self, TypesError> {
+        if depth > MAX_TYPE_TAG_NESTING {
+            return Err(serde_error!("TypeTag", "Exceeded maximum nesting depth"));
+        }
+        if bytes.remaining() < 1 {
+            return Err(serde_error!("TypeTag", "Not enough data for variant"));
+        }
+
+        let type_tag = match bytes.get_u8() {
+            0 => TypeTag::Bool,
+            1 => TypeTag::U8,
+            2 => TypeTag::U64,
+            3 => TypeTag::U128,
+            4 => TypeTag::Address,
+            5 => TypeTag::Signer,
+            6 => TypeTag::Vector(Box::new(Self::read_nested(bytes, depth + 1)?)),
+            7 => TypeTag::Struct(Box::new(StructTag::read_nested(bytes, depth + 1)?)),
+            8 => TypeTag::U16,
+            9 => TypeTag::U32,
+            10 => TypeTag::U256,
+            _ => return Err(serde_error!("TypeTag", "Invalid variant")),
+        };
+
+        Ok(type_tag)
+    }
+}
+
+/// `StructTag` is the representation of a Move struct type.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Getters)]
+#[getset(get = "pub")]
+pub struct StructTag {
+    /// The address of the account that published the module.
+    address: AccountAddress,
+    /// The name of the module declaring the struct.
+    module: String,
+    /// The name of the struct.
+    name: String,
+    /// The type arguments of the struct.
+    type_args: Vec<TypeTag>,
+}
+
+impl StructTag {
+    /// Creates a new `StructTag`.
+    ///
+    /// # Arguments
+    ///
+    /// * `address: AccountAddress` - The address of the account that published the module.
+    /// * `module: &str` - The name of the module declaring the struct.
+    /// * `name: &str` - The name of the struct.
+    /// * `type_args: Vec<TypeTag>` - The type arguments of the struct.
+    ///
+    /// # Returns
+    ///
+    /// A new `StructTag`.
+    pub fn new(address: AccountAddress, module: &str, name: &str, type_args: Vec<TypeTag>) -> Self {
+        Self {
+            address,
+            module: module.into(),
+            name: name.into(),
+            type_args,
+        }
+    }
+
+    /// Converts the `StructTag` to a byte vector.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<u8>` representing the `StructTag`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = BytesMut::new();
+        bytes.put_slice(&self.address.to_bytes());
+        write_bytes(&mut bytes, self.module.as_bytes());
+        write_bytes(&mut bytes, self.name.as_bytes());
+        bytes.put_slice(&write_leb128(self.type_args.len() as u64));
+        for type_arg in &self.type_args {
+            bytes.put_slice(&type_arg.to_bytes());
+        }
+        bytes.to_vec()
+    }
+
+    /// Creates a `StructTag` from a byte slice.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes: &[u8]` - A byte slice from which to create the `StructTag`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok` if the `StructTag`
+    /// could be successfully created, and `Err` otherwise.
+    pub fn from_bytes(mut bytes: &[u8]) -> Result<Self, TypesError> {
+        let struct_tag = Self::read(&mut bytes)?;
+
+        if bytes.remaining() != 0 {
+            return Err(serde_error!(
+                "StructTag",
+                "Unexpected data after completing deserialization"
+            ));
+        }
+
+        Ok(struct_tag)
+    }
+
+    /// Reads a `StructTag` at the start of a buffer, advancing it past the read bytes.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes: &mut &[u8]` - The buffer to read from.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok` if a `StructTag` could be read, and `Err` otherwise.
+    pub(crate) fn read(bytes: &mut &[u8]) -> Result<Self, TypesError> {
+        Self::read_nested(bytes, 0)
+    }
+
+    /// Reads a nested `StructTag` at the start of a buffer, advancing it past the read bytes.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes: &mut &[u8]` - The buffer to read from.
+    /// * `depth: u8` - The nesting depth of the `StructTag` being read.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok` if a `StructTag` could be read, and `Err` otherwise.
+    fn read_nested(bytes: &mut &[u8], depth: u8) -> Result<Self, TypesError> {
+        let address = AccountAddress::from_bytes(
+            bytes
+                .chunk()
+                .get(..ACCOUNT_ADDRESS_SIZE)
+                .ok_or_else(|| serde_error!("StructTag", "Not enough data for address"))?,
+        )?;
+        bytes.advance(ACCOUNT_ADDRESS_SIZE);
+
+        let module = String::from_utf8(read_bytes(bytes, "StructTag")?)
+            .map_err(|e| serde_error!("StructTag", e))?;
+        let name = String::from_utf8(read_bytes(bytes, "StructTag")?)
+            .map_err(|e| serde_error!("StructTag", e))?;
+
+        let (type_args_len, read_bytes) = read_leb128(bytes)
+            .map_err(|_| serde_error!("StructTag", "Not enough data for type arguments length"))?;
+        bytes.advance(read_bytes);
+        let type_args = (0..type_args_len)
+            .map(|_| TypeTag::read_nested(bytes, depth))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            address,
+            module,
+            name,
+            type_args,
+        })
+    }
+}
+
+/// `ModuleId` identifies a Move module by the address of the
+/// account that published it and its name.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize, Getters)]
+#[getset(get = "pub")]
+pub struct ModuleId {
+    /// The address of the account that published the module.
+    address: AccountAddress,
+    /// The name of the module.
+    name: String,
+}
+
+impl ModuleId {
+    /// Creates a new `ModuleId`.
+    ///
+    /// # Arguments
+    ///
+    /// * `address: AccountAddress` - The address of the account that published the module.
+    /// * `name: &str` - The name of the module.
+    ///
+    /// # Returns
+    ///
+    /// A new `ModuleId`.
+    pub fn new(address: AccountAddress, name: &str) -> Self {
+        Self {
+            address,
+            name: name.into(),
+        }
+    }
+
+    /// Converts the `ModuleId` to a byte vector.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<u8>` representing the `ModuleId`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = BytesMut::new();
+        bytes.put_slice(&self.address.to_bytes());
+        write_bytes(&mut bytes, self.name.as_bytes());
+        bytes.to_vec()
+    }
+
+    /// Reads a `ModuleId` at the start of a buffer, advancing it past the read bytes.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes: &mut &[u8]` - The buffer to read from.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok` if a `ModuleId` could be read, and `Err` otherwise.
+    pub(crate) fn read(bytes: &mut &[u8]) -> Result<Self, TypesError> {
+        let address = AccountAddress::from_bytes(
+            bytes
+                .chunk()
+                .get(..ACCOUNT_ADDRESS_SIZE)
+                .ok_or_else(|| serde_error!("ModuleId", "Not enough data for address"))?,
+        )?;
+        bytes.advance(ACCOUNT_ADDRESS_SIZE);
+
+        let name = String::from_utf8(read_bytes(bytes, "ModuleId")?)
+            .map_err(|e| serde_error!("ModuleId", e))?;
+
+        Ok(Self { address, name })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_bytes_conversion_struct_tag() {
+        let struct_tag = StructTag::new(
+            AccountAddress::new([1; ACCOUNT_ADDRESS_SIZE]),
+            "coin",
+            "CoinStore",
+            vec![TypeTag::Struct(Box::new(StructTag::new(
+                AccountAddress::new([1; ACCOUNT_ADDRESS_SIZE]),
+                "aptos_coin",
+                "AptosCoin",
+                vec![],
+            )))],
+        );
+
+        let bytes = bcs::to_bytes(&struct_tag).unwrap();
+        assert_eq!(struct_tag.to_bytes(), bytes);
+        assert_eq!(StructTag::from_bytes(&bytes).unwrap(), struct_tag);
+        assert!(StructTag::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn test_type_tag_nesting() {
+        let mut type_tag = TypeTag::Bool;
+        for _ in 0..MAX_TYPE_TAG_NESTING {
+            type_tag = TypeTag::Vector(Box::new(type_tag));
+        }
+        assert_eq!(TypeTag::from_bytes(&type_tag.to_bytes()).unwrap(), type_tag);
+
+        let type_tag = TypeTag::Vector(Box::new(type_tag));
+        assert!(TypeTag::from_bytes(&type_tag.to_bytes()).is_err());
+    }
+}
@@ -18,7 +18,7 @@ use crate::serde_error;
 use crate::types::block_info::BlockInfo;
 use crate::types::epoch_state::EpochState;
 use crate::types::error::{TypesError, VerifyError};
-use crate::types::utils::ENUM_VARIANT_LEN;
+use crate::types::utils::read_variant;
 use crate::types::validator::ValidatorVerifier;
 use crate::types::Version;
 use bytes::{Buf, BufMut, BytesMut};
@@ -254,6 +254,8 @@ impl LedgerInfoWithV0 {
 /// the aggregated signatures of the validators that signed the `LedgerInfo`.
 ///
 /// This is  an enum to enable versioning of the `LedgerInfo` struct.
+/// Variants unknown to the light client are rejected with a
+/// `TypesError::UnsupportedVariant` error.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum LedgerInfoWithSignatures {
     V0(LedgerInfoWithV0),
@@ -287,22 +289,20 @@ impl LedgerInfoWithSignatures {
     /// A `Result` which is `Ok` if the `LedgerInfoWithSignatures`
     /// could be successfully created, and `Err` otherwise.
     pub fn from_bytes(mut bytes: &[u8]) -> Result<Self, TypesError> {
-        let ledger_v0_size = bytes.len() - ENUM_VARIANT_LEN;
-        let li_w_sig = match bytes.get_u8() {
+        let li_w_sig = match read_variant(&mut bytes, "LedgerInfoWithSignatures")? {
             0 => {
-                let ledger_info_with_v0 = LedgerInfoWithV0::from_bytes(
-                    bytes.chunk().get(..ledger_v0_size).ok_or_else(|| {
-                        serde_error!(
-                            "LedgerInfoWithSignatures",
-                            "Not enough LedgerInfoWithV0 for LedgerInfo"
-                        )
-                    })?,
-                )?;
-                bytes.advance(ledger_v0_size);
+                // `LedgerInfoWithV0` consumes the rest of the buffer
+                let ledger_info_with_v0 = LedgerInfoWithV0::from_bytes(bytes)?;
+                bytes.advance(bytes.remaining());
 
                 LedgerInfoWithSignatures::V0(ledger_info_with_v0)
             }
-            _ => return Err(serde_error!("LedgerInfoWithSignatures", "Unknown variant")),
+            variant => {
+                return Err(TypesError::UnsupportedVariant {
+                    structure: "LedgerInfoWithSignatures".into(),
+                    variant,
+                })
+            }
         };
 
         if bytes.remaining() != 0 {
@@ -366,6 +366,26 @@ mod test {
         assert_eq!(intern_li_hash.to_vec(), aptos_li_hash.to_vec());
     }
 
+    #[test]
+    fn test_unsupported_ledger_info_w_sig_variant() {
+        use super::*;
+        use crate::aptos_test_utils::wrapper::AptosWrapper;
+
+        let mut aptos_wrapper = AptosWrapper::new(2, 1, 1).unwrap();
+        aptos_wrapper.generate_traffic().unwrap();
+
+        let mut latest_li_bytes = aptos_wrapper.get_latest_li_bytes().unwrap();
+        assert!(LedgerInfoWithSignatures::from_bytes(&latest_li_bytes).is_ok());
+
+        // A future version of the signed ledger info is rejected instead of being read as V0
+        latest_li_bytes[0] = 1;
+        assert!(matches!(
+            LedgerInfoWithSignatures::from_bytes(&latest_li_bytes),
+            Err(TypesError::UnsupportedVariant { variant: 1, .. })
+        ));
+        assert!(LedgerInfoWithSignatures::from_bytes(&[]).is_err());
+    }
+
     proptest! {
         #![proptest_config(ProptestConfig::with_cases(10))]
         #[test]
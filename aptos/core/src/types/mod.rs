@@ -13,9 +13,22 @@
 //! - `epoch_state`: This sub-module contains the `EpochState`
 //!   structure and associated methods. It is used to represent
 //!   the epoch state in the blockchain.
+//! - `event`: This sub-module contains the `ContractEvent`
+//!   structure and associated methods. It is used to represent
+//!   the events emitted by transactions in the blockchain.
+//! - `language_storage`: This sub-module contains the `TypeTag`
+//!   and `StructTag` structures and associated methods. They are
+//!   used to represent Move types in the blockchain.
 //! - `ledger_info`: This sub-module contains the `LedgerInfo`
 //!   structure and associated methods. It is used to represent
 //!   the ledger information from the blockchain.
+//! - `resource`: This sub-module contains the `AccountResource`
+//!   and `CoinStoreResource` structures and associated methods. They
+//!   are used to decode the typed fields of common resources stored
+//!   in the state of the blockchain.
+//! - `state`: This sub-module contains the `StateKey` and `StateValue`
+//!   structures and associated methods. They are used to represent
+//!   the entries of the state of the blockchain.
 //! - `transaction`: This sub-module contains the `Transaction`
 //!   structure and associated methods. It is used to represent
 //!   the transactions in the blockchain.
@@ -44,7 +57,11 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 pub mod block_info;
 pub mod epoch_state;
 pub mod error;
+pub mod event;
+pub mod language_storage;
 pub mod ledger_info;
+pub mod resource;
+pub mod state;
 pub mod transaction;
 pub mod trusted_state;
 pub mod utils;
@@ -0,0 +1,489 @@
+// Copyright (c) Argument Computer Corporation
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Resource Module
+//!
+//! This module provides the structures and associated
+//! methods to decode common resources stored in the state
+//! of the chain in the Aptos Light Client.
+//!
+//! The supported resources are the `0x1::account::Account`
+//! resource, represented by `AccountResource`, and the
+//! `0x1::coin::CoinStore<0x1::aptos_coin::AptosCoin>`
+//! resource, represented by `CoinStoreResource`. Their
+//! typed fields are summarized in `ResourceFields`.
+
+// SPDX-License-Identifier: Apache-2.0
+use crate::serde_error;
+use crate::types::error::TypesError;
+use crate::types::event::{EventKey, EVENT_KEY_SIZE};
+use crate::types::language_storage::{StructTag, TypeTag};
+use crate::types::state::{StateKey, StateValue};
+use crate::types::utils::{read_bytes, write_bytes, U64_SIZE};
+use crate::types::{AccountAddress, ACCOUNT_ADDRESS_SIZE};
+use bytes::{Buf, BufMut, BytesMut};
+use getset::{CopyGetters, Getters};
+use serde::{Deserialize, Serialize};
+
+/// Address of the account holding the Aptos framework modules, `0x1`.
+pub const CORE_CODE_ADDRESS: AccountAddress = {
+    let mut address = [0; ACCOUNT_ADDRESS_SIZE];
+    address[ACCOUNT_ADDRESS_SIZE - 1] = 1;
+    AccountAddress::new(address)
+};
+
+/// Typed fields of the resources that can be decoded by the
+/// Light Client.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum ResourceFields {
+    /// The state entry is absent, or is not a resource that can be decoded.
+    Opaque,
+    /// Fields of a `0x1::account::Account` resource.
+    Account { sequence_number: u64 },
+    /// Fields of a `0x1::coin::CoinStore<0x1::aptos_coin::AptosCoin>` resource.
+    CoinStore { balance: u64, frozen: bool },
+}
+
+impl ResourceFields {
+    /// Decodes the typed fields of a state entry, based on the resource its key points to.
+    ///
+    /// # Arguments
+    ///
+    /// * `state_key: &StateKey` - The key of the state entry.
+    /// * `state_value: Option<&StateValue>` - The value of the state entry, `None` if it is absent.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `ResourceFields` of the state entry. It is `Err` if the
+    /// key points to a supported resource whose value could not be decoded.
+    pub fn decode(
+        state_key: &StateKey,
+        state_value: Option<&StateValue>,
+    ) -> Result<Self, TypesError> {
+        let (Some(struct_tag), Some(state_value)) = (state_key.resource_tag()?, state_value) else {
+            return Ok(ResourceFields::Opaque);
+        };
+
+        if struct_tag == AccountResource::struct_tag() {
+            let account = AccountResource::from_bytes(state_value.data())?;
+            Ok(ResourceFields::Account {
+                sequence_number: account.sequence_number,
+            })
+        } else if struct_tag == CoinStoreResource::struct_tag() {
+            let coin_store = CoinStoreResource::from_bytes(state_value.data())?;
+            Ok(ResourceFields::CoinStore {
+                balance: coin_store.coin,
+                frozen: coin_store.frozen,
+            })
+        } else {
+            Ok(ResourceFields::Opaque)
+        }
+    }
+}
+
+/// Length in bytes of the serialized `EventHandle`.
+pub const EVENT_HANDLE_SIZE: usize = U64_SIZE + EVENT_KEY_SIZE;
+
+/// `EventHandle` is the handle through which an account emits events.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize, CopyGetters)]
+#[getset(get_copy = "pub")]
+pub struct EventHandle {
+    /// The number of events emitted through the handle.
+    counter: u64,
+    /// The unique key of the handle.
+    key: EventKey,
+}
+
+impl EventHandle {
+    /// Converts the `EventHandle` to a byte vector.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<u8>` representing the `EventHandle`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = BytesMut::new();
+        bytes.put_u64_le(self.counter);
+        bytes.put_slice(&self.key.to_bytes());
+        bytes.to_vec()
+    }
+
+    /// Reads an `EventHandle` at the start of a buffer, advancing it past the read bytes.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes: &mut &[u8]` - The buffer to read from.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok` if an `EventHandle` could be read, and `Err` otherwise.
+    fn read(bytes: &mut &[u8]) -> Result<Self, TypesError> {
+        if bytes.remaining() < EVENT_HANDLE_SIZE {
+            return Err(TypesError::InvalidLength {
+                structure: "EventHandle".into(),
+                expected: EVENT_HANDLE_SIZE,
+                actual: bytes.remaining(),
+            });
+        }
+
+        let counter = bytes.get_u64_le();
+        let key = EventKey::from_bytes(&bytes[..EVENT_KEY_SIZE])?;
+        bytes.advance(EVENT_KEY_SIZE);
+
+        Ok(Self { counter, key })
+    }
+}
+
+/// `AccountResource` is the `0x1::account::Account` resource,
+/// holding the core information of an account.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Getters, CopyGetters)]
+pub struct AccountResource {
+    /// The authentication key of the account.
+    #[getset(get = "pub")]
+    authentication_key: Vec<u8>,
+    /// The number of transactions sent by the account.
+    #[getset(get_copy = "pub")]
+    sequence_number: u64,
+    /// The creation number of the next event handle of the account.
+    #[getset(get_copy = "pub")]
+    guid_creation_num: u64,
+    /// Handle for the coin registration events.
+    #[getset(get_copy = "pub")]
+    coin_register_events: EventHandle,
+    /// Handle for the key rotation events.
+    #[getset(get_copy = "pub")]
+    key_rotation_events: EventHandle,
+    /// Address the rotation capability is offered to, if any.
+    #[getset(get_copy = "pub")]
+    rotation_capability_offer: Option<AccountAddress>,
+    /// Address the signer capability is offered to, if any.
+    #[getset(get_copy = "pub")]
+    signer_capability_offer: Option<AccountAddress>,
+}
+
+impl AccountResource {
+    /// Returns the type of the `AccountResource`.
+    ///
+    /// # Returns
+    ///
+    /// The `StructTag` of `0x1::account::Account`.
+    pub fn struct_tag() -> StructTag {
+        StructTag::new(CORE_CODE_ADDRESS, "account", "Account", vec![])
+    }
+
+    /// Converts the `AccountResource` to a byte vector.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<u8>` representing the `AccountResource`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = BytesMut::new();
+        write_bytes(&mut bytes, &self.authentication_key);
+        bytes.put_u64_le(self.sequence_number);
+        bytes.put_u64_le(self.guid_creation_num);
+        bytes.put_slice(&self.coin_register_events.to_bytes());
+        bytes.put_slice(&self.key_rotation_events.to_bytes());
+        for offer in [self.rotation_capability_offer, self.signer_capability_offer] {
+            match offer {
+                Some(address) => {
+                    bytes.put_u8(1);
+                    bytes.put_slice(&address.to_bytes());
+                }
+                None => bytes.put_u8(0),
+            }
+        }
+        bytes.to_vec()
+    }
+
+    /// Creates an `AccountResource` from a byte slice.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes: &[u8]` - A byte slice from which to create the `AccountResource`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok` if the `AccountResource`
+    /// could be successfully created, and `Err` otherwise.
+    pub fn from_bytes(mut bytes: &[u8]) -> Result<Self, TypesError> {
+        let authentication_key = read_bytes(&mut bytes, "AccountResource")?;
+
+        if bytes.remaining() < 2 * U64_SIZE {
+            return Err(serde_error!(
+                "AccountResource",
+                "Not enough data for sequence number and guid creation number"
+            ));
+        }
+        let sequence_number = bytes.get_u64_le();
+        let guid_creation_num = bytes.get_u64_le();
+
+        let coin_register_events = EventHandle::read(&mut bytes)?;
+        let key_rotation_events = EventHandle::read(&mut bytes)?;
+
+        let rotation_capability_offer = read_capability_offer(&mut bytes)?;
+        let signer_capability_offer = read_capability_offer(&mut bytes)?;
+
+        if bytes.remaining() != 0 {
+            return Err(serde_error!(
+                "AccountResource",
+                "Unexpected data after completing deserialization"
+            ));
+        }
+
+        Ok(Self {
+            authentication_key,
+            sequence_number,
+            guid_creation_num,
+            coin_register_events,
+            key_rotation_events,
+            rotation_capability_offer,
+            signer_capability_offer,
+        })
+    }
+}
+
+/// Length in bytes of the serialized `CoinStoreResource`.
+pub const COIN_STORE_RESOURCE_SIZE: usize = U64_SIZE + 1 + 2 * EVENT_HANDLE_SIZE;
+
+/// `CoinStoreResource` is the `0x1::coin::CoinStore<0x1::aptos_coin::AptosCoin>`
+/// resource, holding the Aptos coins of an account.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize, CopyGetters)]
+#[getset(get_copy = "pub")]
+pub struct CoinStoreResource {
+    /// The balance of the account.
+    coin: u64,
+    /// Whether the coin store is frozen.
+    frozen: bool,
+    /// Handle for the deposit events.
+    deposit_events: EventHandle,
+    /// Handle for the withdraw events.
+    withdraw_events: EventHandle,
+}
+
+impl CoinStoreResource {
+    /// Returns the type of the `CoinStoreResource`.
+    ///
+    /// # Returns
+    ///
+    /// The `StructTag` of `0x1::coin::CoinStore<0x1::aptos_coin::AptosCoin>`.
+    pub fn struct_tag() -> StructTag {
+        let aptos_coin = StructTag::new(CORE_CODE_ADDRESS, "aptos_coin", "AptosCoin", vec![]);
+
+        StructTag::new(
+            CORE_CODE_ADDRESS,
+            "coin",
+            "CoinStore",
+            vec![TypeTag::Struct(Box::new(aptos_coin))],
+        )
+    }
+
+    /// Converts the `CoinStoreResource` to a byte vector.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<u8>` representing the `CoinStoreResource`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = BytesMut::new();
+        bytes.put_u64_le(self.coin);
+        bytes.put_u8(u8::from(self.frozen));
+        bytes.put_slice(&self.deposit_events.to_bytes());
+        bytes.put_slice(&self.withdraw_events.to_bytes());
+        bytes.to_vec()
+    }
+
+    /// Creates a `CoinStoreResource` from a byte slice.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes: &[u8]` - A byte slice from which to create the `CoinStoreResource`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok` if the `CoinStoreResource`
+    /// could be successfully created, and `Err` otherwise.
+    pub fn from_bytes(mut bytes: &[u8]) -> Result<Self, TypesError> {
+        if bytes.remaining() != COIN_STORE_RESOURCE_SIZE {
+            return Err(TypesError::InvalidLength {
+                structure: "CoinStoreResource".into(),
+                expected: COIN_STORE_RESOURCE_SIZE,
+                actual: bytes.remaining(),
+            });
+        }
+
+        let coin = bytes.get_u64_le();
+        let frozen = match bytes.get_u8() {
+            0 => false,
+            1 => true,
+            _ => return Err(serde_error!("CoinStoreResource", "Invalid boolean")),
+        };
+        let deposit_events = EventHandle::read(&mut bytes)?;
+        let withdraw_events = EventHandle::read(&mut bytes)?;
+
+        Ok(Self {
+            coin,
+            frozen,
+            deposit_events,
+            withdraw_events,
+        })
+    }
+}
+
+/// Reads a capability offer at the start of a buffer, advancing it past the read bytes.
+///
+/// # Arguments
+///
+/// * `bytes: &mut &[u8]` - The buffer to read from.
+///
+/// # Returns
+///
+/// A `Result` containing the address the capability is offered to, if any.
+fn read_capability_offer(bytes: &mut &[u8]) -> Result<Option<AccountAddress>, TypesError> {
+    if bytes.remaining() < 1 {
+        return Err(serde_error!(
+            "AccountResource",
+            "Not enough data for capability offer"
+        ));
+    }
+
+    match bytes.get_u8() {
+        0 => Ok(None),
+        1 => {
+            let address =
+                AccountAddress::from_bytes(bytes.chunk().get(..ACCOUNT_ADDRESS_SIZE).ok_or_else(
+                    || serde_error!("AccountResource", "Not enough data for capability offer"),
+                )?)?;
+            bytes.advance(ACCOUNT_ADDRESS_SIZE);
+            Ok(Some(address))
+        }
+        _ => Err(serde_error!("AccountResource", "Invalid capability offer")),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_decode_resource_fields() {
+        let address = AccountAddress::new([2; ACCOUNT_ADDRESS_SIZE]);
+        let event_handle = EventHandle {
+            counter: 3,
+            key: EventKey::from_bytes(&[4; EVENT_KEY_SIZE]).unwrap(),
+        };
+
+        let account = AccountResource {
+            authentication_key: vec![5; 32],
+            sequence_number: 6,
+            guid_creation_num: 7,
+            coin_register_events: event_handle,
+            key_rotation_events: event_handle,
+            rotation_capability_offer: None,
+            signer_capability_offer: Some(address),
+        };
+        let account_bytes = bcs::to_bytes(&account).unwrap();
+        assert_eq!(account.to_bytes(), account_bytes);
+        assert_eq!(
+            ResourceFields::decode(
+                &StateKey::resource(address, &AccountResource::struct_tag()),
+                Some(&StateValue::V0(account_bytes))
+            )
+            .unwrap(),
+            ResourceFields::Account { sequence_number: 6 }
+        );
+
+        let coin_store = CoinStoreResource {
+            coin: 8,
+            frozen: true,
+            deposit_events: event_handle,
+            withdraw_events: event_handle,
+        };
+        let coin_store_bytes = bcs::to_bytes(&coin_store).unwrap();
+        assert_eq!(coin_store.to_bytes(), coin_store_bytes);
+        assert_eq!(
+            ResourceFields::decode(
+                &StateKey::resource(address, &CoinStoreResource::struct_tag()),
+                Some(&StateValue::V0(coin_store_bytes.clone()))
+            )
+            .unwrap(),
+            ResourceFields::CoinStore {
+                balance: 8,
+                frozen: true
+            }
+        );
+
+        // Absent entries and unknown resources are opaque
+        assert_eq!(
+            ResourceFields::decode(
+                &StateKey::resource(address, &AccountResource::struct_tag()),
+                None
+            )
+            .unwrap(),
+            ResourceFields::Opaque
+        );
+        assert_eq!(
+            ResourceFields::decode(
+                &StateKey::Raw(vec![]),
+                Some(&StateValue::V0(coin_store_bytes.clone()))
+            )
+            .unwrap(),
+            ResourceFields::Opaque
+        );
+
+        // A value that does not match the resource type is rejected
+        assert!(ResourceFields::decode(
+            &StateKey::resource(address, &AccountResource::struct_tag()),
+            Some(&StateValue::V0(coin_store_bytes))
+        )
+        .is_err());
+    }
+
+    #[cfg(feature = "aptos")]
+    #[test]
+    fn test_decode_aptos_resources() {
+        use crate::aptos_test_utils::wrapper::AptosWrapper;
+
+        let mut aptos_wrapper = AptosWrapper::new(40, 1, 1).unwrap();
+        aptos_wrapper.generate_traffic().unwrap();
+
+        let proof_assets = aptos_wrapper.get_latest_proof_account(35).unwrap();
+
+        let state_key =
+            StateKey::from_bytes(&bcs::to_bytes(proof_assets.state_key()).unwrap()).unwrap();
+        assert_eq!(
+            state_key.resource_tag().unwrap(),
+            Some(AccountResource::struct_tag())
+        );
+
+        let state_value = StateValue::from_bytes(
+            &bcs::to_bytes(proof_assets.state_value().as_ref().unwrap()).unwrap(),
+        )
+        .unwrap();
+        let account = AccountResource::from_bytes(state_value.data()).unwrap();
+        assert_eq!(account.to_bytes(), state_value.data());
+
+        assert_eq!(
+            ResourceFields::decode(&state_key, Some(&state_value)).unwrap(),
+            ResourceFields::Account {
+                sequence_number: account.sequence_number()
+            }
+        );
+
+        let proof_assets = aptos_wrapper.get_latest_proof_coin_store(35).unwrap();
+
+        let state_key =
+            StateKey::from_bytes(&bcs::to_bytes(proof_assets.state_key()).unwrap()).unwrap();
+        let state_value = StateValue::from_bytes(
+            &bcs::to_bytes(proof_assets.state_value().as_ref().unwrap()).unwrap(),
+        )
+        .unwrap();
+        let coin_store = CoinStoreResource::from_bytes(state_value.data()).unwrap();
+
+        assert!(coin_store.coin() > 0);
+        assert_eq!(
+            ResourceFields::decode(&state_key, Some(&state_value)).unwrap(),
+            ResourceFields::CoinStore {
+                balance: coin_store.coin(),
+                frozen: false
+            }
+        );
+    }
+}
@@ -0,0 +1,515 @@
+// Copyright (c) Argument Computer Corporation
+// SPDX-License-Identifier: Apache-2.0
+
+//! # State Module
+//!
+//! This module provides the `StateKey` and `StateValue`
+//! structures and associated methods for handling the
+//! entries of the state of the chain in the Aptos Light Client.
+//!
+//! The state of the chain is stored in a Sparse Merkle Tree
+//! whose leaves are indexed by the hash of a `StateKey` and
+//! hold the hash of a `StateValue`. Computing those hashes from
+//! the raw key and value allows to trust the content of a leaf
+//! once its inclusion in the tree has been verified.
+
+// SPDX-License-Identifier: Apache-2.0
+use crate::crypto::hash::{hash_data, prefixed_sha3, CryptoHash, HashValue};
+use crate::serde_error;
+use crate::types::error::TypesError;
+use crate::types::language_storage::StructTag;
+use crate::types::utils::{read_bytes, write_bytes, ENUM_VARIANT_LEN, U64_SIZE};
+use crate::types::{AccountAddress, ACCOUNT_ADDRESS_SIZE};
+use bytes::{Buf, BufMut, BytesMut};
+use getset::{CopyGetters, Getters};
+use serde::{Deserialize, Serialize};
+
+/// Tag of the `Path::Resource` variant, that prefixes the serialized
+/// `StructTag` in the path of an `AccessPath` pointing to a resource.
+const RESOURCE_PATH_TAG: u8 = 1;
+
+/// `StateKey` identifies an entry in the state of the chain.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum StateKey {
+    AccessPath(AccessPath),
+    TableItem {
+        handle: AccountAddress,
+        key: Vec<u8>,
+    },
+    Raw(Vec<u8>),
+}
+
+impl StateKey {
+    /// Creates the `StateKey` of a resource stored under an account.
+    ///
+    /// # Arguments
+    ///
+    /// * `address: AccountAddress` - The address of the account holding the resource.
+    /// * `struct_tag: &StructTag` - The type of the resource.
+    ///
+    /// # Returns
+    ///
+    /// The `StateKey` of the resource.
+    pub fn resource(address: AccountAddress, struct_tag: &StructTag) -> Self {
+        let mut path = vec![RESOURCE_PATH_TAG];
+        path.extend(struct_tag.to_bytes());
+
+        StateKey::AccessPath(AccessPath { address, path })
+    }
+
+    /// Returns the type of the resource the `StateKey` points to, if any.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `StructTag` of the resource, or `None` if the `StateKey`
+    /// does not point to a resource. It is `Err` if the path of the key could not be decoded.
+    pub fn resource_tag(&self) -> Result<Option<StructTag>, TypesError> {
+        match self {
+            StateKey::AccessPath(access_path) => match access_path.path.split_first() {
+                Some((&RESOURCE_PATH_TAG, struct_tag)) => {
+                    Ok(Some(StructTag::from_bytes(struct_tag)?))
+                }
+                _ => Ok(None),
+            },
+            _ => Ok(None),
+        }
+    }
+
+    /// Encodes the `StateKey` in the format used to compute its hash.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<u8>` representing the encoded `StateKey`.
+    ///
+    /// # Notes
+    ///
+    /// This encoding differs from the serialized `StateKey`: it is prefixed by a
+    /// tag that does not follow the variant index, and the key of a table item is
+    /// not prefixed by its length.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = BytesMut::new();
+        match self {
+            StateKey::AccessPath(access_path) => {
+                bytes.put_u8(0);
+                bytes.put_slice(&access_path.to_bytes());
+            }
+            StateKey::TableItem { handle, key } => {
+                bytes.put_u8(1);
+                bytes.put_slice(&handle.to_bytes());
+                bytes.put_slice(key);
+            }
+            StateKey::Raw(raw) => {
+                bytes.put_u8(u8::MAX);
+                bytes.put_slice(raw);
+            }
+        }
+        bytes.to_vec()
+    }
+
+    /// Converts the `StateKey` to a byte vector.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<u8>` representing the `StateKey`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = BytesMut::new();
+        match self {
+            StateKey::AccessPath(access_path) => {
+                bytes.put_u8(0);
+                bytes.put_slice(&access_path.to_bytes());
+            }
+            StateKey::TableItem { handle, key } => {
+                bytes.put_u8(1);
+                bytes.put_slice(&handle.to_bytes());
+                write_bytes(&mut bytes, key);
+            }
+            StateKey::Raw(raw) => {
+                bytes.put_u8(2);
+                write_bytes(&mut bytes, raw);
+            }
+        }
+        bytes.to_vec()
+    }
+
+    /// Creates a `StateKey` from a byte slice.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes: &[u8]` - A byte slice from which to create the `StateKey`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok` if the `StateKey`
+    /// could be successfully created, and `Err` otherwise.
+    pub fn from_bytes(mut bytes: &[u8]) -> Result<Self, TypesError> {
+        if bytes.remaining() < ENUM_VARIANT_LEN {
+            return Err(serde_error!("StateKey", "Not enough data for variant"));
+        }
+
+        let state_key = match bytes.get_u8() {
+            0 => StateKey::AccessPath(AccessPath::read(&mut bytes)?),
+            1 => {
+                let handle = read_address(&mut bytes, "StateKey")?;
+                let key = read_bytes(&mut bytes, "StateKey")?;
+                StateKey::TableItem { handle, key }
+            }
+            2 => StateKey::Raw(read_bytes(&mut bytes, "StateKey")?),
+            _ => return Err(serde_error!("StateKey", "Invalid variant")),
+        };
+
+        if bytes.remaining() != 0 {
+            return Err(serde_error!(
+                "StateKey",
+                "Unexpected data after completing deserialization"
+            ));
+        }
+
+        Ok(state_key)
+    }
+}
+
+impl CryptoHash for StateKey {
+    fn hash(&self) -> HashValue {
+        HashValue::new(hash_data(
+            &prefixed_sha3(b"StateKeyInner"),
+            vec![&self.encode()],
+        ))
+    }
+}
+
+/// `AccessPath` points to a resource or a module stored under an account.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Getters, CopyGetters)]
+pub struct AccessPath {
+    /// The address of the account.
+    #[getset(get_copy = "pub")]
+    address: AccountAddress,
+    /// The serialized path of the resource or the module in the account.
+    #[getset(get = "pub")]
+    path: Vec<u8>,
+}
+
+impl AccessPath {
+    /// Converts the `AccessPath` to a byte vector.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<u8>` representing the `AccessPath`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = BytesMut::new();
+        bytes.put_slice(&self.address.to_bytes());
+        write_bytes(&mut bytes, &self.path);
+        bytes.to_vec()
+    }
+
+    /// Reads an `AccessPath` at the start of a buffer, advancing it past the read bytes.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes: &mut &[u8]` - The buffer to read from.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok` if an `AccessPath` could be read, and `Err` otherwise.
+    fn read(bytes: &mut &[u8]) -> Result<Self, TypesError> {
+        let address = read_address(bytes, "AccessPath")?;
+        let path = read_bytes(bytes, "AccessPath")?;
+
+        Ok(Self { address, path })
+    }
+}
+
+/// `StateValue` is the value of an entry in the state of the chain.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum StateValue {
+    V0(Vec<u8>),
+    WithMetadata {
+        data: Vec<u8>,
+        metadata: StateValueMetadata,
+    },
+}
+
+impl StateValue {
+    /// Returns the data of the `StateValue`.
+    ///
+    /// # Returns
+    ///
+    /// The BCS-serialized data of the state entry.
+    pub fn data(&self) -> &[u8] {
+        match self {
+            StateValue::V0(data) => data,
+            StateValue::WithMetadata { data, .. } => data,
+        }
+    }
+
+    /// Converts the `StateValue` to a byte vector.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<u8>` representing the `StateValue`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = BytesMut::new();
+        match self {
+            StateValue::V0(data) => {
+                bytes.put_u8(0);
+                write_bytes(&mut bytes, data);
+            }
+            StateValue::WithMetadata { data, metadata } => {
+                bytes.put_u8(1);
+                write_bytes(&mut bytes, data);
+                bytes.put_slice(&metadata.to_bytes());
+            }
+        }
+        bytes.to_vec()
+    }
+
+    /// Creates a `StateValue` from a byte slice.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes: &[u8]` - A byte slice from which to create the `StateValue`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok` if the `StateValue`
+    /// could be successfully created, and `Err` otherwise.
+    pub fn from_bytes(mut bytes: &[u8]) -> Result<Self, TypesError> {
+        if bytes.remaining() < ENUM_VARIANT_LEN {
+            return Err(serde_error!("StateValue", "Not enough data for variant"));
+        }
+
+        let state_value = match bytes.get_u8() {
+            0 => StateValue::V0(read_bytes(&mut bytes, "StateValue")?),
+            1 => {
+                let data = read_bytes(&mut bytes, "StateValue")?;
+                let metadata = StateValueMetadata::read(&mut bytes)?;
+                StateValue::WithMetadata { data, metadata }
+            }
+            _ => return Err(serde_error!("StateValue", "Invalid variant")),
+        };
+
+        if bytes.remaining() != 0 {
+            return Err(serde_error!(
+                "StateValue",
+                "Unexpected data after completing deserialization"
+            ));
+        }
+
+        Ok(state_value)
+    }
+}
+
+impl CryptoHash for StateValue {
+    fn hash(&self) -> HashValue {
+        HashValue::new(hash_data(
+            &prefixed_sha3(b"StateValueInner"),
+            vec![&self.to_bytes()],
+        ))
+    }
+}
+
+/// `StateValueMetadata` contains the storage fee information of a state entry.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum StateValueMetadata {
+    V0 {
+        deposit: u64,
+        creation_time_usecs: u64,
+    },
+    V1 {
+        slot_deposit: u64,
+        bytes_deposit: u64,
+        creation_time_usecs: u64,
+    },
+}
+
+impl StateValueMetadata {
+    /// Converts the `StateValueMetadata` to a byte vector.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<u8>` representing the `StateValueMetadata`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = BytesMut::new();
+        match self {
+            StateValueMetadata::V0 {
+                deposit,
+                creation_time_usecs,
+            } => {
+                bytes.put_u8(0);
+                bytes.put_u64_le(*deposit);
+                bytes.put_u64_le(*creation_time_usecs);
+            }
+            StateValueMetadata::V1 {
+                slot_deposit,
+                bytes_deposit,
+                creation_time_usecs,
+            } => {
+                bytes.put_u8(1);
+                bytes.put_u64_le(*slot_deposit);
+                bytes.put_u64_le(*bytes_deposit);
+                bytes.put_u64_le(*creation_time_usecs);
+            }
+        }
+        bytes.to_vec()
+    }
+
+    /// Reads a `StateValueMetadata` at the start of a buffer, advancing it past the read bytes.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes: &mut &[u8]` - The buffer to read from.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok` if a `StateValueMetadata` could be read, and `Err` otherwise.
+    fn read(bytes: &mut &[u8]) -> Result<Self, TypesError> {
+        if bytes.remaining() < ENUM_VARIANT_LEN {
+            return Err(serde_error!(
+                "StateValueMetadata",
+                "Not enough data for variant"
+            ));
+        }
+
+        let (variant, fields_len) = match bytes.get_u8() {
+            0 => (0, 2 * U64_SIZE),
+            1 => (1, 3 * U64_SIZE),
+            _ => return Err(serde_error!("StateValueMetadata", "Invalid variant")),
+        };
+        if bytes.remaining() < fields_len {
+            return Err(serde_error!(
+                "StateValueMetadata",
+                "Not enough data for fields"
+            ));
+        }
+
+        let metadata = if variant == 0 {
+            StateValueMetadata::V0 {
+                deposit: bytes.get_u64_le(),
+                creation_time_usecs: bytes.get_u64_le(),
+            }
+        } else {
+            StateValueMetadata::V1 {
+                slot_deposit: bytes.get_u64_le(),
+                bytes_deposit: bytes.get_u64_le(),
+                creation_time_usecs: bytes.get_u64_le(),
+            }
+        };
+
+        Ok(metadata)
+    }
+}
+
+/// Reads an `AccountAddress` at the start of a buffer, advancing it past the read bytes.
+///
+/// # Arguments
+///
+/// * `bytes: &mut &[u8]` - The buffer to read from.
+/// * `structure: &str` - The name of the structure being deserialized.
+///
+/// # Returns
+///
+/// A `Result` which is `Ok` if the `AccountAddress` could be read, and `Err` otherwise.
+fn read_address(bytes: &mut &[u8], structure: &str) -> Result<AccountAddress, TypesError> {
+    let address = AccountAddress::from_bytes(
+        bytes
+            .chunk()
+            .get(..ACCOUNT_ADDRESS_SIZE)
+            .ok_or_else(|| serde_error!(structure, "Not enough data for address"))?,
+    )?;
+    bytes.advance(ACCOUNT_ADDRESS_SIZE);
+
+    Ok(address)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::types::language_storage::TypeTag;
+
+    #[test]
+    fn test_bytes_conversion_state_key() {
+        let struct_tag = StructTag::new(
+            AccountAddress::new([1; ACCOUNT_ADDRESS_SIZE]),
+            "coin",
+            "CoinStore",
+            vec![TypeTag::U64],
+        );
+
+        let state_keys = [
+            StateKey::resource(AccountAddress::new([2; ACCOUNT_ADDRESS_SIZE]), &struct_tag),
+            StateKey::TableItem {
+                handle: AccountAddress::new([3; ACCOUNT_ADDRESS_SIZE]),
+                key: vec![4; 10],
+            },
+            StateKey::Raw(vec![5; 200]),
+        ];
+
+        for state_key in state_keys {
+            let bytes = bcs::to_bytes(&state_key).unwrap();
+            assert_eq!(state_key.to_bytes(), bytes);
+            assert_eq!(StateKey::from_bytes(&bytes).unwrap(), state_key);
+            assert!(StateKey::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+        }
+
+        let state_key =
+            StateKey::resource(AccountAddress::new([2; ACCOUNT_ADDRESS_SIZE]), &struct_tag);
+        assert_eq!(state_key.resource_tag().unwrap(), Some(struct_tag));
+        assert_eq!(StateKey::Raw(vec![]).resource_tag().unwrap(), None);
+    }
+
+    #[test]
+    fn test_bytes_conversion_state_value() {
+        let state_values = [
+            StateValue::V0(vec![1; 10]),
+            StateValue::WithMetadata {
+                data: vec![2; 200],
+                metadata: StateValueMetadata::V0 {
+                    deposit: 3,
+                    creation_time_usecs: 4,
+                },
+            },
+            StateValue::WithMetadata {
+                data: vec![],
+                metadata: StateValueMetadata::V1 {
+                    slot_deposit: 5,
+                    bytes_deposit: 6,
+                    creation_time_usecs: 7,
+                },
+            },
+        ];
+
+        for state_value in state_values {
+            let bytes = bcs::to_bytes(&state_value).unwrap();
+            assert_eq!(state_value.to_bytes(), bytes);
+            assert_eq!(StateValue::from_bytes(&bytes).unwrap(), state_value);
+            assert!(StateValue::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+        }
+    }
+
+    #[cfg(feature = "aptos")]
+    #[test]
+    fn test_hash_state_key_and_value() {
+        use crate::aptos_test_utils::wrapper::AptosWrapper;
+        use crate::crypto::hash::CryptoHash as LcCryptoHash;
+
+        let mut aptos_wrapper = AptosWrapper::new(40, 1, 1).unwrap();
+        aptos_wrapper.generate_traffic().unwrap();
+
+        let proof_assets = aptos_wrapper.get_latest_proof_account(35).unwrap();
+
+        let state_key =
+            StateKey::from_bytes(&bcs::to_bytes(proof_assets.state_key()).unwrap()).unwrap();
+        assert_eq!(
+            LcCryptoHash::hash(&state_key).as_ref(),
+            proof_assets.key().as_ref()
+        );
+
+        let state_value = StateValue::from_bytes(
+            &bcs::to_bytes(proof_assets.state_value().as_ref().unwrap()).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            LcCryptoHash::hash(&state_value).as_ref(),
+            proof_assets.state_value_hash().unwrap().as_ref()
+        );
+    }
+}
@@ -14,15 +14,20 @@
 use crate::crypto::hash::{hash_data, prefixed_sha3, CryptoHash, HashValue, HASH_LENGTH};
 use crate::serde_error;
 use crate::types::error::TypesError;
-use crate::types::utils::{ENUM_VARIANT_LEN, U64_SIZE};
+use crate::types::language_storage::ModuleId;
+use crate::types::utils::{
+    read_bytes, read_variant, write_bytes, ENUM_VARIANT_LEN, U16_SIZE, U64_SIZE,
+};
 use bytes::{Buf, BufMut, BytesMut};
+use getset::Getters;
 use serde::{Deserialize, Serialize};
 
 /// `TransactionInfo` contains Information related to how
 /// a transaction affected the state of the Aptos blockchain.
 ///
 /// It is implemented as an enum to allow for future expansion
-/// of the transaction info.
+/// of the transaction info. Variants unknown to the light client
+/// are rejected with a `TypesError::UnsupportedVariant` error.
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub enum TransactionInfo {
     V0(TransactionInfoV0),
@@ -40,6 +45,51 @@ impl TransactionInfo {
         }
     }
 
+    /// Returns the amount of gas used by the transaction.
+    ///
+    /// # Returns
+    ///
+    /// The amount of gas used by the transaction.
+    pub const fn gas_used(&self) -> u64 {
+        match self {
+            TransactionInfo::V0(info) => info.gas_used,
+        }
+    }
+
+    /// Returns the execution status of the transaction.
+    ///
+    /// # Returns
+    ///
+    /// The `ExecutionStatus` of the transaction.
+    pub const fn status(&self) -> &ExecutionStatus {
+        match self {
+            TransactionInfo::V0(info) => &info.status,
+        }
+    }
+
+    /// Returns the hash of the transaction described by the `TransactionInfo`.
+    ///
+    /// # Returns
+    ///
+    /// The hash of the committed transaction.
+    pub const fn transaction_hash(&self) -> HashValue {
+        match self {
+            TransactionInfo::V0(info) => info.transaction_hash,
+        }
+    }
+
+    /// Returns the root hash of the Event Accumulator of the `TransactionInfo`.
+    ///
+    /// # Returns
+    ///
+    /// The root hash of the Merkle Accumulator storing all events
+    /// emitted during the transaction.
+    pub const fn event_root_hash(&self) -> HashValue {
+        match self {
+            TransactionInfo::V0(info) => info.event_root_hash,
+        }
+    }
+
     /// Converts the `TransactionInfo` to a byte vector.
     ///
     /// # Returns
@@ -66,22 +116,24 @@ impl TransactionInfo {
     ///
     /// A `Result` which is `Ok` if the `TransactionInfo`
     /// could be successfully created, and `Err` otherwise.
-    pub fn from_bytes(bytes: &[u8]) -> Result<Self, TypesError> {
-        let mut buf = BytesMut::from(bytes);
-        let tx_info = match buf.get_u8() {
+    pub fn from_bytes(mut bytes: &[u8]) -> Result<Self, TypesError> {
+        let tx_info = match read_variant(&mut bytes, "TransactionInfo")? {
             0 => {
-                let tx_info_v0 = TransactionInfoV0::from_bytes(
-                    buf.chunk().get(..TRANSACTION_INFO_V0_SIZE).ok_or_else(|| {
-                        serde_error!("TransactionInfo", "Not enough data for TransactionInfoV0")
-                    })?,
-                )?;
-                buf.advance(TRANSACTION_INFO_V0_SIZE);
+                // The size of `TransactionInfoV0` depends on its optional hashes, so it
+                // consumes the rest of the buffer.
+                let tx_info_v0 = TransactionInfoV0::from_bytes(bytes)?;
+                bytes.advance(bytes.remaining());
                 TransactionInfo::V0(tx_info_v0)
             }
-            _ => return Err(serde_error!("TransactionInfo", "Invalid variant")),
+            variant => {
+                return Err(TypesError::UnsupportedVariant {
+                    structure: "TransactionInfo".into(),
+                    variant,
+                })
+            }
         };
 
-        if buf.remaining() != 0 {
+        if bytes.remaining() != 0 {
             return Err(serde_error!(
                 "TransactionInfo",
                 "Unexpected data after completing deserialization"
@@ -101,7 +153,10 @@ impl CryptoHash for TransactionInfo {
     }
 }
 
-/// Length in bytes of the serialized `TransactionInfoV0`.
+/// Length in bytes of the serialized `TransactionInfoV0` of a state
+/// checkpoint transaction, that has a state checkpoint hash and no
+/// state cemetery hash. Other transactions do not have a state
+/// checkpoint hash, making their `TransactionInfoV0` shorter.
 pub const TRANSACTION_INFO_V0_SIZE: usize =
     U64_SIZE + 4 * HASH_LENGTH + 2 * ENUM_VARIANT_LEN + EXECUTION_STATUS_SIZE;
 
@@ -176,16 +231,17 @@ impl TransactionInfoV0 {
     ///
     /// A `Result` which is `Ok` if the `TransactionInfoV0`
     /// could be successfully created, and `Err` otherwise.
-    pub(crate) fn from_bytes(bytes: &[u8]) -> Result<Self, TypesError> {
-        let mut buf = BytesMut::from(bytes);
-
+    pub(crate) fn from_bytes(mut buf: &[u8]) -> Result<Self, TypesError> {
+        if buf.remaining() < U64_SIZE {
+            return Err(serde_error!(
+                "TransactionInfoV0",
+                "Not enough data for gas used"
+            ));
+        }
         let gas_used = buf.get_u64_le();
 
-        let status =
-            ExecutionStatus::from_bytes(buf.chunk().get(..EXECUTION_STATUS_SIZE).ok_or_else(
-                || serde_error!("TransactionInfo0", "Not enough data for ExecutionStatus"),
-            )?)?;
-        buf.advance(EXECUTION_STATUS_SIZE);
+        // The size of `ExecutionStatus` depends on the outcome of the execution
+        let status = ExecutionStatus::read(&mut buf)?;
 
         let transaction_hash =
             HashValue::from_slice(buf.chunk().get(..HASH_LENGTH).ok_or_else(|| {
@@ -208,21 +264,33 @@ impl TransactionInfoV0 {
             .unwrap();
         buf.advance(HASH_LENGTH);
 
+        if buf.remaining() < ENUM_VARIANT_LEN {
+            return Err(serde_error!(
+                "TransactionInfo0",
+                "Not enough data for state checkpoint hash variant"
+            ));
+        }
         let state_checkpoint_hash = if buf.get_u8() == 1 {
-            Some(
+            let hash_value =
                 HashValue::from_slice(buf.chunk().get(..HASH_LENGTH).ok_or_else(|| {
                     serde_error!(
                         "TransactionInfo0",
                         "Not enough data for state checkpoint hash"
                     )
                 })?)
-                .unwrap(),
-            )
+                .unwrap();
+            buf.advance(HASH_LENGTH);
+            Some(hash_value)
         } else {
             None
         };
-        buf.advance(HASH_LENGTH);
 
+        if buf.remaining() < ENUM_VARIANT_LEN {
+            return Err(serde_error!(
+                "TransactionInfo0",
+                "Not enough data for state cemetery hash variant"
+            ));
+        }
         let state_cemetery_hash = if buf.get_u8() == 1 {
             let hash_value =
                 HashValue::from_slice(buf.chunk().get(..HASH_LENGTH).ok_or_else(|| {
@@ -257,17 +325,32 @@ impl TransactionInfoV0 {
     }
 }
 
-/// Length in bytes of the serialized `ExecutionStatus`. We only
-/// expect to receive successful transactions included  in the block to
-/// prove account inclusion.
+/// Length in bytes of the serialized `ExecutionStatus::Success`. Failed
+/// executions carry details about the failure, making their
+/// `ExecutionStatus` longer.
 pub const EXECUTION_STATUS_SIZE: usize = ENUM_VARIANT_LEN;
 
 /// The status of VM execution, which contains more detailed failure info.
-/// We only expect to handle successful transactions in the light client,
-/// as we use it for state checkpoint.
+/// Only successful transactions are expected when proving account
+/// inclusion or that a user transaction was committed, but the failure
+/// variants are still decoded as failed transactions are committed in
+/// the ledger as well.
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum ExecutionStatus {
     Success,
+    OutOfGas,
+    MoveAbort {
+        location: AbortLocation,
+        code: u64,
+        info: Option<AbortInfo>,
+    },
+    ExecutionFailure {
+        location: AbortLocation,
+        function: u16,
+        code_offset: u16,
+    },
+    /// The numeric value of the `StatusCode` of the error, if known.
+    MiscellaneousError(Option<u64>),
 }
 
 impl ExecutionStatus {
@@ -275,16 +358,56 @@ impl ExecutionStatus {
     ///
     /// # Returns
     ///
-    /// A `Vec<u8>` representing the `ExecutionStatus
-    pub(crate) fn to_bytes(&self) -> Vec<u8> {
+    /// A `Vec<u8>` representing the `ExecutionStatus`.
+    pub fn to_bytes(&self) -> Vec<u8> {
         let mut bytes = BytesMut::new();
         match self {
             ExecutionStatus::Success => {
                 bytes.put_u8(0);
             }
+            ExecutionStatus::OutOfGas => {
+                bytes.put_u8(1);
+            }
+            ExecutionStatus::MoveAbort {
+                location,
+                code,
+                info,
+            } => {
+                bytes.put_u8(2);
+                bytes.put_slice(&location.to_bytes());
+                bytes.put_u64_le(*code);
+                match info {
+                    Some(info) => {
+                        bytes.put_u8(1);
+                        bytes.put_slice(&info.to_bytes());
+                    }
+                    None => bytes.put_u8(0),
+                }
+            }
+            ExecutionStatus::ExecutionFailure {
+                location,
+                function,
+                code_offset,
+            } => {
+                bytes.put_u8(3);
+                bytes.put_slice(&location.to_bytes());
+                bytes.put_u16_le(*function);
+                bytes.put_u16_le(*code_offset);
+            }
+            ExecutionStatus::MiscellaneousError(status_code) => {
+                bytes.put_u8(4);
+                match status_code {
+                    Some(status_code) => {
+                        bytes.put_u8(1);
+                        bytes.put_u64_le(*status_code);
+                    }
+                    None => bytes.put_u8(0),
+                }
+            }
         }
         bytes.to_vec()
     }
+
     /// Creates a `ExecutionStatus` from a byte slice.
     ///
     /// # Arguments
@@ -295,19 +418,10 @@ impl ExecutionStatus {
     ///
     /// A `Result` which is `Ok` if the `ExecutionStatus`
     /// could be successfully created, and `Err` otherwise.
-    pub(crate) fn from_bytes(bytes: &[u8]) -> Result<Self, TypesError> {
-        let mut buf = BytesMut::from(bytes);
-        let execution_status = match buf.get_u8() {
-            0 => ExecutionStatus::Success,
-            _ => {
-                return Err(serde_error!(
-                    "ExecutionStatus",
-                    "Invalid variant, expected only success"
-                ))
-            }
-        };
+    pub fn from_bytes(mut bytes: &[u8]) -> Result<Self, TypesError> {
+        let execution_status = Self::read(&mut bytes)?;
 
-        if buf.remaining() != 0 {
+        if bytes.remaining() != 0 {
             return Err(serde_error!(
                 "ExecutionStatus",
                 "Unexpected data after completing deserialization"
@@ -316,10 +430,208 @@ impl ExecutionStatus {
 
         Ok(execution_status)
     }
+
+    /// Reads an `ExecutionStatus` at the start of a buffer, advancing it past the read bytes.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes: &mut &[u8]` - The buffer to read from.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok` if an `ExecutionStatus` could be read, and `Err` otherwise.
+    pub(crate) fn read(bytes: &mut &[u8]) -> Result<Self, TypesError> {
+        let execution_status = match read_variant(bytes, "ExecutionStatus")? {
+            0 => ExecutionStatus::Success,
+            1 => ExecutionStatus::OutOfGas,
+            2 => {
+                let location = AbortLocation::read(bytes)?;
+
+                if bytes.remaining() < U64_SIZE {
+                    return Err(serde_error!("ExecutionStatus", "Not enough data for code"));
+                }
+                let code = bytes.get_u64_le();
+
+                let info = if read_option_tag(bytes, "ExecutionStatus")? {
+                    Some(AbortInfo::read(bytes)?)
+                } else {
+                    None
+                };
+
+                ExecutionStatus::MoveAbort {
+                    location,
+                    code,
+                    info,
+                }
+            }
+            3 => {
+                let location = AbortLocation::read(bytes)?;
+
+                if bytes.remaining() < 2 * U16_SIZE {
+                    return Err(serde_error!(
+                        "ExecutionStatus",
+                        "Not enough data for function and code offset"
+                    ));
+                }
+                let function = bytes.get_u16_le();
+                let code_offset = bytes.get_u16_le();
+
+                ExecutionStatus::ExecutionFailure {
+                    location,
+                    function,
+                    code_offset,
+                }
+            }
+            4 => {
+                let status_code = if read_option_tag(bytes, "ExecutionStatus")? {
+                    if bytes.remaining() < U64_SIZE {
+                        return Err(serde_error!(
+                            "ExecutionStatus",
+                            "Not enough data for status code"
+                        ));
+                    }
+                    Some(bytes.get_u64_le())
+                } else {
+                    None
+                };
+
+                ExecutionStatus::MiscellaneousError(status_code)
+            }
+            variant => {
+                return Err(TypesError::UnsupportedVariant {
+                    structure: "ExecutionStatus".into(),
+                    variant,
+                })
+            }
+        };
+
+        Ok(execution_status)
+    }
+}
+
+/// Reads the tag of a serialized `Option`, advancing the buffer past it.
+///
+/// # Arguments
+///
+/// * `bytes: &mut &[u8]` - The buffer to read from.
+/// * `structure: &str` - The name of the structure being deserialized.
+///
+/// # Returns
+///
+/// A `Result` which is `Ok` with `true` if the option holds a value,
+/// `false` if it is empty, and `Err` if the tag is invalid.
+fn read_option_tag(bytes: &mut &[u8], structure: &str) -> Result<bool, TypesError> {
+    if bytes.remaining() < ENUM_VARIANT_LEN {
+        return Err(serde_error!(structure, "Not enough data for option tag"));
+    }
+
+    match bytes.get_u8() {
+        0 => Ok(false),
+        1 => Ok(true),
+        _ => Err(serde_error!(structure, "Invalid option tag")),
+    }
+}
+
+/// `AbortLocation` is the location of the code that aborted
+/// the execution of a transaction.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AbortLocation {
+    /// The execution aborted in a published module.
+    Module(ModuleId),
+    /// The execution aborted in the script of the transaction.
+    Script,
+}
+
+impl AbortLocation {
+    /// Converts the `AbortLocation` to a byte vector.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<u8>` representing the `AbortLocation`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = BytesMut::new();
+        match self {
+            AbortLocation::Module(module_id) => {
+                bytes.put_u8(0);
+                bytes.put_slice(&module_id.to_bytes());
+            }
+            AbortLocation::Script => bytes.put_u8(1),
+        }
+        bytes.to_vec()
+    }
+
+    /// Reads an `AbortLocation` at the start of a buffer, advancing it past the read bytes.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes: &mut &[u8]` - The buffer to read from.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok` if an `AbortLocation` could be read, and `Err` otherwise.
+    pub(crate) fn read(bytes: &mut &[u8]) -> Result<Self, TypesError> {
+        match read_variant(bytes, "AbortLocation")? {
+            0 => Ok(AbortLocation::Module(ModuleId::read(bytes)?)),
+            1 => Ok(AbortLocation::Script),
+            variant => Err(TypesError::UnsupportedVariant {
+                structure: "AbortLocation".into(),
+                variant,
+            }),
+        }
+    }
+}
+
+/// `AbortInfo` is the human readable description of the
+/// reason a Move module aborted.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Getters)]
+#[getset(get = "pub")]
+pub struct AbortInfo {
+    /// The name of the error constant of the abort code.
+    reason_name: String,
+    /// The documentation of the error constant.
+    description: String,
+}
+
+impl AbortInfo {
+    /// Converts the `AbortInfo` to a byte vector.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<u8>` representing the `AbortInfo`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = BytesMut::new();
+        write_bytes(&mut bytes, self.reason_name.as_bytes());
+        write_bytes(&mut bytes, self.description.as_bytes());
+        bytes.to_vec()
+    }
+
+    /// Reads an `AbortInfo` at the start of a buffer, advancing it past the read bytes.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes: &mut &[u8]` - The buffer to read from.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok` if an `AbortInfo` could be read, and `Err` otherwise.
+    pub(crate) fn read(bytes: &mut &[u8]) -> Result<Self, TypesError> {
+        let reason_name = String::from_utf8(read_bytes(bytes, "AbortInfo")?)
+            .map_err(|e| serde_error!("AbortInfo", e))?;
+        let description = String::from_utf8(read_bytes(bytes, "AbortInfo")?)
+            .map_err(|e| serde_error!("AbortInfo", e))?;
+
+        Ok(Self {
+            reason_name,
+            description,
+        })
+    }
 }
 
 #[cfg(test)]
 mod test {
+    use proptest::prelude::ProptestConfig;
+    use proptest::proptest;
+
     #[test]
     fn test_bytes_conversion_execution_status() {
         use crate::types::transaction::ExecutionStatus;
@@ -337,6 +649,152 @@ mod test {
         assert_eq!(execution_status_ser_bcs, execution_status_to_bytes);
     }
 
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(50))]
+        #[test]
+        fn test_bytes_conversion_failed_execution_status(
+            code in proptest::num::u64::ANY,
+            function in proptest::num::u16::ANY,
+            code_offset in proptest::num::u16::ANY,
+            address in proptest::array::uniform32(proptest::num::u8::ANY),
+            module in "[a-z_]{1,16}",
+            reason_name in "[A-Z_]{0,16}",
+            description in ".{0,32}",
+        ) {
+            use crate::types::language_storage::ModuleId;
+            use crate::types::transaction::{AbortInfo, AbortLocation, ExecutionStatus};
+            use crate::types::AccountAddress;
+
+            let location = AbortLocation::Module(ModuleId::new(AccountAddress::new(address), &module));
+            let execution_statuses = [
+                ExecutionStatus::OutOfGas,
+                ExecutionStatus::MoveAbort {
+                    location: location.clone(),
+                    code,
+                    info: Some(AbortInfo { reason_name, description }),
+                },
+                ExecutionStatus::MoveAbort {
+                    location: AbortLocation::Script,
+                    code,
+                    info: None,
+                },
+                ExecutionStatus::ExecutionFailure {
+                    location,
+                    function,
+                    code_offset,
+                },
+                ExecutionStatus::MiscellaneousError(Some(code)),
+                ExecutionStatus::MiscellaneousError(None),
+            ];
+
+            for execution_status in execution_statuses {
+                let execution_status_ser_bcs = bcs::to_bytes(&execution_status).unwrap();
+
+                assert_eq!(execution_status.to_bytes(), execution_status_ser_bcs);
+                assert_eq!(
+                    ExecutionStatus::from_bytes(&execution_status_ser_bcs).unwrap(),
+                    execution_status
+                );
+                assert!(ExecutionStatus::from_bytes(
+                    &execution_status_ser_bcs[..execution_status_ser_bcs.len() - 1]
+                )
+                .is_err());
+            }
+        }
+    }
+
+    #[test]
+    fn test_unsupported_variants() {
+        use crate::crypto::hash::HashValue;
+        use crate::types::error::TypesError;
+        use crate::types::transaction::{ExecutionStatus, TransactionInfo, TransactionInfoV0};
+
+        assert!(matches!(
+            ExecutionStatus::from_bytes(&[5]),
+            Err(TypesError::UnsupportedVariant { variant: 5, .. })
+        ));
+
+        let transaction = TransactionInfo::V0(TransactionInfoV0 {
+            gas_used: 5,
+            status: ExecutionStatus::Success,
+            transaction_hash: HashValue::new([1; 32]),
+            event_root_hash: HashValue::new([2; 32]),
+            state_change_hash: HashValue::new([3; 32]),
+            state_checkpoint_hash: None,
+            state_cemetery_hash: None,
+        });
+
+        // A `TransactionInfoV1` is rejected instead of being read as a `TransactionInfoV0`
+        let mut transaction_bytes = transaction.to_bytes();
+        transaction_bytes[0] = 1;
+        assert!(matches!(
+            TransactionInfo::from_bytes(&transaction_bytes),
+            Err(TypesError::UnsupportedVariant { variant: 1, .. })
+        ));
+
+        // Variant indices are ULEB128-encoded
+        assert!(matches!(
+            TransactionInfo::from_bytes(&[0x80, 0x01]),
+            Err(TypesError::UnsupportedVariant { variant: 128, .. })
+        ));
+        assert!(TransactionInfo::from_bytes(&[]).is_err());
+    }
+
+    #[test]
+    fn test_bytes_conversion_transaction_info_without_checkpoint() {
+        use crate::crypto::hash::HashValue;
+        use crate::types::transaction::{ExecutionStatus, TransactionInfo, TransactionInfoV0};
+
+        // Only state checkpoint transactions have a state checkpoint hash
+        for state_checkpoint_hash in [None, Some(HashValue::new([4; 32]))] {
+            let transaction = TransactionInfo::V0(TransactionInfoV0 {
+                gas_used: 5,
+                status: ExecutionStatus::Success,
+                transaction_hash: HashValue::new([1; 32]),
+                event_root_hash: HashValue::new([2; 32]),
+                state_change_hash: HashValue::new([3; 32]),
+                state_checkpoint_hash,
+                state_cemetery_hash: None,
+            });
+            let transaction_ser_bcs = bcs::to_bytes(&transaction).unwrap();
+
+            assert_eq!(transaction.to_bytes(), transaction_ser_bcs);
+            assert_eq!(
+                TransactionInfo::from_bytes(&transaction_ser_bcs).unwrap(),
+                transaction
+            );
+            assert!(TransactionInfo::from_bytes(
+                &transaction_ser_bcs[..transaction_ser_bcs.len() - 1]
+            )
+            .is_err());
+        }
+    }
+
+    #[cfg(feature = "aptos")]
+    #[test]
+    fn test_bytes_conversion_user_transaction_info() {
+        use crate::aptos_test_utils::wrapper::AptosWrapper;
+        use crate::types::transaction::TransactionInfo;
+        use aptos_crypto::hash::CryptoHash;
+
+        let mut aptos_wrapper = AptosWrapper::new(40, 1, 1).unwrap();
+        aptos_wrapper.generate_traffic().unwrap();
+
+        let proof_assets = aptos_wrapper.get_latest_proof_transaction().unwrap();
+
+        let aptos_transaction = proof_assets.transaction();
+        let aptos_transaction_bytes = bcs::to_bytes(aptos_transaction).unwrap();
+
+        let lc_transaction = TransactionInfo::from_bytes(&aptos_transaction_bytes).unwrap();
+
+        assert!(lc_transaction.state_checkpoint().is_none());
+        assert_eq!(lc_transaction.to_bytes(), aptos_transaction_bytes);
+        assert_eq!(
+            lc_transaction.transaction_hash().as_ref(),
+            proof_assets.user_transaction().hash().as_ref()
+        );
+    }
+
     #[cfg(feature = "aptos")]
     #[test]
     fn test_bytes_conversion_transaction_info() {
@@ -381,4 +839,92 @@ mod test {
             AptosCryptoHash::hash(aptos_transaction).as_ref()
         );
     }
+
+    #[cfg(feature = "aptos")]
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(50))]
+        #[test]
+        fn test_bytes_conversion_aptos_execution_status(
+            code in proptest::num::u64::ANY,
+            function in proptest::num::u16::ANY,
+            code_offset in proptest::num::u16::ANY,
+            address in proptest::array::uniform32(proptest::num::u8::ANY),
+            module in "[a-z_]{1,16}",
+            reason_name in "[A-Z_]{0,16}",
+            description in ".{0,32}",
+        ) {
+            use crate::types::transaction::ExecutionStatus;
+            use aptos_sdk::move_types::account_address::AccountAddress;
+            use aptos_sdk::move_types::identifier::Identifier;
+            use aptos_sdk::move_types::language_storage::ModuleId;
+            use aptos_sdk::move_types::vm_status::{AbortLocation, StatusCode};
+            use aptos_types::transaction::{
+                AbortInfo as AptosAbortInfo, ExecutionStatus as AptosExecutionStatus,
+            };
+
+            let location = AbortLocation::Module(ModuleId::new(
+                AccountAddress::new(address),
+                Identifier::new(module).unwrap(),
+            ));
+            let aptos_execution_statuses = [
+                AptosExecutionStatus::Success,
+                AptosExecutionStatus::OutOfGas,
+                AptosExecutionStatus::MoveAbort {
+                    location: location.clone(),
+                    code,
+                    info: Some(AptosAbortInfo { reason_name, description }),
+                },
+                AptosExecutionStatus::MoveAbort {
+                    location: AbortLocation::Script,
+                    code,
+                    info: None,
+                },
+                AptosExecutionStatus::ExecutionFailure {
+                    location,
+                    function,
+                    code_offset,
+                },
+                AptosExecutionStatus::MiscellaneousError(Some(StatusCode::TYPE_MISMATCH)),
+                AptosExecutionStatus::MiscellaneousError(None),
+            ];
+
+            for aptos_execution_status in aptos_execution_statuses {
+                let aptos_execution_status_bytes = bcs::to_bytes(&aptos_execution_status).unwrap();
+
+                let lc_execution_status =
+                    ExecutionStatus::from_bytes(&aptos_execution_status_bytes).unwrap();
+
+                assert_eq!(lc_execution_status.to_bytes(), aptos_execution_status_bytes);
+                assert_eq!(
+                    lc_execution_status == ExecutionStatus::Success,
+                    aptos_execution_status.is_success()
+                );
+            }
+        }
+    }
+
+    #[cfg(feature = "aptos")]
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(5))]
+        #[test]
+        fn test_bytes_conversion_aptos_transaction_info(account_idx in 0..40usize) {
+            use crate::aptos_test_utils::wrapper::AptosWrapper;
+            use crate::types::transaction::TransactionInfo;
+
+            let mut aptos_wrapper = AptosWrapper::new(40, 1, 1).unwrap();
+            aptos_wrapper.generate_traffic().unwrap();
+
+            // State checkpoint transaction
+            let proof_assets = aptos_wrapper.get_latest_proof_account(account_idx).unwrap();
+            let aptos_transaction_bytes = bcs::to_bytes(proof_assets.transaction()).unwrap();
+            let lc_transaction = TransactionInfo::from_bytes(&aptos_transaction_bytes).unwrap();
+            assert_eq!(lc_transaction.to_bytes(), aptos_transaction_bytes);
+
+            // User transaction
+            let proof_assets = aptos_wrapper.get_latest_proof_transaction().unwrap();
+            let aptos_transaction_bytes = bcs::to_bytes(proof_assets.transaction()).unwrap();
+            let lc_transaction = TransactionInfo::from_bytes(&aptos_transaction_bytes).unwrap();
+            assert_eq!(lc_transaction.to_bytes(), aptos_transaction_bytes);
+        }
+    }
 }
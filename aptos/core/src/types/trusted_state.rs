@@ -71,14 +71,14 @@ impl TrustedState {
     /// The waypoint of the `TrustedState`.
     pub fn waypoint(&self) -> Waypoint {
         match self {
-            Self::EpochWaypoint(_waypoint) => {
-                unimplemented!("This LC doesn't support epoch waypoints")
-            }
+            Self::EpochWaypoint(waypoint) => *waypoint,
             Self::EpochState { waypoint, .. } => *waypoint,
         }
     }
+
     /// Checks if epoch change verification is required. This
-    /// is the case if the current epoch is behind the target epoch.
+    /// is the case if the current epoch is behind the target epoch,
+    /// or if the trusted state is still an epoch waypoint.
     ///
     /// # Arguments
     ///
@@ -89,9 +89,7 @@ impl TrustedState {
     /// A boolean indicating whether epoch change verification is required.
     fn epoch_change_verification_required(&self, epoch: u64) -> bool {
         match self {
-            Self::EpochWaypoint(_waypoint) => {
-                unimplemented!("This LC doesn't support epoch waypoints")
-            }
+            Self::EpochWaypoint(_) => true,
             Self::EpochState { epoch_state, .. } => {
                 epoch_state.epoch_change_verification_required(epoch)
             }
@@ -99,7 +97,8 @@ impl TrustedState {
     }
 
     /// Checks if a ledger info is stale. This is the case if the ledger info's
-    /// epoch is behind the current trusted epoch.
+    /// epoch is behind the current trusted epoch or, for an epoch waypoint, if
+    /// its version is behind the waypoint version.
     ///
     /// # Arguments
     ///
@@ -110,14 +109,14 @@ impl TrustedState {
     /// A boolean indicating whether the ledger info is stale.
     fn is_ledger_info_stale(&self, ledger_info: &LedgerInfo) -> bool {
         match self {
-            Self::EpochWaypoint(_waypoint) => {
-                unimplemented!("This LC doesn't support epoch waypoints")
-            }
+            Self::EpochWaypoint(waypoint) => ledger_info.version() < waypoint.version(),
             Self::EpochState { epoch_state, .. } => epoch_state.is_ledger_info_stale(ledger_info),
         }
     }
+
     /// Verifies a ledger info with signatures against  the current trusted state.
-    /// This will verify the signatures and the epoch number.
+    /// This will verify the signatures and the epoch number or, for an epoch
+    /// waypoint, that the ledger info matches the waypoint.
     ///
     /// # Arguments
     ///
@@ -128,12 +127,9 @@ impl TrustedState {
     ///
     /// A `Result` which is `Ok` if the ledger info with
     /// signatures is valid, and `Err` otherwise.
-
     fn verify(&self, ledger_info: &LedgerInfoWithSignatures) -> anyhow::Result<()> {
         match self {
-            Self::EpochWaypoint(_waypoint) => {
-                unimplemented!("This LC doesn't support epoch waypoints")
-            }
+            Self::EpochWaypoint(waypoint) => waypoint.verify(ledger_info.ledger_info()),
             Self::EpochState { epoch_state, .. } => epoch_state.verify(ledger_info),
         }
     }
@@ -145,6 +141,10 @@ impl TrustedState {
     /// trusted_state.epoch -> trusted_state.epoch +1, and
     /// verifies it.
     ///
+    /// When the trusted state is an epoch waypoint, the first non-stale ledger
+    /// info of the proof is verified against the waypoint and the following
+    /// ones against the validator sets they lead to.
+    ///
     /// # Arguments
     ///
     /// * `epoch_change_proof: &'a EpochChangeProof` - The epoch change proof to verify.
@@ -281,6 +281,12 @@ impl TrustedState {
     }
 }
 
+impl From<Waypoint> for TrustedState {
+    fn from(waypoint: Waypoint) -> Self {
+        Self::EpochWaypoint(waypoint)
+    }
+}
+
 impl CryptoHash for TrustedState {
     fn hash(&self) -> HashValue {
         HashValue::new(hash_data(
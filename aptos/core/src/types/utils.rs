@@ -1,11 +1,17 @@
 // Copyright (c) Argument Computer Corporation
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::serde_error;
+use crate::types::error::TypesError;
 use anyhow::{anyhow, Result};
+use bytes::{Buf, BufMut, BytesMut};
 
 /// Size in bytes for an enum variant representation.
 pub const ENUM_VARIANT_LEN: usize = 1;
 
+/// Size of a u16 representation in bytes.
+pub const U16_SIZE: usize = 2;
+
 /// Size of a u64 representation in bytes.
 pub const U64_SIZE: usize = 8;
 
@@ -85,6 +91,63 @@ pub fn write_leb128(mut value: u64) -> Vec<u8> {
     result
 }
 
+/// Writes a byte slice prefixed with its LEB128-encoded length.
+///
+/// # Arguments
+///
+/// * `bytes: &mut BytesMut` - The buffer to write to.
+/// * `value: &[u8]` - The byte slice to write.
+pub(crate) fn write_bytes(bytes: &mut BytesMut, value: &[u8]) {
+    bytes.put_slice(&write_leb128(value.len() as u64));
+    bytes.put_slice(value);
+}
+
+/// Reads a byte vector prefixed with its LEB128-encoded length, advancing
+/// the buffer past the read bytes.
+///
+/// # Arguments
+///
+/// * `bytes: &mut &[u8]` - The buffer to read from.
+/// * `structure: &str` - The name of the structure being deserialized.
+///
+/// # Returns
+///
+/// A `Result` which is `Ok` if the byte vector could be read, and `Err` otherwise.
+pub(crate) fn read_bytes(bytes: &mut &[u8], structure: &str) -> Result<Vec<u8>, TypesError> {
+    let (len, read_bytes) =
+        read_leb128(bytes).map_err(|_| serde_error!(structure, "Not enough data for length"))?;
+    bytes.advance(read_bytes);
+
+    let value = bytes
+        .chunk()
+        .get(..len as usize)
+        .ok_or_else(|| serde_error!(structure, "Not enough data for bytes"))?
+        .to_vec();
+    bytes.advance(value.len());
+
+    Ok(value)
+}
+
+/// Reads the ULEB128-encoded index of an enum variant, advancing the buffer
+/// past the read bytes.
+///
+/// # Arguments
+///
+/// * `bytes: &mut &[u8]` - The buffer to read from.
+/// * `structure: &str` - The name of the enum being deserialized.
+///
+/// # Returns
+///
+/// A `Result` which is `Ok` with the index of the variant if it could be read,
+/// and `Err` otherwise.
+pub(crate) fn read_variant(bytes: &mut &[u8], structure: &str) -> Result<u64, TypesError> {
+    let (variant, read_bytes) =
+        read_leb128(bytes).map_err(|_| serde_error!(structure, "Not enough data for variant"))?;
+    bytes.advance(read_bytes);
+
+    Ok(variant)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
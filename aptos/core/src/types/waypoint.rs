@@ -18,9 +18,12 @@ use crate::types::error::TypesError;
 use crate::types::ledger_info::LedgerInfo;
 use crate::types::utils::U64_SIZE;
 use crate::types::Version;
+use anyhow::{ensure, format_err};
 use bytes::{Buf, BufMut, BytesMut};
 use getset::CopyGetters;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
 
 /// Length in bytes of the serialized `Waypoint`.
 pub const WAYPOINT_SIZE: usize = U64_SIZE + HASH_LENGTH;
@@ -67,6 +70,66 @@ impl Waypoint {
         }
     }
 
+    /// Generate a new waypoint given an epoch change LedgerInfo.
+    ///
+    /// # Arguments
+    ///
+    /// * `ledger_info: &LedgerInfo` - The ledger info, that has to end an epoch.
+    ///
+    /// # Returns
+    ///
+    /// A new `Waypoint` committing to the epoch change.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the ledger info does not carry the next epoch state.
+    pub fn new_epoch_boundary(ledger_info: &LedgerInfo) -> anyhow::Result<Self> {
+        ensure!(
+            ledger_info.next_epoch_state().is_some(),
+            "Cannot create a waypoint for a ledger info that does not end an epoch"
+        );
+
+        Ok(Self {
+            version: ledger_info.version(),
+            value: Ledger2WaypointConverter::new(ledger_info).hash(),
+        })
+    }
+
+    /// Verifies that a given LedgerInfo corresponds to the `Waypoint`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ledger_info: &LedgerInfo` - The ledger info to verify.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is `Ok` if the version and the hash of the chosen
+    /// fields of the ledger info match the `Waypoint`, and `Err` otherwise.
+    ///
+    /// # Note
+    ///
+    /// The hash of the ledger info is always computed, regardless of the
+    /// `waypoint` feature. Waypoints created by [`Waypoint::new_any`] without
+    /// the feature will therefore never be verified.
+    pub fn verify(&self, ledger_info: &LedgerInfo) -> anyhow::Result<()> {
+        ensure!(
+            ledger_info.version() == self.version,
+            "Waypoint version mismatch: waypoint version = {}, given version = {}",
+            self.version,
+            ledger_info.version()
+        );
+
+        let value = Ledger2WaypointConverter::new(ledger_info).hash();
+        ensure!(
+            value == self.value,
+            "Waypoint value mismatch: waypoint value = {:x}, given value = {:x}",
+            self.value,
+            value
+        );
+
+        Ok(())
+    }
+
     /// Creates a `Waypoint` from a byte slice.
     ///
     /// # Arguments
@@ -113,6 +176,36 @@ impl Waypoint {
     }
 }
 
+/// Formats the `Waypoint` as `version:value`, with the value hex-encoded,
+/// which is the format used by Aptos for `waypoint.txt` files.
+impl Display for Waypoint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{:x}", self.version, self.value)
+    }
+}
+
+/// Parses a `Waypoint` formatted as `version:value`, with the value hex-encoded.
+impl FromStr for Waypoint {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (version, value) = s
+            .trim()
+            .split_once(':')
+            .ok_or_else(|| format_err!("Waypoint should be formatted as version:value"))?;
+
+        let version = version
+            .parse::<Version>()
+            .map_err(|e| format_err!("Invalid waypoint version: {}", e))?;
+        let value = HashValue::from_slice(
+            hex::decode(value.trim_start_matches("0x"))
+                .map_err(|e| format_err!("Invalid waypoint value: {}", e))?,
+        )?;
+
+        Ok(Self { version, value })
+    }
+}
+
 impl<'de> Deserialize<'de> for Waypoint {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -159,7 +252,6 @@ impl Ledger2WaypointConverter {
     /// # Returns
     ///
     /// A new `Ledger2WaypointConverter`.
-    #[allow(dead_code)]
     pub(crate) fn new(ledger_info: &LedgerInfo) -> Self {
         Self {
             epoch: ledger_info.epoch(),
@@ -226,6 +318,22 @@ mod test {
         assert_eq!(expected, actual.hash());
     }
 
+    #[test]
+    fn test_waypoint_string_round_trip() {
+        let waypoint = Waypoint {
+            version: 42,
+            value: HashValue::new([0xab; HASH_LENGTH]),
+        };
+
+        let waypoint_str = waypoint.to_string();
+        assert_eq!(waypoint_str, format!("42:{}", "ab".repeat(HASH_LENGTH)));
+        assert_eq!(Waypoint::from_str(&waypoint_str).unwrap(), waypoint);
+
+        assert!(Waypoint::from_str("42").is_err());
+        assert!(Waypoint::from_str("version:ab").is_err());
+        assert!(Waypoint::from_str("42:abab").is_err());
+    }
+
     #[cfg(feature = "aptos")]
     mod aptos {
         use proptest::prelude::ProptestConfig;
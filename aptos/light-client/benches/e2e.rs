@@ -133,15 +133,15 @@ fn main() {
 
     // Serialize and prepare merkle and accumulator proofs for the transaction and its inclusion in the ledger
     let sparse_merkle_proof = bcs::to_bytes(proof_assets.state_proof()).unwrap();
-    let key: [u8; 32] = *proof_assets.key().as_ref();
-    let element_hash: [u8; 32] = *proof_assets.state_value_hash().unwrap().as_ref();
+    let state_key = bcs::to_bytes(proof_assets.state_key()).unwrap();
+    let state_value = bcs::to_bytes(proof_assets.state_value().as_ref().unwrap()).unwrap();
 
     let transaction = bcs::to_bytes(&proof_assets.transaction()).unwrap();
     let transaction_proof = bcs::to_bytes(&proof_assets.transaction_proof()).unwrap();
     let latest_li = aptos_wrapper.get_latest_li_bytes().unwrap();
 
     let sparse_merkle_proof_assets =
-        SparseMerkleProofAssets::new(sparse_merkle_proof, key, element_hash);
+        SparseMerkleProofAssets::new(sparse_merkle_proof, state_key, Some(state_value));
 
     let transaction_proof_assets = TransactionProofAssets::new(
         transaction,
@@ -273,8 +273,8 @@ fn prove_inclusion(
 
     // Account inclusion input
     stdin.write(sparse_merkle_proof_assets.sparse_merkle_proof());
-    stdin.write(sparse_merkle_proof_assets.leaf_key());
-    stdin.write(sparse_merkle_proof_assets.leaf_hash());
+    stdin.write(sparse_merkle_proof_assets.state_key());
+    stdin.write(sparse_merkle_proof_assets.state_value());
 
     // Tx inclusion input
     stdin.write(transaction_proof_assets.transaction());
@@ -42,6 +42,9 @@ struct ProvingAssets {
     validator_verifier_assets: ValidatorVerifierAssets,
     // Final state hash
     state_checkpoint_hash: [u8; 32],
+    // Leaf key and value hash derived from the state key and value
+    leaf_key: [u8; 32],
+    leaf_hash: [u8; 32],
 }
 
 impl ProvingAssets {
@@ -62,15 +65,17 @@ impl ProvingAssets {
             .unwrap();
 
         let sparse_merkle_proof = bcs::to_bytes(proof_assets.state_proof()).unwrap();
-        let key: [u8; 32] = *proof_assets.key().as_ref();
-        let element_hash: [u8; 32] = *proof_assets.state_value_hash().unwrap().as_ref();
+        let state_key = bcs::to_bytes(proof_assets.state_key()).unwrap();
+        let state_value = bcs::to_bytes(proof_assets.state_value().as_ref().unwrap()).unwrap();
+        let leaf_key: [u8; 32] = *proof_assets.key().as_ref();
+        let leaf_hash: [u8; 32] = *proof_assets.state_value_hash().unwrap().as_ref();
 
         let transaction = bcs::to_bytes(&proof_assets.transaction()).unwrap();
         let transaction_proof = bcs::to_bytes(&proof_assets.transaction_proof()).unwrap();
         let latest_li = aptos_wrapper.get_latest_li_bytes().unwrap();
 
         let sparse_merkle_proof_assets =
-            SparseMerkleProofAssets::new(sparse_merkle_proof, key, element_hash);
+            SparseMerkleProofAssets::new(sparse_merkle_proof, state_key, Some(state_value));
 
         let state_checkpoint_hash = proof_assets
             .transaction()
@@ -94,6 +99,8 @@ impl ProvingAssets {
             transaction_proof_assets,
             validator_verifier_assets,
             state_checkpoint_hash: *state_checkpoint_hash.as_ref(),
+            leaf_key,
+            leaf_hash,
         }
     }
 
@@ -106,8 +113,8 @@ impl ProvingAssets {
 
         // Account inclusion input: Writes Merkle proof related data to stdin.
         stdin.write(self.sparse_merkle_proof_assets.sparse_merkle_proof());
-        stdin.write(self.sparse_merkle_proof_assets.leaf_key());
-        stdin.write(self.sparse_merkle_proof_assets.leaf_hash());
+        stdin.write(self.sparse_merkle_proof_assets.state_key());
+        stdin.write(self.sparse_merkle_proof_assets.state_value());
 
         // Tx inclusion input: Writes transaction related data to stdin.
         stdin.write(self.transaction_proof_assets.transaction());
@@ -177,16 +184,14 @@ fn main() {
         );
 
         let key: [u8; 32] = inclusion_proof.public_values.read();
-        assert_eq!(
-            key.to_vec(),
-            proving_assets.sparse_merkle_proof_assets.leaf_key(),
-            "Merkle tree key mismatch"
-        );
+        assert_eq!(key, proving_assets.leaf_key, "Merkle tree key mismatch");
+
+        let present: bool = inclusion_proof.public_values.read();
+        assert!(present, "Merkle tree key should be present");
 
         let value: [u8; 32] = inclusion_proof.public_values.read();
         assert_eq!(
-            value.to_vec(),
-            proving_assets.sparse_merkle_proof_assets.leaf_hash(),
+            value, proving_assets.leaf_hash,
             "Merkle tree value mismatch"
         );
 
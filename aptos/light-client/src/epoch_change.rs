@@ -16,6 +16,21 @@ struct EpochChangeOutput {
 
 #[cfg(feature = "aptos")]
 pub fn setup_assets() -> (Vec<u8>, Vec<u8>, Vec<u8>) {
+    setup_assets_with_epoch_changes(1)
+}
+
+/// Sets up the assets for an epoch change proof spanning several consecutive epoch changes.
+///
+/// # Arguments
+///
+/// * `epoch_changes` - The number of epoch changes in the epoch change proof.
+///
+/// # Returns
+///
+/// The trusted state, the epoch change proof and the hash of the validator verifier of the trusted
+/// state.
+#[cfg(feature = "aptos")]
+pub fn setup_assets_with_epoch_changes(epoch_changes: usize) -> (Vec<u8>, Vec<u8>, Vec<u8>) {
     use aptos_lc_core::aptos_test_utils::wrapper::AptosWrapper;
     use aptos_lc_core::crypto::hash::CryptoHash;
 
@@ -37,6 +52,11 @@ pub fn setup_assets() -> (Vec<u8>, Vec<u8>, Vec<u8>) {
 
     aptos_wrapper.generate_traffic().unwrap();
 
+    // The last epoch change is committed when generating the state proof.
+    for _ in 1..epoch_changes {
+        aptos_wrapper.commit_new_epoch().unwrap();
+    }
+
     let state_proof = aptos_wrapper
         .new_state_proof(trusted_state_version)
         .unwrap();
@@ -93,7 +113,7 @@ fn prove_epoch_change(
 
 #[cfg(all(test, feature = "aptos"))]
 mod test {
-    use crate::epoch_change::setup_assets;
+    use crate::epoch_change::{setup_assets, setup_assets_with_epoch_changes, EpochChangeOutput};
     use crate::error::LightClientError;
     use sphinx_sdk::artifacts::try_install_plonk_bn254_artifacts;
     use sphinx_sdk::utils::setup_logger;
@@ -102,7 +122,7 @@ mod test {
     fn execute_epoch_change(
         current_trusted_state: &[u8],
         epoch_change_proof: &[u8],
-    ) -> Result<(), LightClientError> {
+    ) -> Result<EpochChangeOutput, LightClientError> {
         setup_logger();
 
         let mut stdin = SphinxStdin::new();
@@ -111,7 +131,7 @@ mod test {
         stdin.write(&epoch_change_proof);
 
         let client = ProverClient::new();
-        client
+        let (mut public_values, _) = client
             .execute(aptos_programs::EPOCH_CHANGE_PROGRAM, stdin)
             .run()
             .map_err(|err| LightClientError::ProvingError {
@@ -119,7 +139,10 @@ mod test {
                 source: err.into(),
             })?;
 
-        Ok(())
+        Ok(EpochChangeOutput {
+            prev_validator_verifier_hash: public_values.read::<[u8; 32]>(),
+            new_validator_verifier_hash: public_values.read::<[u8; 32]>(),
+        })
     }
 
     #[test]
@@ -134,6 +157,45 @@ mod test {
         println!("Execution took {:?}", start.elapsed());
     }
 
+    #[test]
+    fn test_execute_epoch_change_multiple_epochs() {
+        use aptos_lc_core::crypto::hash::CryptoHash;
+        use aptos_lc_core::types::trusted_state::EpochChangeProof;
+        use std::time::Instant;
+
+        const EPOCH_CHANGES: usize = 3;
+
+        let (trusted_state, epoch_change_proof, validator_verifier_hash) =
+            setup_assets_with_epoch_changes(EPOCH_CHANGES);
+
+        let ledger_infos = EpochChangeProof::from_bytes(&epoch_change_proof)
+            .unwrap()
+            .ledger_info_with_sigs;
+        assert_eq!(ledger_infos.len(), EPOCH_CHANGES);
+        let new_validator_verifier_hash = ledger_infos
+            .last()
+            .unwrap()
+            .ledger_info()
+            .next_epoch_state()
+            .unwrap()
+            .verifier()
+            .hash();
+
+        println!("Starting execution of prove_epoch_change over {EPOCH_CHANGES} epochs...");
+        let start = Instant::now();
+        let output = execute_epoch_change(&trusted_state, &epoch_change_proof).unwrap();
+        println!("Execution took {:?}", start.elapsed());
+
+        assert_eq!(
+            output.prev_validator_verifier_hash,
+            validator_verifier_hash.as_slice()
+        );
+        assert_eq!(
+            &output.new_validator_verifier_hash,
+            new_validator_verifier_hash.as_ref()
+        );
+    }
+
     #[test]
     #[ignore = "This test is too slow for CI"]
     fn test_prove_epoch_change() {
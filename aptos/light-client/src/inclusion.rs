@@ -9,25 +9,33 @@ use sphinx_sdk::{
 };
 
 use crate::error::LightClientError;
+#[cfg(feature = "aptos")]
+use aptos_lc_core::aptos_test_utils::wrapper::{
+    AptosWrapper, SparseMerkleProofAssets as AptosSparseMerkleProofAssets,
+};
+use aptos_lc_core::types::resource::ResourceFields;
 
+/// Assets to prove the inclusion of a state value in the state Sparse Merkle Tree. The
+/// serialized `StateKey` and `StateValue` are hashed by the inclusion program. When
+/// `state_value` is `None`, the assets prove that `state_key` is absent from the tree instead.
 #[derive(Clone, Debug, Getters, Serialize, Deserialize)]
 #[getset(get = "pub")]
 pub struct SparseMerkleProofAssets {
     sparse_merkle_proof: Vec<u8>,
-    leaf_key: [u8; 32],
-    leaf_hash: [u8; 32],
+    state_key: Vec<u8>,
+    state_value: Option<Vec<u8>>,
 }
 
 impl SparseMerkleProofAssets {
     pub const fn new(
         sparse_merkle_proof: Vec<u8>,
-        leaf_key: [u8; 32],
-        leaf_hash: [u8; 32],
+        state_key: Vec<u8>,
+        state_value: Option<Vec<u8>>,
     ) -> SparseMerkleProofAssets {
         SparseMerkleProofAssets {
             sparse_merkle_proof,
-            leaf_key,
-            leaf_hash,
+            state_key,
+            state_value,
         }
     }
 }
@@ -75,7 +83,35 @@ pub fn setup_assets() -> (
     TransactionProofAssets,
     ValidatorVerifierAssets,
 ) {
-    use aptos_lc_core::aptos_test_utils::wrapper::AptosWrapper;
+    setup_assets_with(|aptos_wrapper| aptos_wrapper.get_latest_proof_account(400).unwrap())
+}
+
+#[cfg(feature = "aptos")]
+pub fn setup_coin_store_assets() -> (
+    SparseMerkleProofAssets,
+    TransactionProofAssets,
+    ValidatorVerifierAssets,
+) {
+    setup_assets_with(|aptos_wrapper| aptos_wrapper.get_latest_proof_coin_store(400).unwrap())
+}
+
+#[cfg(feature = "aptos")]
+pub fn setup_non_inclusion_assets() -> (
+    SparseMerkleProofAssets,
+    TransactionProofAssets,
+    ValidatorVerifierAssets,
+) {
+    setup_assets_with(|aptos_wrapper| aptos_wrapper.get_latest_proof_absent_account().unwrap())
+}
+
+#[cfg(feature = "aptos")]
+fn setup_assets_with(
+    get_proof_assets: impl FnOnce(&AptosWrapper) -> AptosSparseMerkleProofAssets,
+) -> (
+    SparseMerkleProofAssets,
+    TransactionProofAssets,
+    ValidatorVerifierAssets,
+) {
     use aptos_lc_core::types::trusted_state::TrustedState;
 
     const NBR_VALIDATORS: usize = 130;
@@ -84,11 +120,14 @@ pub fn setup_assets() -> (
     let mut aptos_wrapper = AptosWrapper::new(500, NBR_VALIDATORS, AVERAGE_SIGNERS_NBR).unwrap();
     aptos_wrapper.generate_traffic().unwrap();
 
-    let proof_assets = aptos_wrapper.get_latest_proof_account(400).unwrap();
+    let proof_assets = get_proof_assets(&aptos_wrapper);
 
     let sparse_merkle_proof = bcs::to_bytes(proof_assets.state_proof()).unwrap();
-    let key: [u8; 32] = *proof_assets.key().as_ref();
-    let element_hash: [u8; 32] = *proof_assets.state_value_hash().unwrap().as_ref();
+    let state_key = bcs::to_bytes(proof_assets.state_key()).unwrap();
+    let state_value = proof_assets
+        .state_value()
+        .as_ref()
+        .map(|state_value| bcs::to_bytes(state_value).unwrap());
 
     let transaction = bcs::to_bytes(&proof_assets.transaction()).unwrap();
     let transaction_proof = bcs::to_bytes(&proof_assets.transaction_proof()).unwrap();
@@ -105,8 +144,8 @@ pub fn setup_assets() -> (
 
     let sparse_merkle_proof_assets = SparseMerkleProofAssets {
         sparse_merkle_proof,
-        leaf_key: key,
-        leaf_hash: element_hash,
+        state_key,
+        state_value,
     };
 
     let transaction_proof_assets = TransactionProofAssets {
@@ -135,8 +174,8 @@ pub fn generate_stdin(
     let mut stdin = SphinxStdin::new();
     // Account inclusion input
     stdin.write(&sparse_merkle_proof_assets.sparse_merkle_proof);
-    stdin.write(&sparse_merkle_proof_assets.leaf_key);
-    stdin.write(&sparse_merkle_proof_assets.leaf_hash);
+    stdin.write(&sparse_merkle_proof_assets.state_key);
+    stdin.write(&sparse_merkle_proof_assets.state_value);
 
     // Tx inclusion input
     stdin.write(&transaction_proof_assets.transaction);
@@ -161,7 +200,12 @@ struct InclusionOutput {
     state_hash: [u8; 32],
     block_hash: [u8; 32],
     key: [u8; 32],
+    /// Whether the key is present in the state, or proven to be absent from it.
+    present: bool,
+    /// Hash of the leaf value, or `SPARSE_MERKLE_PLACEHOLDER_HASH` if the key is absent.
     value: [u8; 32],
+    /// Typed fields of the resource stored at the key, if it could be decoded.
+    resource: ResourceFields,
 }
 
 #[allow(dead_code)]
@@ -194,7 +238,9 @@ fn prove_inclusion(
     let state_hash = proof.public_values.read::<[u8; 32]>();
     let block_hash = proof.public_values.read::<[u8; 32]>();
     let key = proof.public_values.read::<[u8; 32]>();
+    let present = proof.public_values.read::<bool>();
     let value = proof.public_values.read::<[u8; 32]>();
+    let resource = proof.public_values.read::<ResourceFields>();
 
     Ok((
         proof,
@@ -203,7 +249,9 @@ fn prove_inclusion(
             state_hash,
             block_hash,
             key,
+            present,
             value,
+            resource,
         },
     ))
 }
@@ -212,7 +260,8 @@ fn prove_inclusion(
 mod test {
     use crate::error::LightClientError;
     use crate::inclusion::{
-        setup_assets, SparseMerkleProofAssets, TransactionProofAssets, ValidatorVerifierAssets,
+        setup_assets, setup_coin_store_assets, setup_non_inclusion_assets, SparseMerkleProofAssets,
+        TransactionProofAssets, ValidatorVerifierAssets,
     };
     use aptos_lc_core::types::validator::ValidatorVerifier;
     use sphinx_sdk::artifacts::try_install_plonk_bn254_artifacts;
@@ -230,8 +279,8 @@ mod test {
 
         // Account inclusion input
         stdin.write(&sparse_merkle_proof_assets.sparse_merkle_proof);
-        stdin.write(&sparse_merkle_proof_assets.leaf_key);
-        stdin.write(&sparse_merkle_proof_assets.leaf_hash);
+        stdin.write(&sparse_merkle_proof_assets.state_key);
+        stdin.write(&sparse_merkle_proof_assets.state_value);
 
         // Tx inclusion input
         stdin.write(&transaction_proof_assets.transaction);
@@ -272,6 +321,43 @@ mod test {
         println!("Execution took {:?}", start.elapsed());
     }
 
+    #[test]
+    fn test_execute_coin_store_inclusion() {
+        use std::time::Instant;
+
+        let (sparse_merkle_proof_assets, transaction_proof_assets, validator_verifier_assets) =
+            setup_coin_store_assets();
+
+        println!("Starting execution of coin store inclusion...");
+        let start = Instant::now();
+        execute_inclusion(
+            &sparse_merkle_proof_assets,
+            &transaction_proof_assets,
+            &validator_verifier_assets,
+        )
+        .unwrap();
+        println!("Execution took {:?}", start.elapsed());
+    }
+
+    #[test]
+    fn test_execute_non_inclusion() {
+        use std::time::Instant;
+
+        let (sparse_merkle_proof_assets, transaction_proof_assets, validator_verifier_assets) =
+            setup_non_inclusion_assets();
+        assert!(sparse_merkle_proof_assets.state_value().is_none());
+
+        println!("Starting execution of non-inclusion...");
+        let start = Instant::now();
+        execute_inclusion(
+            &sparse_merkle_proof_assets,
+            &transaction_proof_assets,
+            &validator_verifier_assets,
+        )
+        .unwrap();
+        println!("Execution took {:?}", start.elapsed());
+    }
+
     #[test]
     #[ignore = "This test is too slow for CI"]
     fn test_prove_inclusion() {
@@ -301,6 +387,7 @@ mod test {
                 .hash()
                 .as_ref()
         );
+        assert!(matches!(output.resource, ResourceFields::Account { .. }));
 
         println!("Proving took {:?}", start.elapsed());
 
@@ -3,5 +3,7 @@
 
 pub mod epoch_change;
 mod error;
+pub mod event_inclusion;
 pub mod inclusion;
 mod sig;
+pub mod transaction_inclusion;
@@ -0,0 +1,243 @@
+// Copyright (c) Argument Computer Corporation
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::Result;
+use aptos_lc_core::types::transaction::ExecutionStatus;
+use sphinx_sdk::{
+    ProverClient, SphinxProofWithPublicValues, SphinxProvingKey, SphinxStdin, SphinxVerifyingKey,
+};
+
+use crate::error::LightClientError;
+use crate::inclusion::{TransactionProofAssets, ValidatorVerifierAssets};
+
+#[cfg(feature = "aptos")]
+pub fn setup_assets() -> (TransactionProofAssets, ValidatorVerifierAssets) {
+    use aptos_lc_core::aptos_test_utils::wrapper::AptosWrapper;
+    use aptos_lc_core::types::trusted_state::TrustedState;
+
+    const NBR_VALIDATORS: usize = 130;
+    const AVERAGE_SIGNERS_NBR: usize = 95;
+
+    let mut aptos_wrapper = AptosWrapper::new(500, NBR_VALIDATORS, AVERAGE_SIGNERS_NBR).unwrap();
+    aptos_wrapper.generate_traffic().unwrap();
+
+    let proof_assets = aptos_wrapper.get_latest_proof_transaction().unwrap();
+
+    let transaction = bcs::to_bytes(&proof_assets.transaction()).unwrap();
+    let transaction_proof = bcs::to_bytes(&proof_assets.transaction_proof()).unwrap();
+
+    let latest_li = aptos_wrapper.get_latest_li_bytes().unwrap();
+
+    let validator_verifier =
+        match TrustedState::from_bytes(&bcs::to_bytes(&aptos_wrapper.trusted_state()).unwrap())
+            .unwrap()
+        {
+            TrustedState::EpochState { epoch_state, .. } => epoch_state.verifier().clone(),
+            _ => panic!("expected epoch state"),
+        };
+
+    (
+        TransactionProofAssets::new(
+            transaction,
+            *proof_assets.transaction_version(),
+            transaction_proof,
+            latest_li,
+        ),
+        ValidatorVerifierAssets::new(validator_verifier.to_bytes()),
+    )
+}
+
+pub fn generate_stdin(
+    transaction_proof_assets: &TransactionProofAssets,
+    validator_verifier_assets: &ValidatorVerifierAssets,
+) -> SphinxStdin {
+    let mut stdin = SphinxStdin::new();
+    // Tx inclusion input
+    stdin.write(transaction_proof_assets.transaction());
+    stdin.write(transaction_proof_assets.transaction_index());
+    stdin.write(transaction_proof_assets.transaction_proof());
+    stdin.write(transaction_proof_assets.latest_li());
+
+    // Validator verifier
+    stdin.write(validator_verifier_assets.validator_verifier());
+
+    stdin
+}
+
+#[inline]
+pub fn generate_keys(client: &ProverClient) -> (SphinxProvingKey, SphinxVerifyingKey) {
+    client.setup(aptos_programs::TRANSACTION_INCLUSION_PROGRAM)
+}
+
+#[allow(dead_code)]
+struct TransactionInclusionOutput {
+    validator_verifier_hash: [u8; 32],
+    block_hash: [u8; 32],
+    transaction_hash: [u8; 32],
+    transaction_version: u64,
+    gas_used: u64,
+    status: ExecutionStatus,
+}
+
+#[allow(dead_code)]
+fn prove_transaction_inclusion(
+    client: &ProverClient,
+    transaction_proof_assets: &TransactionProofAssets,
+    validator_verifier_assets: &ValidatorVerifierAssets,
+) -> Result<(SphinxProofWithPublicValues, TransactionInclusionOutput), LightClientError> {
+    sphinx_sdk::utils::setup_logger();
+
+    let stdin = generate_stdin(transaction_proof_assets, validator_verifier_assets);
+    let (pk, _) = generate_keys(client);
+
+    let mut proof =
+        client
+            .prove(&pk, stdin)
+            .run()
+            .map_err(|err| LightClientError::ProvingError {
+                program: "prove-transaction-inclusion".to_string(),
+                source: err.into(),
+            })?;
+
+    // Read output.
+    let validator_verifier_hash = proof.public_values.read::<[u8; 32]>();
+    let block_hash = proof.public_values.read::<[u8; 32]>();
+    let transaction_hash = proof.public_values.read::<[u8; 32]>();
+    let transaction_version = proof.public_values.read::<u64>();
+    let gas_used = proof.public_values.read::<u64>();
+    let status = proof.public_values.read::<ExecutionStatus>();
+
+    Ok((
+        proof,
+        TransactionInclusionOutput {
+            validator_verifier_hash,
+            block_hash,
+            transaction_hash,
+            transaction_version,
+            gas_used,
+            status,
+        },
+    ))
+}
+
+#[cfg(all(test, feature = "aptos"))]
+mod test {
+    use crate::error::LightClientError;
+    use crate::transaction_inclusion::{
+        generate_stdin, setup_assets, TransactionProofAssets, ValidatorVerifierAssets,
+    };
+    use aptos_lc_core::crypto::hash::CryptoHash;
+    use aptos_lc_core::types::transaction::{ExecutionStatus, TransactionInfo};
+    use aptos_lc_core::types::validator::ValidatorVerifier;
+    use sphinx_sdk::artifacts::try_install_plonk_bn254_artifacts;
+    use sphinx_sdk::utils::setup_logger;
+    use sphinx_sdk::ProverClient;
+
+    fn execute_transaction_inclusion(
+        transaction_proof_assets: &TransactionProofAssets,
+        validator_verifier_assets: &ValidatorVerifierAssets,
+    ) -> Result<(), LightClientError> {
+        setup_logger();
+
+        let stdin = generate_stdin(transaction_proof_assets, validator_verifier_assets);
+
+        let client = ProverClient::new();
+        client
+            .execute(aptos_programs::TRANSACTION_INCLUSION_PROGRAM, stdin)
+            .run()
+            .map_err(|err| LightClientError::ProvingError {
+                program: "prove-transaction-inclusion".to_string(),
+                source: err.into(),
+            })?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_execute_transaction_inclusion() {
+        use std::time::Instant;
+
+        let (transaction_proof_assets, validator_verifier_assets) = setup_assets();
+
+        println!("Starting execution of transaction inclusion...");
+        let start = Instant::now();
+        execute_transaction_inclusion(&transaction_proof_assets, &validator_verifier_assets)
+            .unwrap();
+        println!("Execution took {:?}", start.elapsed());
+    }
+
+    #[test]
+    #[ignore = "This test is too slow for CI"]
+    fn test_prove_transaction_inclusion() {
+        use super::*;
+        use std::time::Instant;
+        let client = ProverClient::new();
+
+        let (transaction_proof_assets, validator_verifier_assets) = setup_assets();
+
+        let start = Instant::now();
+        println!("Starting generation of transaction inclusion proof...");
+        let (proof, output) = prove_transaction_inclusion(
+            &client,
+            &transaction_proof_assets,
+            &validator_verifier_assets,
+        )
+        .unwrap();
+
+        assert_eq!(
+            &output.validator_verifier_hash,
+            ValidatorVerifier::from_bytes(validator_verifier_assets.validator_verifier())
+                .unwrap()
+                .hash()
+                .as_ref()
+        );
+        let transaction =
+            TransactionInfo::from_bytes(transaction_proof_assets.transaction()).unwrap();
+        assert_eq!(
+            &output.transaction_hash,
+            transaction.transaction_hash().as_ref()
+        );
+        assert_eq!(
+            output.transaction_version,
+            *transaction_proof_assets.transaction_index()
+        );
+        assert_eq!(output.gas_used, transaction.gas_used());
+        assert_eq!(output.status, ExecutionStatus::Success);
+
+        println!("Proving took {:?}", start.elapsed());
+
+        let (_, vk) = generate_keys(&client);
+        let start = Instant::now();
+        println!("Starting verification of transaction inclusion proof...");
+        client.verify(&proof, &vk).unwrap();
+        println!("Verification took {:?}", start.elapsed());
+    }
+
+    #[test]
+    #[ignore = "This test is too slow for CI"]
+    fn test_snark_prove_transaction_inclusion() {
+        use std::time::Instant;
+
+        setup_logger();
+
+        let client = ProverClient::new();
+        let (pk, vk) = super::generate_keys(&client);
+
+        let (transaction_proof_assets, validator_verifier_assets) = setup_assets();
+
+        let stdin = generate_stdin(&transaction_proof_assets, &validator_verifier_assets);
+
+        // Install PLONK artifacts.
+        try_install_plonk_bn254_artifacts(false);
+
+        let start = Instant::now();
+        println!("Starting generation of transaction inclusion proof...");
+        let snark_proof = client.prove(&pk, stdin).plonk().run().unwrap();
+        println!("Proving took {:?}", start.elapsed());
+
+        let start = Instant::now();
+        println!("Starting verification of transaction inclusion proof...");
+        client.verify(&snark_proof, &vk).unwrap();
+        println!("Verification took {:?}", start.elapsed());
+    }
+}
@@ -36,7 +36,7 @@ pub fn main() {
                 println!("cycle-tracker-end: deserialize_epoch_change_proof");
     }
 
-    // Verify and ratchet the trusted state
+    // Verify and ratchet the trusted state through every epoch change of the proof
     sphinx_zkvm::precompiles::unconstrained! {
                 println!("cycle-tracker-start: verify_and_ratchet");
     }
@@ -47,7 +47,7 @@ pub fn main() {
                 println!("cycle-tracker-end: verify_and_ratchet");
     }
 
-    // Extract new trusted state
+    // Extract the validator verifier of the last epoch change
     sphinx_zkvm::precompiles::unconstrained! {
                 println!("cycle-tracker-start: validator_verifier_hash");
     }
@@ -80,6 +80,6 @@ pub fn main() {
                 println!("cycle-tracker-end: hash_prev_validator");
     }
 
-    // Hash new validator verifier and pass the hash as the now trusted state
+    // Commit the hash of the final validator verifier as the now trusted state
     sphinx_zkvm::io::commit(validator_verifier_hash.as_ref());
 }
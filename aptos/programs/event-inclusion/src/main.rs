@@ -0,0 +1,107 @@
+// Copyright (c) Argument Computer Corporation
+// SPDX-License-Identifier: Apache-2.0
+
+#![no_main]
+
+use aptos_lc_core::crypto::hash::CryptoHash;
+use aptos_lc_core::merkle::event_proof::EventAccumulatorProof;
+use aptos_lc_core::merkle::transaction_proof::TransactionAccumulatorProof;
+use aptos_lc_core::types::event::ContractEvent;
+use aptos_lc_core::types::ledger_info::LedgerInfoWithSignatures;
+use aptos_lc_core::types::transaction::TransactionInfo;
+use aptos_lc_core::types::validator::ValidatorVerifier;
+
+sphinx_zkvm::entrypoint!(main);
+
+pub fn main() {
+    sphinx_zkvm::precompiles::unconstrained! {
+                println!("cycle-tracker-start: read_inputs");
+    }
+    // Get inputs for event inclusion
+    let event_bytes = sphinx_zkvm::io::read::<Vec<u8>>();
+    let event_index = sphinx_zkvm::io::read::<u64>();
+    let event_proof = sphinx_zkvm::io::read::<Vec<u8>>();
+
+    // Get inputs for tx inclusion
+    let transaction_bytes = sphinx_zkvm::io::read::<Vec<u8>>();
+    let transaction_index = sphinx_zkvm::io::read::<u64>();
+    let transaction_proof = sphinx_zkvm::io::read::<Vec<u8>>();
+    let ledger_info_bytes = sphinx_zkvm::io::read::<Vec<u8>>();
+
+    // Latest verified validator verifier &  hash
+    let verified_validator_verifier = sphinx_zkvm::io::read::<Vec<u8>>();
+
+    sphinx_zkvm::precompiles::unconstrained! {
+                println!("cycle-tracker-end: read_inputs");
+    }
+
+    // Deserialize Validator Verifier
+    let validator_verifier = ValidatorVerifier::from_bytes(&verified_validator_verifier)
+        .expect("validator_verifier: could not create ValidatorVerifier from bytes");
+
+    // Verify transaction inclusion in the LedgerInfoWithSignatures
+    let transaction = TransactionInfo::from_bytes(&transaction_bytes)
+        .expect("from_bytes: could not deserialize TransactionInfo");
+    let transaction_hash = transaction.hash();
+    let transaction_proof = TransactionAccumulatorProof::from_bytes(&transaction_proof)
+        .expect("from_bytes: could not deserialize TransactionAccumulatorProof");
+    let latest_li = LedgerInfoWithSignatures::from_bytes(&ledger_info_bytes)
+        .expect("from_bytes: could not deserialize LedgerInfo");
+
+    sphinx_zkvm::precompiles::unconstrained! {
+                println!("cycle-tracker-start: verify_transaction_inclusion");
+    }
+
+    let expected_root_hash = latest_li.ledger_info().transaction_accumulator_hash();
+
+    transaction_proof
+        .verify(expected_root_hash, transaction_hash, transaction_index)
+        .expect("verify: could not verify proof");
+    sphinx_zkvm::precompiles::unconstrained! {
+                println!("cycle-tracker-end: verify_transaction_inclusion");
+    }
+
+    // Check signature
+    sphinx_zkvm::precompiles::unconstrained! {
+                println!("cycle-tracker-start: verify_signature");
+    }
+    latest_li
+        .verify_signatures(&validator_verifier)
+        .expect("verify_signatures: could not verify signatures");
+    sphinx_zkvm::precompiles::unconstrained! {
+                    println!("cycle-tracker-end: verify_signature");
+    }
+
+    // Verify event inclusion in the event accumulator of the transaction
+    let event = ContractEvent::from_bytes(&event_bytes)
+        .expect("from_bytes: could not deserialize ContractEvent");
+    let event_hash = event.hash();
+    let event_proof = EventAccumulatorProof::from_bytes(&event_proof)
+        .expect("from_bytes: could not deserialize EventAccumulatorProof");
+
+    sphinx_zkvm::precompiles::unconstrained! {
+                println!("cycle-tracker-start: verify_event_inclusion");
+    }
+    event_proof
+        .verify(transaction.event_root_hash(), event_hash, event_index)
+        .expect("verify: could not verify event proof");
+    sphinx_zkvm::precompiles::unconstrained! {
+                println!("cycle-tracker-end: verify_event_inclusion");
+    }
+
+    // Commit the validator verifier hash
+    sphinx_zkvm::io::commit(validator_verifier.hash().as_ref());
+
+    // Commit current block id
+    let block_hash = latest_li.ledger_info().block_id();
+    sphinx_zkvm::io::commit(block_hash.as_ref());
+
+    // Commit the version of the transaction that emitted the event
+    sphinx_zkvm::io::commit(&transaction_index);
+
+    // Commit the index of the event in the transaction
+    sphinx_zkvm::io::commit(&event_index);
+
+    // Commit the event hash
+    sphinx_zkvm::io::commit(event_hash.as_ref());
+}
@@ -3,10 +3,12 @@
 
 #![no_main]
 
-use aptos_lc_core::crypto::hash::{CryptoHash, HashValue};
-use aptos_lc_core::merkle::sparse_proof::SparseMerkleProof;
+use aptos_lc_core::crypto::hash::CryptoHash;
+use aptos_lc_core::merkle::sparse_proof::{SparseMerkleProof, SPARSE_MERKLE_PLACEHOLDER_HASH};
 use aptos_lc_core::merkle::transaction_proof::TransactionAccumulatorProof;
 use aptos_lc_core::types::ledger_info::LedgerInfoWithSignatures;
+use aptos_lc_core::types::resource::ResourceFields;
+use aptos_lc_core::types::state::{StateKey, StateValue};
 use aptos_lc_core::types::transaction::TransactionInfo;
 use aptos_lc_core::types::validator::ValidatorVerifier;
 
@@ -16,10 +18,10 @@ pub fn main() {
     sphinx_zkvm::precompiles::unconstrained! {
                 println!("cycle-tracker-start: read_inputs");
     }
-    // Get inputs for account inclusion
+    // Get inputs for account inclusion, a `None` state value asks for a proof of non-inclusion
     let sparse_merkle_proof_bytes = sphinx_zkvm::io::read::<Vec<u8>>();
-    let key = sphinx_zkvm::io::read::<[u8; 32]>();
-    let leaf_value_hash = sphinx_zkvm::io::read::<[u8; 32]>();
+    let state_key_bytes = sphinx_zkvm::io::read::<Vec<u8>>();
+    let state_value_bytes = sphinx_zkvm::io::read::<Option<Vec<u8>>>();
 
     // Get inputs for tx inclusion
     let transaction_bytes = sphinx_zkvm::io::read::<Vec<u8>>();
@@ -70,7 +72,7 @@ pub fn main() {
     sphinx_zkvm::precompiles::unconstrained! {
                     println!("cycle-tracker-end: verify_signature");
     }
-    // Verify account inclusion in the SparseMerkleTree
+    // Verify account inclusion, or non-inclusion, in the SparseMerkleTree
     let sparse_merkle_proof = SparseMerkleProof::from_bytes(&sparse_merkle_proof_bytes)
         .expect("from_bytes: could not deserialize SparseMerkleProof");
 
@@ -80,14 +82,25 @@ pub fn main() {
     let sparse_expected_root_hash = transaction
         .state_checkpoint()
         .expect("state_checkpoint: could not get state checkpoint");
-    let reconstructed_root_hash = sparse_merkle_proof
-        .verify_by_hash(
-            sparse_expected_root_hash,
-            HashValue::from_slice(key).expect("key: could not use input to create HashValue"),
-            HashValue::from_slice(leaf_value_hash)
-                .expect("leaf_value_hash: could not use input to create HashValue"),
-        )
-        .expect("verify_by_hash: could not verify proof");
+
+    // Derive the leaf key and value hash from the raw state key and value
+    let state_key =
+        StateKey::from_bytes(&state_key_bytes).expect("from_bytes: could not deserialize StateKey");
+    let state_value = state_value_bytes.map(|state_value_bytes| {
+        StateValue::from_bytes(&state_value_bytes)
+            .expect("from_bytes: could not deserialize StateValue")
+    });
+    let element_key = state_key.hash();
+    let leaf_value_hash = state_value.as_ref().map(CryptoHash::hash);
+
+    let reconstructed_root_hash = match leaf_value_hash {
+        Some(leaf_value_hash) => sparse_merkle_proof
+            .verify_by_hash(sparse_expected_root_hash, element_key, leaf_value_hash)
+            .expect("verify_by_hash: could not verify proof"),
+        None => sparse_merkle_proof
+            .verify_non_inclusion(sparse_expected_root_hash, element_key)
+            .expect("verify_non_inclusion: could not verify proof"),
+    };
     sphinx_zkvm::precompiles::unconstrained! {
                 println!("cycle-tracker-end: verify_merkle_proof");
     }
@@ -103,8 +116,20 @@ pub fn main() {
     sphinx_zkvm::io::commit(block_hash.as_ref());
 
     // Commit key
-    sphinx_zkvm::io::commit(&key);
-
-    // Commit leaf value hash
-    sphinx_zkvm::io::commit(&leaf_value_hash);
+    sphinx_zkvm::io::commit(element_key.as_ref());
+
+    // Commit whether the key is present in the state, or proven to be absent from it
+    sphinx_zkvm::io::commit(&leaf_value_hash.is_some());
+
+    // Commit leaf value hash, or the placeholder hash of an empty subtree if the key is absent
+    sphinx_zkvm::io::commit(
+        leaf_value_hash
+            .unwrap_or(SPARSE_MERKLE_PLACEHOLDER_HASH)
+            .as_ref(),
+    );
+
+    // Commit the typed fields of the resource, if it is one that can be decoded
+    let resource_fields = ResourceFields::decode(&state_key, state_value.as_ref())
+        .expect("decode: could not decode resource fields");
+    sphinx_zkvm::io::commit(&resource_fields);
 }
@@ -0,0 +1,93 @@
+// Copyright (c) Argument Computer Corporation
+// SPDX-License-Identifier: Apache-2.0
+
+#![no_main]
+
+use aptos_lc_core::crypto::hash::CryptoHash;
+use aptos_lc_core::merkle::transaction_proof::TransactionAccumulatorProof;
+use aptos_lc_core::types::ledger_info::LedgerInfoWithSignatures;
+use aptos_lc_core::types::transaction::{ExecutionStatus, TransactionInfo};
+use aptos_lc_core::types::validator::ValidatorVerifier;
+
+sphinx_zkvm::entrypoint!(main);
+
+pub fn main() {
+    sphinx_zkvm::precompiles::unconstrained! {
+                println!("cycle-tracker-start: read_inputs");
+    }
+    // Get inputs for tx inclusion
+    let transaction_bytes = sphinx_zkvm::io::read::<Vec<u8>>();
+    let transaction_index = sphinx_zkvm::io::read::<u64>();
+    let transaction_proof = sphinx_zkvm::io::read::<Vec<u8>>();
+    let ledger_info_bytes = sphinx_zkvm::io::read::<Vec<u8>>();
+
+    // Latest verified validator verifier &  hash
+    let verified_validator_verifier = sphinx_zkvm::io::read::<Vec<u8>>();
+
+    sphinx_zkvm::precompiles::unconstrained! {
+                println!("cycle-tracker-end: read_inputs");
+    }
+
+    // Deserialize Validator Verifier
+    let validator_verifier = ValidatorVerifier::from_bytes(&verified_validator_verifier)
+        .expect("validator_verifier: could not create ValidatorVerifier from bytes");
+
+    // Verify transaction inclusion in the LedgerInfoWithSignatures
+    let transaction = TransactionInfo::from_bytes(&transaction_bytes)
+        .expect("from_bytes: could not deserialize TransactionInfo");
+    let transaction_hash = transaction.hash();
+    let transaction_proof = TransactionAccumulatorProof::from_bytes(&transaction_proof)
+        .expect("from_bytes: could not deserialize TransactionAccumulatorProof");
+    let latest_li = LedgerInfoWithSignatures::from_bytes(&ledger_info_bytes)
+        .expect("from_bytes: could not deserialize LedgerInfo");
+
+    sphinx_zkvm::precompiles::unconstrained! {
+                println!("cycle-tracker-start: verify_transaction_inclusion");
+    }
+
+    let expected_root_hash = latest_li.ledger_info().transaction_accumulator_hash();
+
+    transaction_proof
+        .verify(expected_root_hash, transaction_hash, transaction_index)
+        .expect("verify: could not verify proof");
+    sphinx_zkvm::precompiles::unconstrained! {
+                println!("cycle-tracker-end: verify_transaction_inclusion");
+    }
+
+    // Check signature
+    sphinx_zkvm::precompiles::unconstrained! {
+                println!("cycle-tracker-start: verify_signature");
+    }
+    latest_li
+        .verify_signatures(&validator_verifier)
+        .expect("verify_signatures: could not verify signatures");
+    sphinx_zkvm::precompiles::unconstrained! {
+                    println!("cycle-tracker-end: verify_signature");
+    }
+
+    // Ensure the transaction was successfully executed
+    assert_eq!(
+        transaction.status(),
+        &ExecutionStatus::Success,
+        "status: transaction was not successfully executed"
+    );
+
+    // Commit the validator verifier hash
+    sphinx_zkvm::io::commit(validator_verifier.hash().as_ref());
+
+    // Commit current block id
+    let block_hash = latest_li.ledger_info().block_id();
+    sphinx_zkvm::io::commit(block_hash.as_ref());
+
+    // Commit the hash of the committed user transaction
+    sphinx_zkvm::io::commit(transaction.transaction_hash().as_ref());
+
+    // Commit the version of the transaction
+    sphinx_zkvm::io::commit(&transaction_index);
+
+    // Commit the gas used by the transaction
+    sphinx_zkvm::io::commit(&transaction.gas_used());
+
+    // Commit the execution status of the transaction
+    sphinx_zkvm::io::commit(transaction.status());
+}
@@ -18,6 +18,14 @@
 //! - **Main Loop**: In this phase, the client listens for new data from the Aptos node and generates
 //!   proofs for the verifier to verify.
 //!
+//! If a state directory is given, the client saves its trusted state along with the last verified
+//! epoch change proof and waypoint after each epoch change, and resumes from them at startup instead
+//! of trusting the latest epoch reported by the Aptos node.
+//!
+//! If a waypoint is given and no state has been saved yet, the client bootstraps from the epoch
+//! change ledger info committed to by the waypoint instead of trusting the latest epoch reported by
+//! the Aptos node.
+//!
 //! ## Usage
 //!
 //! For a detailed usage guide, please refer to the dedicated README in `aptos/docs/src/run/setup_client.md`.
@@ -36,12 +44,13 @@
 
 use anyhow::{anyhow, Result};
 use aptos_lc_core::crypto::hash::{CryptoHash, HashValue};
-use aptos_lc_core::types::trusted_state::TrustedState;
+use aptos_lc_core::types::trusted_state::{TrustedState, TrustedStateChange};
 use aptos_lc_core::types::waypoint::Waypoint;
 use backoff::ExponentialBackoff;
 use clap::Parser;
-use log::{debug, error, info};
+use log::{debug, error, info, warn};
 use proof_server::error::ClientError;
+use proof_server::persistence::{PersistedState, StateDirectory};
 use proof_server::types::aptos::{
     AccountInclusionProofResponse, EpochChangeProofResponse, LedgerInfoResponse,
 };
@@ -52,8 +61,11 @@ use proof_server::{
     APTOS_EPOCH_CHANGE_PROOF_ENDPOINT, APTOS_LEDGER_INFO_ENDPOINT,
 };
 use sphinx_sdk::SphinxProofWithPublicValues;
+use std::cmp::Ordering;
 use std::env;
 use std::fmt::Display;
+use std::ops::RangeInclusive;
+use std::path::PathBuf;
 use std::str::FromStr;
 use std::sync::Arc;
 use std::time::Duration;
@@ -64,6 +76,10 @@ use tokio::task::JoinHandle;
 /// Address that will be used to generate the inclusion proof.
 const ACCOUNT: &str = "0x2d91309b5b07a8be428ccd75d0443e81542ffcd059d0ab380cefc552229b1a";
 
+/// Maximum number of epoch changes proven at once when the client is several epochs behind. Larger
+/// ranges are caught up with several consecutive proofs.
+const MAX_EPOCH_CHANGES_PER_PROOF: u64 = 10;
+
 /// A client displaying how one can make requests to the proof server and
 /// handle its responses.
 ///
@@ -78,6 +94,15 @@ struct Cli {
     /// The URL of the Aptos node.
     #[arg(short, long)]
     aptos_node_url: String,
+
+    /// Directory in which the client persists its trusted state, to resume from it after a restart.
+    #[arg(short, long)]
+    state_directory: Option<PathBuf>,
+
+    /// Trusted waypoint, formatted as `version:hash`, from which the client bootstraps when it has
+    /// no saved state. Usually the genesis waypoint of the network.
+    #[arg(short, long)]
+    waypoint: Option<Waypoint>,
 }
 
 /// `ClientState` is a structure meant to hold the state maintained by
@@ -117,7 +142,8 @@ async fn main() -> Result<()> {
     let Cli {
         proof_server_address,
         aptos_node_url,
-        ..
+        state_directory,
+        waypoint,
     } = Cli::parse();
 
     // Initialize the logger
@@ -132,12 +158,19 @@ async fn main() -> Result<()> {
 
     let proof_server_address = Arc::new(proof_server_address);
     let aptos_node_url = Arc::new(aptos_node_url);
+    let state_directory = state_directory.map(StateDirectory::new);
 
     debug!("Initializing client");
     // Try to connect to proof server.
     connect_to_proof_server(&proof_server_address).await?;
     // Initialize the client.
-    let (client_state, verififer_state) = init(&proof_server_address, &aptos_node_url).await?;
+    let (client_state, verififer_state) = init(
+        &proof_server_address,
+        &aptos_node_url,
+        state_directory.as_ref(),
+        waypoint,
+    )
+    .await?;
     debug!("Client initialized successfully");
 
     let client_state: Arc<Mutex<ClientState>> = Arc::new(Mutex::new(client_state));
@@ -155,6 +188,7 @@ async fn main() -> Result<()> {
         proof_server_address.clone(),
         verififer_state,
         client_state.clone(),
+        state_directory,
     ));
 
     // Start the main loop to listen for Aptos data every 10 seconds.
@@ -184,7 +218,7 @@ async fn main() -> Result<()> {
         };
 
         // Check if epoch changed and ig the epoch changed semaphore has a permit available.
-        if aptos_epoch != client_state_epoch && epoch_change_semaphore.available_permits() > 0 {
+        if aptos_epoch > client_state_epoch && epoch_change_semaphore.available_permits() > 0 {
             // Acquire a permit from the semaphore before starting the inclusion task.
             let permit = epoch_change_semaphore
                 .clone()
@@ -192,11 +226,14 @@ async fn main() -> Result<()> {
                 .await
                 .unwrap();
 
-            // Spawn proving task for epoch change proof, and send it to the verifier task.
+            // Spawn proving task for the epochs following the trusted one, so that the chain of
+            // committees is verified in a single proof even when the client is several epochs
+            // behind.
             let task = tokio::spawn(epoch_change_proving_task(
                 proof_server_address.clone(),
                 aptos_node_url.clone(),
-                aptos_epoch,
+                client_state_epoch + 1
+                    ..=aptos_epoch.min(client_state_epoch + MAX_EPOCH_CHANGES_PER_PROOF),
             ));
             task_sender
                 .send(ProofType::EpochChange { task, permit })
@@ -252,14 +289,18 @@ async fn connect_to_proof_server(proof_server_address: &str) -> Result<(), Clien
     Ok(())
 }
 
-/// Method to initialize the client. It fetches the initial data from the Aptos node and generates
-/// the initial state for the client and the verifier. While initializing the client, it handles the
-/// generation of both proof as it would happen in the worst-case scenario.
+/// Method to initialize the client. If a state was saved in the state directory, the client resumes
+/// from it. Otherwise, if a waypoint is given, the client bootstraps from it. Otherwise, it fetches
+/// the initial data from the Aptos node and generates the initial state for the client and the
+/// verifier. While initializing the client, it handles the generation of both proof as it would
+/// happen in the worst-case scenario.
 ///
 /// # Arguments
 ///
 /// * `proof_server_address` - The address of the proof server.
 /// * `aptos_node_url` - The URL of the Aptos node.
+/// * `state_directory` - The directory in which the client state is persisted, if any.
+/// * `waypoint` - The trusted waypoint to bootstrap from, if any.
 ///
 /// # Returns
 ///
@@ -267,9 +308,29 @@ async fn connect_to_proof_server(proof_server_address: &str) -> Result<(), Clien
 async fn init(
     proof_server_address: &Arc<String>,
     aptos_node_url: &Arc<String>,
+    state_directory: Option<&StateDirectory>,
+    waypoint: Option<Waypoint>,
 ) -> Result<(ClientState, VerifierState), ClientError> {
     info!("Initializing client");
 
+    if let Some(state_directory) = state_directory {
+        match state_directory
+            .load()
+            .and_then(|state| state.map(resume).transpose())
+        {
+            Ok(Some(resumed)) => return Ok(resumed),
+            Ok(None) => info!(
+                "No saved state in {}, initializing from the Aptos node",
+                state_directory.path().display()
+            ),
+            Err(err) => warn!("Could not resume from the saved state: {err}"),
+        }
+    }
+
+    if let Some(waypoint) = waypoint {
+        return bootstrap_from_waypoint(aptos_node_url, waypoint).await;
+    }
+
     let ledger_info_request = format!("{}{APTOS_LEDGER_INFO_ENDPOINT}", aptos_node_url);
     let ledger_info: LedgerInfoResponse =
         bcs::from_bytes(&request_aptos_node(&ledger_info_request).await?).map_err(|err| {
@@ -279,12 +340,14 @@ async fn init(
             }
         })?;
 
+    let latest_epoch = u64::from_str(&ledger_info.epoch())
+        .map_err(|err| ClientError::Internal { source: err.into() })?;
+
     // Spawn epoch change proving task and inclusion proving task.
     let epoch_change_task = tokio::spawn(epoch_change_proving_task(
         proof_server_address.clone(),
         aptos_node_url.clone(),
-        u64::from_str(&ledger_info.epoch())
-            .map_err(|err| ClientError::Internal { source: err.into() })?,
+        latest_epoch..=latest_epoch,
     ));
 
     let inclusion_task = tokio::spawn(inclusion_proving_task(
@@ -304,6 +367,7 @@ async fn init(
     let mut inclusion_proof = inclusion_payload?;
 
     let verifier_state = (validator_verifier_hash, HashValue::default());
+    let verified_epoch_change_proof = epoch_change_proof.clone();
 
     let verifier_state = epoch_change_verifying_task(
         proof_server_address.clone(),
@@ -312,6 +376,14 @@ async fn init(
     )
     .await?;
 
+    if let Some(state_directory) = state_directory {
+        state_directory.save(&PersistedState {
+            waypoint: ratcheted_trusted_state.waypoint(),
+            trusted_state: ratcheted_trusted_state.clone(),
+            epoch_change_proof: verified_epoch_change_proof,
+        })?;
+    }
+
     // Verify inclusion proof.
     let verifier_state = inclusion_verifying_task(
         proof_server_address.clone(),
@@ -323,6 +395,147 @@ async fn init(
     Ok((ratcheted_trusted_state, verifier_state))
 }
 
+/// This method restores the client and verifier states from a saved state. The saved epoch change
+/// proof is checked to commit to the validator verifier of the saved trusted state.
+///
+/// # Arguments
+///
+/// * `state` - The saved state.
+///
+/// # Returns
+///
+/// A tuple containing the client state and the verifier state.
+fn resume(state: PersistedState) -> Result<(ClientState, VerifierState), ClientError> {
+    let PersistedState {
+        trusted_state,
+        mut epoch_change_proof,
+        ..
+    } = state;
+
+    let validator_verifier_hash = match &trusted_state {
+        TrustedState::EpochState { epoch_state, .. } => epoch_state.verifier().hash(),
+        _ => {
+            return Err(ClientError::Internal {
+                source: "Expected epoch state".into(),
+            })
+        }
+    };
+
+    // The epoch change proof commits to the previous and to the new validator verifier hashes.
+    let _ = epoch_change_proof.public_values.read::<[u8; 32]>();
+    assert_validator_verifier_predicate(&mut epoch_change_proof, validator_verifier_hash)?;
+
+    info!(
+        "Resuming from saved state at epoch {:?}",
+        trusted_state.epoch()
+    );
+
+    Ok((
+        trusted_state,
+        (validator_verifier_hash, HashValue::default()),
+    ))
+}
+
+/// This method bootstraps the client and verifier states from a trusted waypoint. The epoch change
+/// ledger info committed to by the waypoint is fetched from the Aptos node, verified against the
+/// waypoint and used to ratchet into the epoch it starts.
+///
+/// # Arguments
+///
+/// * `aptos_node_url` - The URL of the Aptos node.
+/// * `waypoint` - The trusted waypoint.
+///
+/// # Returns
+///
+/// A tuple containing the client state and the verifier state.
+///
+/// # Errors
+///
+/// This method returns an error if no epoch change ledger info matches the waypoint version, or if
+/// the matching ledger info does not verify against the waypoint.
+async fn bootstrap_from_waypoint(
+    aptos_node_url: &str,
+    waypoint: Waypoint,
+) -> Result<(ClientState, VerifierState), ClientError> {
+    info!("Bootstrapping from waypoint {waypoint}");
+
+    let ledger_info_request = format!("{}{APTOS_LEDGER_INFO_ENDPOINT}", aptos_node_url);
+    let ledger_info: LedgerInfoResponse =
+        bcs::from_bytes(&request_aptos_node(&ledger_info_request).await?).map_err(|err| {
+            ClientError::ResponsePayload {
+                endpoint: ledger_info_request,
+                source: err.into(),
+            }
+        })?;
+    let latest_epoch = u64::from_str(&ledger_info.epoch())
+        .map_err(|err| ClientError::Internal { source: err.into() })?;
+
+    // Epoch change ledger infos have increasing versions, so the one committed to by the waypoint
+    // is found by bisecting on the epochs.
+    let (mut low, mut high) = (1, latest_epoch);
+    let mut epoch_change_proof_data = None;
+    while low <= high {
+        let epoch = low + (high - low) / 2;
+        let data = fetch_epoch_change_proof_data(aptos_node_url, Some(epoch)).await?;
+        let version = data
+            .epoch_change_proof()
+            .ledger_info_with_sigs
+            .first()
+            .ok_or_else(|| ClientError::Internal {
+                source: "Epoch Change Proof has no LedgerInfoWithSignatures".into(),
+            })?
+            .ledger_info()
+            .version();
+
+        match version.cmp(&waypoint.version()) {
+            Ordering::Equal => {
+                epoch_change_proof_data = Some(data);
+                break;
+            }
+            Ordering::Less => low = epoch + 1,
+            Ordering::Greater => high = epoch - 1,
+        }
+    }
+    let epoch_change_proof_data = epoch_change_proof_data.ok_or_else(|| ClientError::Internal {
+        source: format!(
+            "No epoch change found at waypoint version {}",
+            waypoint.version()
+        )
+        .into(),
+    })?;
+
+    let trusted_state = match TrustedState::from(waypoint)
+        .verify_and_ratchet_inner(epoch_change_proof_data.epoch_change_proof())
+        .map_err(|err| ClientError::Internal { source: err.into() })?
+    {
+        TrustedStateChange::Epoch { new_state, .. } => new_state,
+        _ => {
+            return Err(ClientError::Internal {
+                source: "Expected epoch change".into(),
+            })
+        }
+    };
+
+    let validator_verifier_hash = match &trusted_state {
+        TrustedState::EpochState { epoch_state, .. } => epoch_state.verifier().hash(),
+        _ => {
+            return Err(ClientError::Internal {
+                source: "Expected epoch state".into(),
+            })
+        }
+    };
+
+    info!(
+        "Bootstrapped from waypoint at epoch {:?}",
+        trusted_state.epoch()
+    );
+
+    Ok((
+        trusted_state,
+        (validator_verifier_hash, HashValue::default()),
+    ))
+}
+
 /// This method calls the endpoint to fetch epoch change proof data from the Aptos node and returns
 /// the deserialized payload.
 ///
@@ -496,13 +709,15 @@ fn assert_validator_verifier_predicate(
     Ok(())
 }
 
-/// This method sends a request to the prover to generate an epoch change proof.
+/// This method sends a request to the prover to generate an epoch change proof. The epoch change
+/// data of every epoch in the range is fetched from the Aptos node and merged, so that a single
+/// proof ratchets the trusted state through all of them.
 ///
 /// # Arguments
 ///
 /// * `proof_server_address` - The address of the proof server.
 /// * `aptos_node_url` - The URL of the Aptos node.
-/// * `epoch` - The epoch for which to generate the epoch change proof.
+/// * `epochs` - The consecutive epochs for which to generate the epoch change proof.
 ///
 /// # Errors
 ///
@@ -511,15 +726,28 @@ fn assert_validator_verifier_predicate(
 async fn epoch_change_proving_task(
     proof_server_address: Arc<String>,
     aptos_node_url: Arc<String>,
-    epoch: u64,
+    epochs: RangeInclusive<u64>,
 ) -> Result<(TrustedState, HashValue, SphinxProofWithPublicValues), ClientError> {
-    info!("Starting epoch change proving task for epoch: {}", epoch);
+    info!(
+        "Starting epoch change proving task for epochs: {:?}",
+        epochs
+    );
+
+    let (first_epoch, last_epoch) = epochs.into_inner();
 
-    debug!("Fetching epoch change proof data for epoch: {}", epoch);
-    let epoch_change_proof_data =
-        fetch_epoch_change_proof_data(&aptos_node_url, Some(epoch)).await?;
+    debug!(
+        "Fetching epoch change proof data for epoch: {}",
+        first_epoch
+    );
+    let mut epoch_change_proof_data =
+        fetch_epoch_change_proof_data(&aptos_node_url, Some(first_epoch)).await?;
+    for epoch in first_epoch + 1..=last_epoch {
+        debug!("Fetching epoch change proof data for epoch: {}", epoch);
+        epoch_change_proof_data
+            .append(fetch_epoch_change_proof_data(&aptos_node_url, Some(epoch)).await?);
+    }
 
-    // Retrieve the validator verifier hash for penultimate epoch.
+    // Retrieve the validator verifier hash for the epoch preceding the first epoch change.
     let validator_verifier_hash = match epoch_change_proof_data.trusted_state() {
         TrustedState::EpochState { epoch_state, .. } => epoch_state.verifier().hash(),
         _ => {
@@ -530,11 +758,11 @@ async fn epoch_change_proving_task(
     };
 
     debug!(
-        "Got data for epoch change with penultimate committee hash: {:?}",
+        "Got data for epoch change with starting committee hash: {:?}",
         validator_verifier_hash
     );
 
-    // Request a proof generation for  the latest epoch change.
+    // Request a proof generation for the epoch changes.
     debug!("Sending epoch change proof request to the prover");
 
     let request = Request::ProveEpochChange(Box::new((
@@ -554,15 +782,15 @@ async fn epoch_change_proving_task(
         source: err.into(),
     })?;
 
-    debug!("Epoch change proof for latest epoch received from prover");
+    debug!("Epoch change proof for epochs {first_epoch} to {last_epoch} received from prover");
 
-    // Proving is done, ratchet the client state to the new trusted state.
+    // Proving is done, ratchet the client state to the trusted state of the last epoch change.
     let ledger_info = epoch_change_proof_data
         .epoch_change_proof()
         .ledger_info_with_sigs
-        .first()
+        .last()
         .ok_or_else(|| ClientError::Internal {
-            source: "Epoch Change Proof has more than one LedgerInfoWithSignatures".into(),
+            source: "Epoch Change Proof has no LedgerInfoWithSignatures".into(),
         })?
         .ledger_info()
         .clone();
@@ -725,11 +953,13 @@ async fn inclusion_verifying_task(
 /// * `proof_server_address` - The address of the proof server.
 /// * `initial_verifier_state` - The initial verifier state.
 /// * `client_state` - The client state.
+/// * `state_directory` - The directory in which the client state is persisted, if any.
 async fn verifier_task(
     mut task_receiver: mpsc::Receiver<ProofType>,
     proof_server_address: Arc<String>,
     initial_verifier_state: VerifierState,
     client_state: Arc<Mutex<ClientState>>,
+    state_directory: Option<StateDirectory>,
 ) {
     let mut verifier_state = initial_verifier_state;
 
@@ -743,6 +973,7 @@ async fn verifier_task(
                     Ok(result) => match result {
                         Ok((ratcheted_trusted_state, _, mut epoch_change_proof)) => {
                             debug!("Start verifying epoch change proof");
+                            let verified_epoch_change_proof = epoch_change_proof.clone();
                             let res = epoch_change_verifying_task(
                                 proof_server_address.clone(),
                                 &mut epoch_change_proof,
@@ -752,12 +983,23 @@ async fn verifier_task(
 
                             if let Ok(updated_verifier_state) = res {
                                 verifier_state = updated_verifier_state;
+
+                                if let Some(state_directory) = &state_directory {
+                                    if let Err(e) = state_directory.save(&PersistedState {
+                                        waypoint: ratcheted_trusted_state.waypoint(),
+                                        trusted_state: ratcheted_trusted_state.clone(),
+                                        epoch_change_proof: verified_epoch_change_proof,
+                                    }) {
+                                        error!("Failed to save client state: {:?}", e);
+                                    }
+                                }
+
+                                let mut client_state = client_state.lock().await;
+                                *client_state = ratcheted_trusted_state;
                             } else {
                                 error!("Epoch change proof verification failed: {:?}", res);
                             }
 
-                            let mut client_state = client_state.lock().await;
-                            *client_state = ratcheted_trusted_state;
                             drop(permit)
                         }
                         Err(e) => {
@@ -11,7 +11,7 @@
 //! For a detailed usage guide, please refer to the dedicated README in `aptos/docs/src/run/setup_proof_server.md`.
 
 use anyhow::{Error, Result};
-use aptos_lc::{epoch_change, inclusion};
+use aptos_lc::{epoch_change, inclusion, transaction_inclusion};
 use axum::body::Body;
 use axum::extract::State;
 use axum::http::header::CONTENT_TYPE;
@@ -23,7 +23,7 @@ use axum::Router;
 use clap::{Parser, ValueEnum};
 use log::{error, info};
 use proof_server::types::proof_server::{EpochChangeData, ProvingMode};
-use proof_server::types::proof_server::{InclusionData, Request};
+use proof_server::types::proof_server::{InclusionData, Request, TransactionInclusionData};
 use sphinx_sdk::{ProverClient, SphinxProvingKey, SphinxVerifyingKey};
 use std::cmp::PartialEq;
 use std::sync::atomic::{AtomicUsize, Ordering};
@@ -71,6 +71,8 @@ struct ServerState {
     inclusion_vk: Arc<SphinxVerifyingKey>,
     epoch_pk: Arc<SphinxProvingKey>,
     epoch_vk: Arc<SphinxVerifyingKey>,
+    transaction_inclusion_pk: Arc<SphinxProvingKey>,
+    transaction_inclusion_vk: Arc<SphinxVerifyingKey>,
     snd_addr: Arc<Option<String>>,
     mode: Mode,
     active_requests: Arc<AtomicUsize>,
@@ -95,6 +97,8 @@ async fn main() -> Result<()> {
     let prover_client = Arc::new(ProverClient::default());
     let (inclusion_pk, inclusion_vk) = inclusion::generate_keys(&prover_client);
     let (epoch_pk, epoch_vk) = epoch_change::generate_keys(&prover_client);
+    let (transaction_inclusion_pk, transaction_inclusion_vk) =
+        transaction_inclusion::generate_keys(&prover_client);
 
     let state = ServerState {
         prover_client,
@@ -102,6 +106,8 @@ async fn main() -> Result<()> {
         inclusion_vk: Arc::new(inclusion_vk),
         epoch_pk: Arc::new(epoch_pk),
         epoch_vk: Arc::new(epoch_vk),
+        transaction_inclusion_pk: Arc::new(transaction_inclusion_pk),
+        transaction_inclusion_vk: Arc::new(transaction_inclusion_vk),
         snd_addr: Arc::new(snd_addr),
         mode,
         active_requests: Arc::new(AtomicUsize::new(0)),
@@ -114,6 +120,8 @@ async fn main() -> Result<()> {
         .route("/epoch/proof", post(epoch_proof))
         .route("/epoch/verify", post(epoch_verify))
         .route("/inclusion/verify", post(inclusion_verify))
+        .route("/transaction/proof", post(transaction_inclusion_proof))
+        .route("/transaction/verify", post(transaction_inclusion_verify))
         .layer(axum::middleware::from_fn_with_state(
             state.clone(),
             count_requests_middleware,
@@ -265,6 +273,127 @@ async fn inclusion_verify(
     Ok(response)
 }
 
+async fn transaction_inclusion_proof(
+    State(state): State<ServerState>,
+    request: axum::extract::Request,
+) -> Result<impl IntoResponse, StatusCode> {
+    let bytes = axum::body::to_bytes(request.into_body(), usize::MAX)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    let res = bcs::from_bytes::<Request>(&bytes);
+
+    if let Err(err) = res {
+        error!("Failed to deserialize request object: {err}");
+        return Err(StatusCode::BAD_REQUEST);
+    }
+
+    let request = res.unwrap();
+
+    let Request::ProveTransactionInclusion(boxed) = request else {
+        error!("Invalid request type");
+        return Err(StatusCode::BAD_REQUEST);
+    };
+    let res = {
+        info!("Start proving transaction inclusion");
+
+        let (proof_type, transaction_inclusion_data) = boxed.as_ref();
+        let TransactionInclusionData {
+            transaction_proof_assets,
+            validator_verifier_assets,
+        } = transaction_inclusion_data;
+        let stdin = transaction_inclusion::generate_stdin(
+            transaction_proof_assets,
+            validator_verifier_assets,
+        );
+
+        let prover_client = state.prover_client.clone();
+        let pk = state.transaction_inclusion_pk.clone();
+
+        let proof_handle = if proof_type == &ProvingMode::SNARK {
+            spawn_blocking(move || prover_client.prove(&pk, stdin).plonk().run())
+        } else {
+            spawn_blocking(move || prover_client.prove(&pk, stdin).run())
+        };
+
+        let proof = proof_handle
+            .await
+            .map_err(|_| {
+                error!("Failed to handle generate transaction inclusion proof task");
+                StatusCode::INTERNAL_SERVER_ERROR
+            })?
+            .map_err(|err| {
+                error!("Failed to generate transaction inclusion proof: {err}");
+                StatusCode::INTERNAL_SERVER_ERROR
+            })?;
+        info!("Transaction inclusion proof generated. Serializing");
+        bcs::to_bytes(&proof).map_err(|err| {
+            error!("Failed to serialize transaction inclusion proof: {err}");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })
+    }?;
+
+    let response = Response::builder()
+        .status(StatusCode::OK)
+        .header(CONTENT_TYPE, "application/octet-stream")
+        .body(Body::from(res))
+        .map_err(|err| {
+            error!("Could not construct response for client: {err}");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+
+    Ok(response)
+}
+
+async fn transaction_inclusion_verify(
+    State(state): State<ServerState>,
+    request: axum::extract::Request,
+) -> Result<impl IntoResponse, StatusCode> {
+    let bytes = axum::body::to_bytes(request.into_body(), usize::MAX)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    let res = bcs::from_bytes::<Request>(&bytes);
+
+    if let Err(err) = res {
+        error!("Failed to deserialize request object: {err}");
+        return Err(StatusCode::BAD_REQUEST);
+    }
+
+    let request = res.unwrap();
+
+    let Request::VerifyTransactionInclusion(proof) = request else {
+        error!("Invalid request type");
+        return Err(StatusCode::BAD_REQUEST);
+    };
+    let res = {
+        info!("Start verifying transaction inclusion proof");
+
+        let is_valid = state
+            .prover_client
+            .verify(&proof, &state.transaction_inclusion_vk)
+            .is_ok();
+
+        info!("Transaction inclusion verification result: {}", is_valid);
+
+        bcs::to_bytes(&is_valid).map_err(|_| {
+            error!("Failed to serialize transaction inclusion verification result");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })
+    }?;
+
+    let response = Response::builder()
+        .status(StatusCode::OK)
+        .header(CONTENT_TYPE, "application/octet-stream")
+        .body(Body::from(res))
+        .map_err(|err| {
+            error!("Could not construct response for client: {err}");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+
+    Ok(response)
+}
+
 async fn epoch_proof(
     State(state): State<ServerState>,
     request: axum::extract::Request,
@@ -37,6 +37,12 @@ pub enum ClientError {
     Ratchet {
         source: Box<dyn std::error::Error + Sync + Send>,
     },
+    #[error("Error while accessing the client state at {path}: {source}")]
+    Persistence {
+        path: String,
+        #[source]
+        source: Box<dyn std::error::Error + Sync + Send>,
+    },
     #[error("Internal error: {source}")]
     Internal {
         #[source]
@@ -19,6 +19,8 @@
 
 /// Module containing the errors that can be thrown while using the client and the proof server.
 pub mod error;
+/// Module containing the state directory used by the client to persist its progress across restarts.
+pub mod persistence;
 /// Module containing the types encountered while fetching data from an Aptos Public Full Node and
 /// interacting with the proof server.
 pub mod types;
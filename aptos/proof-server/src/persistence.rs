@@ -0,0 +1,316 @@
+// Copyright (c) Argument Computer Corporation
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Persistence
+//!
+//! This module contains the state directory used by the client to persist its progress across
+//! restarts. The directory holds a single file with the [`PersistedState`], serialized with `bcs`:
+//! - the last ratcheted [`TrustedState`], serialized with [`TrustedState::to_bytes`],
+//! - the last verified epoch change proof,
+//! - the [`Waypoint`] of the last ratcheted [`TrustedState`], serialized with [`Waypoint::to_bytes`].
+//!
+//! The file is written atomically by first writing a temporary file that is then renamed over the
+//! previous one, so that the trusted state and the proof that justifies it are always replaced
+//! together and a crash while saving never leaves a partially written state behind.
+
+use crate::error::ClientError;
+use aptos_lc_core::types::trusted_state::TrustedState;
+use aptos_lc_core::types::waypoint::Waypoint;
+use serde::{Deserialize, Serialize};
+use sphinx_sdk::SphinxProofWithPublicValues;
+use std::fs;
+use std::fs::File;
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+
+/// Name of the file holding the saved state.
+pub const STATE_FILE: &str = "state.bin";
+
+/// Name of the temporary file used while saving the state.
+const STATE_TMP_FILE: &str = "state.bin.tmp";
+
+/// State of the client as saved in the state directory.
+#[derive(Debug, Clone)]
+pub struct PersistedState {
+    /// The last ratcheted trusted state.
+    pub trusted_state: TrustedState,
+    /// The verified epoch change proof that led to the trusted state.
+    pub epoch_change_proof: SphinxProofWithPublicValues,
+    /// The waypoint of the trusted state.
+    pub waypoint: Waypoint,
+}
+
+/// Layout of the state file.
+#[derive(Serialize, Deserialize)]
+struct StateFile {
+    trusted_state: Vec<u8>,
+    epoch_change_proof: SphinxProofWithPublicValues,
+    waypoint: Vec<u8>,
+}
+
+/// State directory in which the client persists its progress.
+#[derive(Debug, Clone)]
+pub struct StateDirectory {
+    path: PathBuf,
+}
+
+impl StateDirectory {
+    /// Creates a new `StateDirectory` at the given path. The directory is created on the first save
+    /// if it does not exist.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the state directory.
+    ///
+    /// # Returns
+    ///
+    /// A new `StateDirectory`.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Returns the path of the state directory.
+    ///
+    /// # Returns
+    ///
+    /// The path of the state directory.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the path of the file holding the saved state.
+    ///
+    /// # Returns
+    ///
+    /// The path of the state file.
+    pub fn state_path(&self) -> PathBuf {
+        self.path.join(STATE_FILE)
+    }
+
+    /// Atomically saves the given state in the state directory, replacing the previous one.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` - The state to save.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the state can't be serialized or written to disk.
+    pub fn save(&self, state: &PersistedState) -> Result<(), ClientError> {
+        let path = self.state_path();
+        let tmp_path = self.path.join(STATE_TMP_FILE);
+
+        let bytes = bcs::to_bytes(&StateFile {
+            trusted_state: state.trusted_state.to_bytes(),
+            epoch_change_proof: state.epoch_change_proof.clone(),
+            waypoint: state.waypoint.to_bytes(),
+        })
+        .map_err(|err| persistence_error(&path, err))?;
+
+        fs::create_dir_all(&self.path).map_err(|err| persistence_error(&self.path, err))?;
+
+        // Write the state to a temporary file and make sure it reached the disk
+        let mut file = File::create(&tmp_path).map_err(|err| persistence_error(&tmp_path, err))?;
+        file.write_all(&bytes)
+            .and_then(|_| file.sync_all())
+            .map_err(|err| persistence_error(&tmp_path, err))?;
+
+        // Replace the previous state
+        fs::rename(&tmp_path, &path).map_err(|err| persistence_error(&path, err))?;
+
+        // Persist the rename itself
+        #[cfg(unix)]
+        File::open(&self.path)
+            .and_then(|directory| directory.sync_all())
+            .map_err(|err| persistence_error(&self.path, err))?;
+
+        Ok(())
+    }
+
+    /// Loads the state saved in the state directory, if any.
+    ///
+    /// # Returns
+    ///
+    /// The saved state, or `None` if no state has been saved yet.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the file can't be read or deserialized, or if it does not
+    /// describe a consistent state.
+    pub fn load(&self) -> Result<Option<PersistedState>, ClientError> {
+        let path = self.state_path();
+        let Some(bytes) = read_if_exists(&path)? else {
+            return Ok(None);
+        };
+
+        let state_file: StateFile =
+            bcs::from_bytes(&bytes).map_err(|err| persistence_error(&path, err))?;
+        let trusted_state = TrustedState::from_bytes(&state_file.trusted_state)
+            .map_err(|err| persistence_error(&path, err))?;
+        let waypoint = Waypoint::from_bytes(&state_file.waypoint)
+            .map_err(|err| persistence_error(&path, err))?;
+
+        if !matches!(trusted_state, TrustedState::EpochState { .. })
+            || trusted_state.waypoint() != waypoint
+        {
+            return Err(persistence_error(
+                &path,
+                "Waypoint does not match the saved trusted state",
+            ));
+        }
+
+        Ok(Some(PersistedState {
+            trusted_state,
+            epoch_change_proof: state_file.epoch_change_proof,
+            waypoint,
+        }))
+    }
+}
+
+/// Reads the content of a file, returning `None` if it does not exist.
+///
+/// # Arguments
+///
+/// * `path` - The path of the file to read.
+///
+/// # Errors
+///
+/// This method returns an error if the file exists but can't be read.
+fn read_if_exists(path: &Path) -> Result<Option<Vec<u8>>, ClientError> {
+    match fs::read(path) {
+        Ok(bytes) => Ok(Some(bytes)),
+        Err(err) if err.kind() == ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(persistence_error(path, err)),
+    }
+}
+
+/// Utility to create a `ClientError::Persistence` for the given path.
+///
+/// # Arguments
+///
+/// * `path` - The path that could not be accessed.
+/// * `source` - The source of the error.
+///
+/// # Returns
+///
+/// The `ClientError`.
+fn persistence_error(
+    path: &Path,
+    source: impl Into<Box<dyn std::error::Error + Sync + Send>>,
+) -> ClientError {
+    ClientError::Persistence {
+        path: path.display().to_string(),
+        source: source.into(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::error::ClientError;
+    use crate::persistence::{PersistedState, StateDirectory, STATE_TMP_FILE};
+    use aptos_lc_core::types::epoch_state::EpochState;
+    use aptos_lc_core::types::trusted_state::TrustedState;
+    use aptos_lc_core::types::validator::ValidatorVerifier;
+    use aptos_lc_core::types::waypoint::Waypoint;
+    use sphinx_sdk::{SphinxProof, SphinxProofWithPublicValues, SphinxPublicValues, SphinxStdin};
+    use std::fs;
+
+    fn state_directory(name: &str) -> StateDirectory {
+        StateDirectory::new(std::env::temp_dir().join(format!(
+            "aptos-lc-state-directory-{name}-{}",
+            std::process::id()
+        )))
+    }
+
+    fn waypoint(version: u64) -> Waypoint {
+        let mut bytes = version.to_le_bytes().to_vec();
+        bytes.extend_from_slice(&[version as u8; 32]);
+        Waypoint::from_bytes(&bytes).unwrap()
+    }
+
+    fn persisted_state(epoch: u64) -> PersistedState {
+        let waypoint = waypoint(epoch);
+
+        PersistedState {
+            trusted_state: TrustedState::EpochState {
+                waypoint,
+                epoch_state: EpochState {
+                    epoch,
+                    verifier: ValidatorVerifier::new(vec![]),
+                },
+            },
+            epoch_change_proof: SphinxProofWithPublicValues {
+                proof: SphinxProof::Core(vec![]),
+                stdin: SphinxStdin::new(),
+                public_values: SphinxPublicValues::from(&[epoch as u8; 64]),
+                sphinx_version: String::new(),
+            },
+            waypoint,
+        }
+    }
+
+    #[test]
+    fn test_state_directory_persistence() {
+        let state_directory = state_directory("persistence");
+
+        // Nothing to resume from before the first save
+        assert!(state_directory.load().unwrap().is_none());
+
+        state_directory.save(&persisted_state(1)).unwrap();
+        state_directory.save(&persisted_state(2)).unwrap();
+
+        let state = state_directory.load().unwrap().unwrap();
+        let expected = persisted_state(2);
+
+        // The last saved state replaces the previous one as a whole
+        assert_eq!(
+            state.trusted_state.to_bytes(),
+            expected.trusted_state.to_bytes()
+        );
+        assert_eq!(state.waypoint, expected.waypoint);
+        assert_eq!(
+            state.epoch_change_proof.public_values.bytes(),
+            expected.epoch_change_proof.public_values.bytes()
+        );
+        assert!(!state_directory.path().join(STATE_TMP_FILE).exists());
+
+        fs::remove_dir_all(state_directory.path()).unwrap();
+    }
+
+    #[test]
+    fn test_state_directory_inconsistent_state() {
+        let state_directory = state_directory("inconsistent");
+
+        // A waypoint that is not the one of the trusted state is refused
+        let mut state = persisted_state(1);
+        state.waypoint = waypoint(2);
+        state_directory.save(&state).unwrap();
+
+        assert!(matches!(
+            state_directory.load(),
+            Err(ClientError::Persistence { .. })
+        ));
+
+        // A trusted state that is not in a verified epoch is refused
+        let mut state = persisted_state(1);
+        state.trusted_state = TrustedState::EpochWaypoint(state.waypoint);
+        state_directory.save(&state).unwrap();
+
+        assert!(matches!(
+            state_directory.load(),
+            Err(ClientError::Persistence { .. })
+        ));
+
+        // A partially written state is refused
+        state_directory.save(&persisted_state(1)).unwrap();
+        let bytes = fs::read(state_directory.state_path()).unwrap();
+        fs::write(state_directory.state_path(), &bytes[..bytes.len() / 2]).unwrap();
+
+        assert!(matches!(
+            state_directory.load(),
+            Err(ClientError::Persistence { .. })
+        ));
+
+        fs::remove_dir_all(state_directory.path()).unwrap();
+    }
+}
@@ -5,10 +5,10 @@ use crate::types::proof_server::{EpochChangeData, InclusionData};
 use aptos_lc::inclusion::{
     SparseMerkleProofAssets, TransactionProofAssets, ValidatorVerifierAssets,
 };
-use aptos_lc_core::crypto::hash::HashValue;
 use aptos_lc_core::merkle::sparse_proof::SparseMerkleProof;
 use aptos_lc_core::merkle::transaction_proof::TransactionAccumulatorProof;
 use aptos_lc_core::types::ledger_info::LedgerInfoWithSignatures;
+use aptos_lc_core::types::state::{StateKey, StateValue};
 use aptos_lc_core::types::transaction::TransactionInfo;
 use aptos_lc_core::types::trusted_state::{EpochChangeProof, TrustedState};
 use aptos_lc_core::types::validator::ValidatorVerifier;
@@ -54,6 +54,20 @@ impl EpochChangeProofResponse {
     pub const fn trusted_state(&self) -> &TrustedState {
         &self.trusted_state
     }
+
+    /// Appends the epoch change proof of the response for the following epoch, so that the epoch
+    /// change proof spans several consecutive epoch changes. The trusted state remains the one
+    /// preceding the first epoch change.
+    ///
+    /// # Arguments
+    ///
+    /// * `next` - The response for the following epoch.
+    pub fn append(&mut self, next: Self) {
+        self.epoch_change_proof
+            .ledger_info_with_sigs
+            .extend(next.epoch_change_proof.ledger_info_with_sigs);
+        self.epoch_change_proof.more = next.epoch_change_proof.more;
+    }
 }
 
 impl From<EpochChangeProofResponse> for EpochChangeData {
@@ -71,10 +85,10 @@ impl From<EpochChangeProofResponse> for EpochChangeData {
 pub struct AccountInclusionProofResponse {
     /// Proof for the account inclusion
     state_proof: SparseMerkleProof,
-    /// Account leaf key
-    element_key: HashValue,
-    /// Account state value
-    element_hash: HashValue,
+    /// Account state key
+    state_key: StateKey,
+    /// Account state value, `None` if the proof is a non-inclusion proof
+    state_value: Option<StateValue>,
     /// Proof for the transaction inclusion
     transaction_proof: TransactionAccumulatorProof,
     /// Hashed representation of the transaction
@@ -92,8 +106,8 @@ impl From<AccountInclusionProofResponse> for InclusionData {
         InclusionData {
             sparse_merkle_proof_assets: SparseMerkleProofAssets::new(
                 val.state_proof.to_bytes(),
-                *val.element_key.as_ref(),
-                *val.element_hash.as_ref(),
+                val.state_key.to_bytes(),
+                val.state_value.map(|state_value| state_value.to_bytes()),
             ),
             transaction_proof_assets: TransactionProofAssets::new(
                 val.transaction.to_bytes(),
@@ -92,8 +92,16 @@ pub struct InclusionData {
     pub validator_verifier_assets: ValidatorVerifierAssets,
 }
 
-/// Main request type for the proof server. It can be used to request both inclusion and epoch
-/// change proofs, as well as their verification. There are two variants for each type of proof:
+/// Data structure used as a payload to request a transaction inclusion proof generation from the
+/// proof server.
+#[derive(Serialize, Deserialize)]
+pub struct TransactionInclusionData {
+    pub transaction_proof_assets: TransactionProofAssets,
+    pub validator_verifier_assets: ValidatorVerifierAssets,
+}
+
+/// Main request type for the proof server. It can be used to request inclusion, transaction
+/// inclusion and epoch change proofs, as well as their verification. There are two variants for each type of proof:
 /// one using the [`SphinxProof`] type and another using the [`SphinxGroth16Proof`] type.
 #[derive(Serialize, Deserialize)]
 pub enum Request {
@@ -101,6 +109,8 @@ pub enum Request {
     ProveEpochChange(Box<(ProvingMode, EpochChangeData)>),
     VerifyInclusion(SphinxProofWithPublicValues),
     VerifyEpochChange(SphinxProofWithPublicValues),
+    ProveTransactionInclusion(Box<(ProvingMode, TransactionInclusionData)>),
+    VerifyTransactionInclusion(SphinxProofWithPublicValues),
 }
 
 impl Display for &Request {
@@ -110,6 +120,8 @@ impl Display for &Request {
             Request::ProveEpochChange(_) => write!(f, "ProveEpochChange"),
             Request::VerifyInclusion(_) => write!(f, "VerifyInclusion"),
             Request::VerifyEpochChange(_) => write!(f, "VerifyEpochChange"),
+            Request::ProveTransactionInclusion(_) => write!(f, "ProveTransactionInclusion"),
+            Request::VerifyTransactionInclusion(_) => write!(f, "VerifyTransactionInclusion"),
         }
     }
 }
@@ -14,6 +14,18 @@ contract Wrapper is SphinxPlonkVerifier, Ownable(msg.sender) {
     error ErrorUnexpectedSignerHash();
     error ErrorUnexpectedInclusionFixture();
     error ErrorUnexpectedEpochChangeFixture();
+    error ErrorUnexpectedPresenceFlag();
+    error ErrorUnexpectedResourceFields();
+
+    // Offset of the resource fields in the inclusion public values, after the hashes, the key, the presence flag and
+    // the value hash
+    uint256 private constant RESOURCE_FIELDS_OFFSET = 32 + 32 + 32 + 32 + 1 + 32;
+    // The resource fields start with their variant, encoded as a little-endian u32
+    uint256 private constant RESOURCE_VARIANT_LENGTH = 4;
+    // Variants of the resource fields, in their declaration order
+    uint32 private constant RESOURCE_OPAQUE = 0;
+    uint32 private constant RESOURCE_ACCOUNT = 1;
+    uint32 private constant RESOURCE_COIN_STORE = 2;
 
     bytes32 private signerHash;
 
@@ -30,7 +42,13 @@ contract Wrapper is SphinxPlonkVerifier, Ownable(msg.sender) {
     }
 
     function verifyInclusion(SphinxProofFixture memory fixture) public view {
-        if (fixture.publicValues.length != 32 + 32 + 32 + 32 + 32) {
+        // The hashes and the presence flag are followed by the resource fields, whose length depends on the resource
+        if (fixture.publicValues.length < RESOURCE_FIELDS_OFFSET + RESOURCE_VARIANT_LENGTH) {
+            revert ErrorUnexpectedInclusionFixture();
+        }
+        uint32 resourceVariant =
+            uint32(readLittleEndian(fixture.publicValues, RESOURCE_FIELDS_OFFSET, RESOURCE_VARIANT_LENGTH));
+        if (fixture.publicValues.length != RESOURCE_FIELDS_OFFSET + resourceFieldsLength(resourceVariant)) {
             revert ErrorUnexpectedInclusionFixture();
         }
 
@@ -64,10 +82,19 @@ contract Wrapper is SphinxPlonkVerifier, Ownable(msg.sender) {
         }
         offset += 32;
 
+        // the key is either proven present in the state or absent from it
+        uint8 presentFlag = uint8(fixture.publicValues[offset]);
+        if (presentFlag > 1) {
+            revert ErrorUnexpectedPresenceFlag();
+        }
+        bool present = presentFlag == 1;
+        offset += 1;
+
         bytes memory value = new bytes(32);
         for (i = 0; i < 32; i++) {
             value[i] = fixture.publicValues[i + offset];
         }
+        offset += 32 + RESOURCE_VARIANT_LENGTH;
 
         if (signerHash != bytes32(signerHashFixture)) {
             revert ErrorUnexpectedSignerHash();
@@ -76,11 +103,49 @@ contract Wrapper is SphinxPlonkVerifier, Ownable(msg.sender) {
         console.log("merkle root hash is: ", uint256(bytes32(merkleRootHash)));
         console.log("block identifier is: ", uint256(bytes32(blockId)));
         console.log("key is: ", uint256(bytes32(key)));
+        console.log("key is present: ", present);
         console.log("value is: ", uint256(bytes32(value)));
+        if (resourceVariant == RESOURCE_ACCOUNT) {
+            console.log("account sequence number is: ", readLittleEndian(fixture.publicValues, offset, 8));
+        } else if (resourceVariant == RESOURCE_COIN_STORE) {
+            console.log("coin store balance is: ", readLittleEndian(fixture.publicValues, offset, 8));
+            uint8 frozenFlag = uint8(fixture.publicValues[offset + 8]);
+            if (frozenFlag > 1) {
+                revert ErrorUnexpectedResourceFields();
+            }
+            console.log("coin store is frozen: ", frozenFlag == 1);
+        }
 
         // allow funds transfer
     }
 
+    // Returns the length of the resource fields of the given variant, including the variant itself
+    function resourceFieldsLength(uint32 resourceVariant) private pure returns (uint256) {
+        if (resourceVariant == RESOURCE_OPAQUE) {
+            return RESOURCE_VARIANT_LENGTH;
+        }
+        if (resourceVariant == RESOURCE_ACCOUNT) {
+            // sequence number (u64)
+            return RESOURCE_VARIANT_LENGTH + 8;
+        }
+        if (resourceVariant == RESOURCE_COIN_STORE) {
+            // balance (u64) and frozen flag (bool)
+            return RESOURCE_VARIANT_LENGTH + 8 + 1;
+        }
+        revert ErrorUnexpectedResourceFields();
+    }
+
+    // Reads a little-endian encoded unsigned integer of the given length from the public values at the given offset
+    function readLittleEndian(bytes memory publicValues, uint256 offset, uint256 length)
+        private
+        pure
+        returns (uint256 result)
+    {
+        for (uint256 i = 0; i < length; i++) {
+            result |= uint256(uint8(publicValues[offset + i])) << (8 * i);
+        }
+    }
+
     function verifyEpochChange(SphinxProofFixture memory fixture) public {
         if (fixture.publicValues.length != 64) {
             revert ErrorUnexpectedEpochChangeFixture();
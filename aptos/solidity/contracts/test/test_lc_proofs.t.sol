@@ -116,6 +116,26 @@ contract SolidityVerificationTest is Test {
         wrapper.verifyInclusion(fixture);
     }
 
+    function testUnexpectedResourceFieldsLengthInclusion() public {
+        SphinxProofFixture memory fixture = loadPlonkInclusionFixture();
+        // account fields (variant 1), with the length of the opaque ones (variant 0)
+        bytes memory publicValues = new bytes(32 + 32 + 32 + 32 + 1 + 32 + 4);
+        publicValues[32 + 32 + 32 + 32 + 1 + 32] = 0x01;
+        fixture.publicValues = publicValues;
+        vm.expectRevert(Wrapper.ErrorUnexpectedInclusionFixture.selector);
+        wrapper.verifyInclusion(fixture);
+    }
+
+    function testUnexpectedResourceFieldsVariantInclusion() public {
+        SphinxProofFixture memory fixture = loadPlonkInclusionFixture();
+        // there are only three variants of resource fields
+        bytes memory publicValues = new bytes(32 + 32 + 32 + 32 + 1 + 32 + 4);
+        publicValues[32 + 32 + 32 + 32 + 1 + 32] = 0x03;
+        fixture.publicValues = publicValues;
+        vm.expectRevert(Wrapper.ErrorUnexpectedResourceFields.selector);
+        wrapper.verifyInclusion(fixture);
+    }
+
     function testFail_FakePublicValuesEpochChange() public {
         SphinxProofFixture memory fixture = loadPlonkEpochChangeFixture();
         bytes memory fakePublicValues = new bytes(fixture.proof.length);
@@ -33,6 +33,8 @@ pub enum MerkleError {
 pub enum RlpError {
     #[error("Received an empty input")]
     EmptyInput,
+    #[error("Expected an RLP list, received an RLP string")]
+    ExpectedList,
     #[error("Received input is too short for {decode_type}: expected {expected}, got {actual}")]
     InputTooShort {
         decode_type: String,
@@ -41,6 +43,18 @@ pub enum RlpError {
     },
     #[error("Leftover data after decoding the RLP item. Expected 0 bytes, got {actual} bytes")]
     LeftoverData { expected: usize, actual: usize },
+    #[error("Unexpected number of items in RLP list for {decode_type}: expected {expected}, got {actual}")]
+    InvalidListLength {
+        decode_type: String,
+        expected: usize,
+        actual: usize,
+    },
+    #[error("Unexpected length for {field}: expected at most {maximum} bytes, got {actual}")]
+    InvalidFieldLength {
+        field: String,
+        maximum: usize,
+        actual: usize,
+    },
     #[error("Error while decoding hexadecimal value")]
     HexDecodeError {
         #[source]
@@ -7,11 +7,13 @@
 //! proofs should be fetch using an RPC endpoint that supports [the EIP-1186](https://eips.ethereum.org/EIPS/eip-1186).
 //! The EIP-1186 is a standard that defines the format of the response of the `eth_getProof` RPC call.
 //!
-//! This module only handles inclusion proofs from the EIP1186.
+//! Both inclusion and exclusion proofs from the EIP1186 are supported. An exclusion proof shows
+//! that a key is absent from the account or storage trie, either because its path ends on an empty
+//! branch slot or because it diverges from the path of a leaf or extension node.
 
 use crate::crypto::hash::{keccak256_hash, HashValue, HASH_LENGTH};
 use crate::deserialization_error;
-use crate::merkle::error::MerkleError;
+use crate::merkle::error::{MerkleError, RlpError};
 use crate::merkle::utils::rlp::{decode_list, paths_match, shared_prefix_length, skip_length};
 use crate::merkle::utils::{get_nibble, rlp::rlp_encode_account};
 use crate::types::error::TypesError;
@@ -23,7 +25,28 @@ use crate::types::{Address, Bytes32, ADDRESS_BYTES_LEN};
 use ethers_core::abi::AbiEncode;
 use ethers_core::types::EIP1186ProofResponse;
 use ethers_core::utils::rlp::encode;
-use getset::Getters;
+use getset::{CopyGetters, Getters};
+
+/// Root of an empty Merkle Patricia trie, `keccak256(rlp(""))`.
+pub const EMPTY_TRIE_ROOT: Bytes32 = [
+    86, 232, 31, 23, 27, 204, 85, 166, 255, 131, 69, 230, 146, 192, 248, 110, 91, 72, 224, 27, 153,
+    108, 173, 192, 1, 98, 47, 181, 227, 99, 180, 33,
+];
+
+/// Code hash of an account that has no code, `keccak256("")`.
+pub const EMPTY_CODE_HASH: Bytes32 = [
+    197, 210, 70, 1, 134, 247, 35, 60, 146, 126, 125, 178, 220, 199, 3, 192, 229, 0, 182, 83, 202,
+    130, 39, 59, 123, 250, 216, 4, 93, 133, 164, 112,
+];
+
+/// RLP encoding of a zero storage value, as returned for absent storage keys.
+const EMPTY_STORAGE_VALUE: [u8; 1] = [0x80];
+
+/// Index of the storage root in an RLP encoded account.
+const ACCOUNT_STORAGE_ROOT_INDEX: usize = 2;
+
+/// Number of fields in an RLP encoded account.
+const ACCOUNT_FIELDS_LENGTH: usize = 4;
 
 /// Bse byte length for the SSZ serialized `EIP1186Proof`.
 pub const EIP1186_PROOF_BASE_BYTE_LENGTH: usize =
@@ -39,6 +62,106 @@ const BRANCH_NODE_LENGTH: usize = 17;
 /// From [the Ethereum documentation](https://ethereum.org/en/developers/docs/data-structures-and-encoding/patricia-merkle-trie/).
 const LEAF_EXTENSION_NODE_LENGTH: usize = 2;
 
+/// Hex-prefix flag of an extension node with an even number of nibbles.
+///
+/// From [the Ethereum documentation](https://ethereum.org/en/developers/docs/data-structures-and-encoding/patricia-merkle-trie/#specification).
+const EXTENSION_EVEN_PREFIX: u8 = 0;
+
+/// Hex-prefix flag of an extension node with an odd number of nibbles.
+///
+/// From [the Ethereum documentation](https://ethereum.org/en/developers/docs/data-structures-and-encoding/patricia-merkle-trie/#specification).
+const EXTENSION_ODD_PREFIX: u8 = 1;
+
+/// Hex-prefix flag of a leaf node with an even number of nibbles.
+///
+/// From [the Ethereum documentation](https://ethereum.org/en/developers/docs/data-structures-and-encoding/patricia-merkle-trie/#specification).
+const LEAF_EVEN_PREFIX: u8 = 2;
+
+/// Hex-prefix flag of a leaf node with an odd number of nibbles.
+///
+/// From [the Ethereum documentation](https://ethereum.org/en/developers/docs/data-structures-and-encoding/patricia-merkle-trie/#specification).
+const LEAF_ODD_PREFIX: u8 = 3;
+
+/// Membership of a key in a Merkle Patricia trie, as attested by a proof.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Membership {
+    /// The key is part of the trie with the expected value.
+    Present,
+    /// The key is not part of the trie.
+    Absent,
+}
+
+impl Membership {
+    /// Returns a boolean indicating if the key is present in the trie.
+    ///
+    /// # Returns
+    ///
+    /// A boolean indicating if the key is present in the trie.
+    pub const fn is_present(&self) -> bool {
+        matches!(self, Membership::Present)
+    }
+}
+
+/// Membership of the account and of each of its storage keys, as attested by an `EIP1186Proof`.
+#[derive(Debug, Clone, PartialEq, Eq, Getters)]
+#[getset(get = "pub")]
+pub struct EIP1186Membership {
+    account: Membership,
+    storage: Vec<Membership>,
+}
+
+/// Fields of an Ethereum account, decoded from its RLP encoding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, CopyGetters)]
+#[getset(get_copy = "pub")]
+pub struct Account {
+    nonce: u64,
+    /// Balance of the account in wei, as a big-endian 256-bit integer.
+    balance: Bytes32,
+    storage_root: HashValue,
+    code_hash: HashValue,
+}
+
+impl Account {
+    /// Decodes an `Account` from its RLP encoding, as found in the state trie.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The RLP encoded account.
+    ///
+    /// # Returns
+    ///
+    /// The decoded `Account`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input is not an RLP list of four items, or if one of the items does
+    /// not fit in its field.
+    pub fn from_rlp_bytes(bytes: &[u8]) -> Result<Self, RlpError> {
+        let fields = decode_list(bytes)?;
+
+        if fields.len() != ACCOUNT_FIELDS_LENGTH {
+            return Err(RlpError::InvalidListLength {
+                decode_type: "Account".into(),
+                expected: ACCOUNT_FIELDS_LENGTH,
+                actual: fields.len(),
+            });
+        }
+
+        let nonce = u64::from_be_bytes(left_pad("nonce", &fields[0])?);
+        let balance = left_pad("balance", &fields[1])?;
+
+        let storage_root = HashValue::new(fixed_hash("storage_root", &fields[2])?);
+        let code_hash = HashValue::new(fixed_hash("code_hash", &fields[3])?);
+
+        Ok(Self {
+            nonce,
+            balance,
+            storage_root,
+            code_hash,
+        })
+    }
+}
+
 /// Data structure the data received from the `eth_getProof` RPC call.
 #[derive(Debug, Clone, PartialEq, Eq, Getters)]
 #[getset(get = "pub")]
@@ -77,7 +200,8 @@ impl TryFrom<EIP1186ProofResponse> for EIP1186Proof {
 }
 
 impl EIP1186Proof {
-    /// Verifies the account proof and the storage proofs against the state root.
+    /// Verifies the account proof and the storage proofs against the state root. Both inclusion
+    /// and exclusion proofs are accepted.
     ///
     /// # Arguments
     ///
@@ -87,32 +211,87 @@ impl EIP1186Proof {
     ///
     /// A boolean indicating if the proofs are valid.
     pub fn verify(&self, state_root: &Bytes32) -> Result<bool, MerkleError> {
+        Ok(self.verify_membership(state_root)?.is_some())
+    }
+
+    /// Decodes the fields of the account of the proof.
+    ///
+    /// # Returns
+    ///
+    /// The decoded `Account`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the encoded account is not a valid RLP encoded account.
+    pub fn decode_account(&self) -> Result<Account, RlpError> {
+        Account::from_rlp_bytes(&self.encoded_account)
+    }
+
+    /// Verifies the account proof and the storage proofs against the state root, and returns
+    /// whether the account and each of the storage keys are present or absent.
+    ///
+    /// # Arguments
+    ///
+    /// * `state_root` - The state root to verify the proofs against.
+    ///
+    /// # Returns
+    ///
+    /// The `EIP1186Membership` of the account and its storage keys if the proofs are valid, `None`
+    /// otherwise.
+    ///
+    /// # Notes
+    ///
+    /// An absent account must be represented as an empty account (zero nonce and balance, empty
+    /// storage root and code hash), and an absent storage key must have a zero value.
+    pub fn verify_membership(
+        &self,
+        state_root: &Bytes32,
+    ) -> Result<Option<EIP1186Membership>, MerkleError> {
+        // Ensure that the storage hash is the one committed in the account
+        let account_fields = decode_list(&self.encoded_account)
+            .map_err(|err| MerkleError::ProofVerification { source: err.into() })?;
+        if account_fields.len() != ACCOUNT_FIELDS_LENGTH
+            || account_fields[ACCOUNT_STORAGE_ROOT_INDEX] != self.storage_hash.as_ref()
+        {
+            return Ok(None);
+        }
+
         let address_hash = keccak256_hash(&self.address)
             .map_err(|err| MerkleError::ProofVerification { source: err.into() })?;
-        if !verify_proof(
+        let account = match verify_proof(
             &self.account_proof,
             state_root,
-            &self.encoded_account,
             address_hash.as_ref(),
+            &self.encoded_account,
         )? {
-            return Ok(false);
-        }
+            Some(Membership::Present) => Membership::Present,
+            Some(Membership::Absent) if is_empty_account(&account_fields) => Membership::Absent,
+            _ => return Ok(None),
+        };
+
+        let mut storage = Vec::with_capacity(self.storage_proof.len());
 
         for storage_proof in &self.storage_proof {
             let key_hash = keccak256_hash(&storage_proof.key)
                 .map_err(|err| MerkleError::ProofVerification { source: err.into() })?;
 
-            if !verify_proof(
+            let membership = match verify_proof(
                 &storage_proof.proof,
                 self.storage_hash.as_ref(),
                 key_hash.as_ref(),
                 &storage_proof.value,
             )? {
-                return Ok(false);
-            }
+                Some(Membership::Present) => Membership::Present,
+                Some(Membership::Absent) if storage_proof.value == EMPTY_STORAGE_VALUE => {
+                    Membership::Absent
+                }
+                _ => return Ok(None),
+            };
+
+            storage.push(membership);
         }
 
-        Ok(true)
+        Ok(Some(EIP1186Membership { account, storage }))
     }
 
     pub fn to_ssz_bytes(&self) -> Vec<u8> {
@@ -369,6 +548,74 @@ impl StorageProof {
     }
 }
 
+/// Left pads a big-endian RLP decoded integer to a fixed size array.
+///
+/// # Arguments
+///
+/// * `field` - The name of the field being decoded.
+/// * `bytes` - The big-endian bytes of the integer.
+///
+/// # Returns
+///
+/// The left padded array.
+///
+/// # Errors
+///
+/// Returns an error if the integer does not fit in the array.
+fn left_pad<const N: usize>(field: &str, bytes: &[u8]) -> Result<[u8; N], RlpError> {
+    if bytes.len() > N {
+        return Err(RlpError::InvalidFieldLength {
+            field: field.into(),
+            maximum: N,
+            actual: bytes.len(),
+        });
+    }
+
+    let mut padded = [0; N];
+    padded[N - bytes.len()..].copy_from_slice(bytes);
+
+    Ok(padded)
+}
+
+/// Converts an RLP decoded hash to a fixed size array.
+///
+/// # Arguments
+///
+/// * `field` - The name of the field being decoded.
+/// * `bytes` - The bytes of the hash.
+///
+/// # Returns
+///
+/// The hash as a fixed size array.
+///
+/// # Errors
+///
+/// Returns an error if the hash is not exactly `HASH_LENGTH` bytes long.
+fn fixed_hash(field: &str, bytes: &[u8]) -> Result<Bytes32, RlpError> {
+    bytes.try_into().map_err(|_| RlpError::InvalidFieldLength {
+        field: field.into(),
+        maximum: HASH_LENGTH,
+        actual: bytes.len(),
+    })
+}
+
+/// Checks if the decoded fields of an account are the ones of an empty account.
+///
+/// # Arguments
+///
+/// * `account_fields` - The RLP decoded fields of the account.
+///
+/// # Returns
+///
+/// A boolean indicating if the account is empty.
+fn is_empty_account(account_fields: &[Vec<u8>]) -> bool {
+    account_fields.len() == ACCOUNT_FIELDS_LENGTH
+        && account_fields[0].is_empty()
+        && account_fields[1].is_empty()
+        && account_fields[2] == EMPTY_TRIE_ROOT
+        && account_fields[3] == EMPTY_CODE_HASH
+}
+
 /// Verifies a proof against a root hash.
 ///
 /// # Arguments
@@ -380,60 +627,100 @@ impl StorageProof {
 ///
 /// # Returns
 ///
-/// A boolean indicating if the proof is valid.
+/// The `Membership` of the path in the tree if the proof is valid, `None` otherwise. When the path
+/// is absent from the tree, the value is not checked.
 fn verify_proof(
     proof: &[Vec<u8>],
     root: &[u8],
     path: &[u8],
     value: &[u8],
-) -> Result<bool, MerkleError> {
+) -> Result<Option<Membership>, MerkleError> {
+    // An empty proof can only attest that the tree is empty
+    if proof.is_empty() {
+        return Ok((root == EMPTY_TRIE_ROOT).then_some(Membership::Absent));
+    }
+
     let mut expected_hash = root.to_vec();
     let mut path_offset = 0;
 
     for (i, node) in proof.iter().enumerate() {
+        let is_last_node = i == proof.len() - 1;
+
         if expected_hash
             != keccak256_hash(node)
                 .map_err(|err| MerkleError::ProofVerification { source: err.into() })?
                 .to_vec()
         {
-            return Ok(false);
+            return Ok(None);
         }
 
         let node_list = decode_list(node)
             .map_err(|err| MerkleError::ProofVerification { source: err.into() })?;
 
         if node_list.len() == BRANCH_NODE_LENGTH {
+            if path_offset >= path.len() * 2 {
+                return Ok(None);
+            }
+
             let nibble = get_nibble(path, path_offset);
-            expected_hash = node_list[nibble as usize].clone();
+            let child = &node_list[nibble as usize];
+
+            // An empty slot in the branch proves that the path is absent
+            if child.is_empty() {
+                return Ok(is_last_node.then_some(Membership::Absent));
+            }
+
+            expected_hash = child.clone();
             path_offset += 1;
         } else if node_list.len() == LEAF_EXTENSION_NODE_LENGTH {
-            if i == proof.len() - 1 && node_list[1] == value {
-                return Ok(paths_match(
-                    &node_list[0],
-                    skip_length(&node_list[0]),
-                    path,
-                    path_offset,
-                ));
-            } else {
-                let node_path = &node_list[0];
-                let prefix_length = shared_prefix_length(path, path_offset, node_path);
-                if prefix_length < node_path.len() * 2 - skip_length(node_path) {
-                    return Ok(false);
+            let node_path = &node_list[0];
+            if node_path.is_empty() {
+                return Ok(None);
+            }
+
+            match get_nibble(node_path, 0) {
+                // Leaf node, it has to be the last node of the proof
+                LEAF_EVEN_PREFIX | LEAF_ODD_PREFIX => {
+                    if !is_last_node {
+                        return Ok(None);
+                    }
+
+                    // A leaf for another path proves that our path is absent
+                    if !paths_match(node_path, skip_length(node_path), path, path_offset) {
+                        return Ok(Some(Membership::Absent));
+                    }
+
+                    return Ok((node_list[1] == value).then_some(Membership::Present));
                 }
-                path_offset += prefix_length;
-                expected_hash = node_list[1].clone();
+                // Extension node
+                EXTENSION_EVEN_PREFIX | EXTENSION_ODD_PREFIX => {
+                    let prefix_length = shared_prefix_length(path, path_offset, node_path);
+
+                    // An extension diverging from our path proves that it is absent
+                    if prefix_length < node_path.len() * 2 - skip_length(node_path) {
+                        return Ok(is_last_node.then_some(Membership::Absent));
+                    }
+
+                    path_offset += prefix_length;
+                    expected_hash = node_list[1].clone();
+                }
+                _ => return Ok(None),
             }
         } else {
-            return Ok(false);
+            return Ok(None);
         }
     }
 
-    Ok(false)
+    Ok(None)
 }
 
 #[cfg(test)]
 mod test {
-    use crate::merkle::storage_proofs::{EIP1186Proof, StorageProof};
+    use crate::crypto::hash::HashValue;
+    use crate::merkle::storage_proofs::{
+        verify_proof, Account, EIP1186Proof, Membership, StorageProof, EMPTY_CODE_HASH,
+        EMPTY_STORAGE_VALUE, EMPTY_TRIE_ROOT,
+    };
     use serde::{Deserialize, Serialize};
     use ssz::Encode;
     use ssz_derive::{Decode, Encode};
@@ -558,4 +845,108 @@ mod test {
 
         assert_eq!(eip1186_proof_bytes, serialized_eip1186_proof_test);
     }
+
+    #[test]
+    fn test_verify_proof_empty_trie() {
+        assert_eq!(
+            verify_proof(&[], &EMPTY_TRIE_ROOT, &[0; 32], &EMPTY_STORAGE_VALUE).unwrap(),
+            Some(Membership::Absent)
+        );
+        assert_eq!(
+            verify_proof(&[], &[1; 32], &[0; 32], &EMPTY_STORAGE_VALUE).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_decode_account() {
+        // Empty account
+        let mut encoded_account = vec![0xf8, 0x44, 0x80, 0x80, 0xa0];
+        encoded_account.extend_from_slice(&EMPTY_TRIE_ROOT);
+        encoded_account.push(0xa0);
+        encoded_account.extend_from_slice(&EMPTY_CODE_HASH);
+
+        let account = Account::from_rlp_bytes(&encoded_account).unwrap();
+        assert_eq!(account.nonce(), 0);
+        assert_eq!(account.balance(), [0; 32]);
+        assert_eq!(account.storage_root(), HashValue::new(EMPTY_TRIE_ROOT));
+        assert_eq!(account.code_hash(), HashValue::new(EMPTY_CODE_HASH));
+
+        // Account with a nonce of 1 and a balance of 1 ether
+        let mut encoded_account = vec![0xf8, 0x4c, 0x01, 0x88];
+        encoded_account.extend_from_slice(&1_000_000_000_000_000_000u64.to_be_bytes());
+        encoded_account.push(0xa0);
+        encoded_account.extend_from_slice(&EMPTY_TRIE_ROOT);
+        encoded_account.push(0xa0);
+        encoded_account.extend_from_slice(&EMPTY_CODE_HASH);
+
+        let account = Account::from_rlp_bytes(&encoded_account).unwrap();
+        let mut expected_balance = [0; 32];
+        expected_balance[24..].copy_from_slice(&1_000_000_000_000_000_000u64.to_be_bytes());
+        assert_eq!(account.nonce(), 1);
+        assert_eq!(account.balance(), expected_balance);
+
+        // A list that is not an account should be rejected
+        assert!(Account::from_rlp_bytes(&[0xc2, 0x80, 0x80]).is_err());
+    }
+
+    #[cfg(feature = "ethereum")]
+    #[test]
+    fn test_verify_membership() {
+        use crate::test_utils::generate_inclusion_test_assets;
+
+        let test_assets = generate_inclusion_test_assets();
+        let state_root = test_assets
+            .finality_update()
+            .finalized_header()
+            .execution()
+            .state_root();
+
+        // The account exists but the storage key of the test assets is not set
+        let membership = test_assets
+            .eip1186_proof()
+            .verify_membership(state_root)
+            .unwrap()
+            .unwrap();
+        assert_eq!(membership.account(), &Membership::Present);
+        assert_eq!(membership.storage(), &vec![Membership::Absent]);
+        assert!(test_assets.eip1186_proof().verify(state_root).unwrap());
+
+        let account = test_assets.eip1186_proof().decode_account().unwrap();
+        assert_eq!(
+            account.storage_root(),
+            test_assets.eip1186_proof().storage_hash
+        );
+
+        // Claiming a value for an absent storage key should fail
+        let mut invalid_proof = test_assets.eip1186_proof().clone();
+        invalid_proof.storage_proof[0].value = vec![1];
+        assert!(!invalid_proof.verify(state_root).unwrap());
+
+        // Claiming another address for the account proof should fail
+        let mut invalid_proof = test_assets.eip1186_proof().clone();
+        invalid_proof.address[0] ^= 1;
+        assert!(!invalid_proof.verify(state_root).unwrap());
+
+        // Storage hash should be the one committed in the account
+        let mut invalid_proof = test_assets.eip1186_proof().clone();
+        invalid_proof.storage_hash = HashValue::new(EMPTY_TRIE_ROOT);
+        assert!(!invalid_proof.verify(state_root).unwrap());
+
+        // The absent account is proven against the same state root, as an empty account
+        let absent_proof = test_assets.absent_account_eip1186_proof();
+        let membership = absent_proof.verify_membership(state_root).unwrap().unwrap();
+        assert_eq!(membership.account(), &Membership::Absent);
+        assert_eq!(membership.storage(), &vec![Membership::Absent]);
+        assert_ne!(
+            absent_proof.address(),
+            test_assets.eip1186_proof().address()
+        );
+
+        // An absent account can not claim a non empty account
+        let mut invalid_proof = absent_proof.clone();
+        invalid_proof.encoded_account = test_assets.eip1186_proof().encoded_account.clone();
+        invalid_proof.storage_hash = test_assets.eip1186_proof().storage_hash;
+        assert!(!invalid_proof.verify(state_root).unwrap());
+    }
 }
@@ -10,14 +10,9 @@ use crate::types::block::execution::{
     ExecutionBlockHeader, ExecutionBranch, EXECUTION_BRANCH_NBR_SIBLINGS,
     EXECUTION_PAYLOAD_GENERALIZED_INDEX,
 };
-use crate::types::committee::{
-    SyncCommittee, SyncCommitteeBranch, CURRENT_SYNC_COMMITTEE_GENERALIZED_INDEX,
-    NEXT_SYNC_COMMITTEE_GENERALIZED_INDEX, SYNC_COMMITTEE_BRANCH_NBR_SIBLINGS,
-};
-use crate::types::{
-    Bytes32, FinalizedRootBranch, FINALIZED_CHECKPOINT_BRANCH_NBR_SIBLINGS,
-    FINALIZED_ROOT_GENERALIZED_INDEX,
-};
+use crate::types::committee::{SyncCommittee, SyncCommitteeBranch};
+use crate::types::forks::Fork;
+use crate::types::{Bytes32, FinalizedRootBranch};
 
 /// Verifies the validity of a finality proof received in an  [`crate::types::update::Update`] message.
 ///
@@ -26,6 +21,7 @@ use crate::types::{
 /// * `state_root` - The state root of the Beacon block that the proof is attesting to.
 /// * `finality_header` - The header of the block that the update is attesting to be finalized.
 /// * `finality_branch` - The branch of the Merkle tree that proves the finality of the block.
+/// * `fork` - The fork active at the slot of the Beacon block that the proof is attesting to.
 ///
 /// # Returns
 ///
@@ -34,13 +30,14 @@ pub fn is_finality_proof_valid(
     state_root: &Bytes32,
     finality_header: &BeaconBlockHeader,
     finality_branch: &FinalizedRootBranch,
+    fork: Fork,
 ) -> Result<bool, MerkleError> {
     is_proof_valid(
         state_root,
         finality_header,
         finality_branch,
-        FINALIZED_CHECKPOINT_BRANCH_NBR_SIBLINGS,
-        FINALIZED_ROOT_GENERALIZED_INDEX,
+        fork.finalized_root_branch_depth(),
+        fork.finalized_root_generalized_index(),
     )
 }
 
@@ -51,6 +48,7 @@ pub fn is_finality_proof_valid(
 /// * `state_root` - The state root of the Beacon block that the proof is attesting to.
 /// * `sync_committee` - The next sync committee that the update is attesting to.
 /// * `sync_committee_branch` - The branch of the Merkle tree that proves the sync committee of the block.
+/// * `fork` - The fork active at the slot of the Beacon block that the proof is attesting to.
 ///
 /// # Returns
 ///
@@ -59,13 +57,14 @@ pub fn is_next_committee_proof_valid(
     state_root: &Bytes32,
     next_committee: &SyncCommittee,
     next_committee_branch: &SyncCommitteeBranch,
+    fork: Fork,
 ) -> Result<bool, MerkleError> {
     is_proof_valid(
         state_root,
         next_committee,
         next_committee_branch,
-        SYNC_COMMITTEE_BRANCH_NBR_SIBLINGS,
-        NEXT_SYNC_COMMITTEE_GENERALIZED_INDEX,
+        fork.sync_committee_branch_depth(),
+        fork.next_sync_committee_generalized_index(),
     )
 }
 
@@ -76,6 +75,7 @@ pub fn is_next_committee_proof_valid(
 /// * `state_root` - The state root of the Beacon block that the proof is attesting to.
 /// * `current_committee` - The current sync committee that the bootstrap is attesting to.
 /// * `current_committee_branch` - The branch of the Merkle tree that proves the current committee of the block.
+/// * `fork` - The fork active at the slot of the Beacon block that the proof is attesting to.
 ///
 /// # Returns
 ///
@@ -84,13 +84,14 @@ pub fn is_current_committee_proof_valid(
     state_root: &Bytes32,
     current_committee: &SyncCommittee,
     current_committee_branch: &SyncCommitteeBranch,
+    fork: Fork,
 ) -> Result<bool, MerkleError> {
     is_proof_valid(
         state_root,
         current_committee,
         current_committee_branch,
-        SYNC_COMMITTEE_BRANCH_NBR_SIBLINGS,
-        CURRENT_SYNC_COMMITTEE_GENERALIZED_INDEX,
+        fork.sync_committee_branch_depth(),
+        fork.current_sync_committee_generalized_index(),
     )
 }
 
@@ -132,6 +133,13 @@ pub fn is_execution_payload_proof_valid(
 /// # Returns
 ///
 /// A `bool` indicating whether the proof is valid.
+///
+/// # Notes
+///
+/// Branches longer than `depth` are accepted as long as their extra leading siblings are zero,
+/// so that data structures of a fork can carry proofs for a shallower tree of a previous fork.
+///
+/// From [the Electra specifications](https://github.com/ethereum/consensus-specs/blob/v1.5.0/specs/electra/light-client/sync-protocol.md#is_valid_normalized_merkle_branch).
 fn is_proof_valid<M: Merkleized>(
     state_root: &Bytes32,
     leaf_object: &M,
@@ -139,14 +147,20 @@ fn is_proof_valid<M: Merkleized>(
     depth: usize,
     generalized_index: usize,
 ) -> Result<bool, MerkleError> {
-    // Ensure we receive the number of siblings we expected
-    if branch.len() != depth {
+    // Ensure we receive at least the number of siblings we expected
+    if branch.len() < depth {
         return Err(MerkleError::InvalidBranchLength {
             expected: depth,
             actual: branch.len(),
         });
     }
 
+    // Extra siblings are only padding for a deeper tree
+    let (padding, branch) = branch.split_at(branch.len() - depth);
+    if padding.iter().any(|sibling| sibling != &Bytes32::default()) {
+        return Ok(false);
+    }
+
     // Ensure that the generalized index is for the given depth
     let generalized_index_depth = 63 - (generalized_index as u64).leading_zeros();
     if generalized_index_depth != depth as u32 {
@@ -216,6 +230,8 @@ mod test {
         generate_committee_change_test_assets, generate_inclusion_test_assets,
     };
     use crate::types::bootstrap::Bootstrap;
+    use crate::types::forks::Fork;
+    use crate::types::Bytes32;
     use std::env::current_dir;
     use std::fs;
 
@@ -252,10 +268,65 @@ mod test {
                 .state_root(),
             test_assets.finality_update().finalized_header().beacon(),
             test_assets.finality_update().finality_branch(),
+            Fork::Deneb,
+        )
+        .unwrap();
+
+        assert!(is_valid);
+    }
+
+    #[test]
+    fn test_is_finality_proof_valid_normalized_branch() {
+        let test_assets = generate_inclusion_test_assets();
+
+        // A branch padded with a zero sibling is still valid for the shallower tree
+        let mut finality_branch = vec![Bytes32::default()];
+        finality_branch.extend_from_slice(test_assets.finality_update().finality_branch());
+
+        let is_valid = is_finality_proof_valid(
+            test_assets
+                .finality_update()
+                .attested_header()
+                .beacon()
+                .state_root(),
+            test_assets.finality_update().finalized_header().beacon(),
+            &finality_branch,
+            Fork::Deneb,
         )
         .unwrap();
 
         assert!(is_valid);
+
+        // Non-zero padding is rejected
+        finality_branch[0] = [1; 32];
+
+        let is_valid = is_finality_proof_valid(
+            test_assets
+                .finality_update()
+                .attested_header()
+                .beacon()
+                .state_root(),
+            test_assets.finality_update().finalized_header().beacon(),
+            &finality_branch,
+            Fork::Deneb,
+        )
+        .unwrap();
+
+        assert!(!is_valid);
+
+        // Deneb proofs are not valid against the Electra generalized index
+        let is_valid = is_finality_proof_valid(
+            test_assets
+                .finality_update()
+                .attested_header()
+                .beacon()
+                .state_root(),
+            test_assets.finality_update().finalized_header().beacon(),
+            test_assets.finality_update().finality_branch(),
+            Fork::Electra,
+        );
+
+        assert!(is_valid.is_err());
     }
 
     #[test]
@@ -270,6 +341,7 @@ mod test {
                 .state_root(),
             test_assets.update_new_period().next_sync_committee(),
             test_assets.update_new_period().next_sync_committee_branch(),
+            Fork::Deneb,
         )
         .unwrap();
 
@@ -290,6 +362,7 @@ mod test {
             bootstrap.header().beacon().state_root(),
             bootstrap.current_sync_committee(),
             bootstrap.current_sync_committee_branch(),
+            Fork::Deneb,
         )
         .unwrap();
 
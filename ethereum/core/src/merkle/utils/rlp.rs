@@ -106,7 +106,7 @@ fn decode_item(input: &[u8]) -> Result<(Vec<u8>, &[u8]), RlpError> {
             &input[1 + len_of_str_len + str_len..],
         ))
     } else if prefix <= SHORT_LIST_LIMIT {
-        let list_len = (prefix - SHORT_LIST_LIMIT + 0xc0) as usize;
+        let list_len = (prefix - LONG_STRING_LIMIT - 1) as usize;
         if input.len() < 1 + list_len {
             return Err(RlpError::InputTooShort {
                 decode_type: "short list".into(),
@@ -151,14 +151,29 @@ fn decode_item(input: &[u8]) -> Result<(Vec<u8>, &[u8]), RlpError> {
 ///
 /// # Errors
 ///
-/// Returns an error if the input is empty or if there is leftover data after decoding the list.
+/// Returns an error if the input is empty, if it is not an RLP list or if there is leftover data
+/// after decoding the list.
 pub fn decode_list(input: &[u8]) -> Result<Vec<Vec<u8>>, RlpError> {
     if input.is_empty() {
         return Err(RlpError::EmptyInput);
     }
 
+    if input[0] <= LONG_STRING_LIMIT {
+        return Err(RlpError::ExpectedList);
+    }
+
+    // Strip the list header to get to the encoded items
+    let (payload, rest) = decode_item(input)?;
+
+    if !rest.is_empty() {
+        return Err(RlpError::LeftoverData {
+            expected: 0,
+            actual: rest.len(),
+        });
+    }
+
     let mut items = Vec::new();
-    let mut rest = input;
+    let mut rest = payload.as_slice();
 
     while !rest.is_empty() {
         let (item, remaining) = decode_item(rest)?;
@@ -1,5 +1,6 @@
 use crate::merkle::storage_proofs::EIP1186Proof;
 use crate::types::bootstrap::Bootstrap;
+use crate::types::network::MAINNET_NETWORK_CONFIG;
 use crate::types::store::LightClientStore;
 use crate::types::update::{FinalityUpdate, Update};
 use ethers_core::types::EIP1186ProofResponse;
@@ -14,6 +15,8 @@ const INCLUSION_UPDATE_DENEB_PATH: &str = "../test-assets/inclusion/LightClientU
 const INCLUSION_FINALITY_UPDATE_PATH: &str =
     "../test-assets/inclusion/LightClientFinalityUpdateDeneb.ssz";
 const ETH_GET_PROOF: &str = "../test-assets/inclusion/base-data/EthGetProof.json";
+const ETH_GET_PROOF_ABSENT_ACCOUNT: &str =
+    "../test-assets/inclusion/base-data/EthGetProofAbsentAccount.json";
 
 const COMMITTEE_CHANGE_BOOTSTRAP_DENEB_PATH: &str =
     "../test-assets/committee-change/LightClientBootstrapDeneb.ssz";
@@ -63,7 +66,9 @@ pub fn generate_committee_change_test_assets() -> CommitteeChangeTestAssets {
         .try_into()
         .unwrap();
 
-    let store = LightClientStore::initialize(trusted_block_root, &bootstrap).unwrap();
+    let store =
+        LightClientStore::initialize(trusted_block_root, &bootstrap, &MAINNET_NETWORK_CONFIG)
+            .unwrap();
 
     CommitteeChangeTestAssets {
         store,
@@ -78,6 +83,22 @@ pub struct InclusionTestAssets {
     store: LightClientStore,
     finality_update: FinalityUpdate,
     eip1186_proof: EIP1186Proof,
+    /// Proof that an account is absent from the same state as `eip1186_proof`.
+    absent_account_eip1186_proof: EIP1186Proof,
+}
+
+fn read_eip1186_proof(path: PathBuf) -> EIP1186Proof {
+    let test_bytes = fs::read(path).unwrap();
+
+    let ethers_eip1186_proof: Value = serde_json::from_slice(&test_bytes).unwrap();
+
+    let call_res = ethers_eip1186_proof
+        .get("result")
+        .expect("Ethers EIP1186 proof result not found");
+    let ethers_eip1186_proof: EIP1186ProofResponse =
+        serde_json::from_value(call_res.clone()).unwrap();
+
+    EIP1186Proof::try_from(ethers_eip1186_proof).unwrap()
 }
 
 pub fn generate_inclusion_test_assets() -> InclusionTestAssets {
@@ -104,17 +125,9 @@ pub fn generate_inclusion_test_assets() -> InclusionTestAssets {
     let finality_update = FinalityUpdate::from_ssz_bytes(&test_bytes).unwrap();
 
     // Instantiate EIP1186 proof data
-    let test_asset_path = root_dir.join(ETH_GET_PROOF);
-
-    let test_bytes = fs::read(test_asset_path).unwrap();
-
-    let ethers_eip1186_proof: Value = serde_json::from_slice(&test_bytes).unwrap();
-
-    let call_res = ethers_eip1186_proof
-        .get("result")
-        .expect("Ethers EIP1186 proof result not found");
-    let ethers_eip1186_proof: EIP1186ProofResponse =
-        serde_json::from_value(call_res.clone()).unwrap();
+    let eip1186_proof = read_eip1186_proof(root_dir.join(ETH_GET_PROOF));
+    let absent_account_eip1186_proof =
+        read_eip1186_proof(root_dir.join(ETH_GET_PROOF_ABSENT_ACCOUNT));
 
     // Initialize the LightClientStore
     let trusted_block_root = hex::decode(INCLUSION_CHECKPOINT.strip_prefix("0x").unwrap())
@@ -122,13 +135,16 @@ pub fn generate_inclusion_test_assets() -> InclusionTestAssets {
         .try_into()
         .unwrap();
 
-    let mut store = LightClientStore::initialize(trusted_block_root, &bootstrap).unwrap();
+    let mut store =
+        LightClientStore::initialize(trusted_block_root, &bootstrap, &MAINNET_NETWORK_CONFIG)
+            .unwrap();
 
     store.process_light_client_update(&update).unwrap();
 
     InclusionTestAssets {
         store,
         finality_update,
-        eip1186_proof: EIP1186Proof::try_from(ethers_eip1186_proof).unwrap(),
+        eip1186_proof,
+        absent_account_eip1186_proof,
     }
 }
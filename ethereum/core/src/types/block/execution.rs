@@ -52,6 +52,7 @@ pub const MAX_EXTRA_DATA_BYTES_LEN: usize = BYTES_32_LEN;
 /// `ExecutionBlockHeader` represents the header of an execution block.
 ///
 /// From [the Deneb specifications](https://github.com/ethereum/consensus-specs/blob/v1.4.0/specs/deneb/beacon-chain.md#executionpayloadheader).
+/// The layout is unchanged by the Electra and Fulu forks, see [`crate::types::forks`].
 #[derive(Debug, Clone, Eq, PartialEq, Getters)]
 #[getset(get = "pub")]
 pub struct ExecutionBlockHeader {
@@ -18,15 +18,26 @@
 
 use crate::deserialization_error;
 use crate::types::block::{LightClientHeader, LIGHT_CLIENT_HEADER_BASE_BYTES_LEN};
-use crate::types::committee::{
-    SyncCommittee, SyncCommitteeBranch, SYNC_COMMITTEE_BRANCH_NBR_SIBLINGS,
-    SYNC_COMMITTEE_BYTES_LEN,
-};
+use crate::types::committee::{SyncCommittee, SyncCommitteeBranch, SYNC_COMMITTEE_BYTES_LEN};
 use crate::types::error::TypesError;
-use crate::types::utils::{extract_u32, OFFSET_BYTE_LENGTH};
+use crate::types::forks::Fork;
+use crate::types::utils::{extract_branch, extract_u32, OFFSET_BYTE_LENGTH};
 use crate::types::BYTES_32_LEN;
 use getset::Getters;
 
+/// Computes the offset of the header in a serialized `Bootstrap`.
+///
+/// # Arguments
+///
+/// * `sync_committee_branch_depth` - The number of siblings in the current sync committee branch.
+///
+/// # Returns
+///
+/// The offset of the header.
+const fn bootstrap_header_offset(sync_committee_branch_depth: usize) -> usize {
+    OFFSET_BYTE_LENGTH + SYNC_COMMITTEE_BYTES_LEN + sync_committee_branch_depth * BYTES_32_LEN
+}
+
 /// `Bootstrap` represents the bootstrap data for the light client.
 ///
 /// From [the Altaïr specifications](https://github.com/ethereum/consensus-specs/blob/v1.4.0/specs/altair/light-client/sync-protocol.md#lightclientbootstrap).
@@ -48,9 +59,7 @@ impl Bootstrap {
         let mut bytes = vec![];
 
         // Serialize header offset
-        let offset = OFFSET_BYTE_LENGTH
-            + SYNC_COMMITTEE_BYTES_LEN
-            + SYNC_COMMITTEE_BRANCH_NBR_SIBLINGS * BYTES_32_LEN;
+        let offset = bootstrap_header_offset(self.current_sync_committee_branch.len());
         bytes.extend_from_slice(&(offset as u32).to_le_bytes());
 
         // Serialize the current sync committee
@@ -83,7 +92,7 @@ impl Bootstrap {
     pub fn from_ssz_bytes(bytes: &[u8]) -> Result<Self, TypesError> {
         let expected_len = LIGHT_CLIENT_HEADER_BASE_BYTES_LEN
             + SYNC_COMMITTEE_BYTES_LEN
-            + SYNC_COMMITTEE_BRANCH_NBR_SIBLINGS * BYTES_32_LEN;
+            + Fork::Deneb.sync_committee_branch_depth() * BYTES_32_LEN;
         if bytes.len() < expected_len {
             return Err(TypesError::UnderLength {
                 minimum: expected_len,
@@ -97,35 +106,28 @@ impl Bootstrap {
         // Deserialize `LightClientHeader` offset
         let (cursor, offset) = extract_u32("Bootstrap", bytes, cursor)?;
 
+        // The branch depth depends on the fork, which is reflected in the offset
+        let fork = Fork::ALL
+            .into_iter()
+            .find(|fork| {
+                bootstrap_header_offset(fork.sync_committee_branch_depth()) == offset as usize
+            })
+            .ok_or_else(|| deserialization_error!("Bootstrap", "Unknown offset for header"))?;
+
         // Deserialize `SyncCommittee`
         let current_sync_committee =
             SyncCommittee::from_ssz_bytes(&bytes[cursor..cursor + SYNC_COMMITTEE_BYTES_LEN])?;
 
         // Deserialize `SyncCommitteeBranch`
         let cursor = cursor + SYNC_COMMITTEE_BYTES_LEN;
-        let current_sync_committee_branch = (0..SYNC_COMMITTEE_BRANCH_NBR_SIBLINGS)
-            .map(|i| {
-                let start = cursor + i * BYTES_32_LEN;
-                let end = start + BYTES_32_LEN;
-                let returned_bytes = &bytes[start..end];
-                returned_bytes.try_into().map_err(|_| {
-                    deserialization_error!(
-                        "Bootstrap",
-                        "Failed to convert bytes into SyncCommitteeBranch"
-                    )
-                })
-            })
-            .collect::<Result<Vec<[u8; BYTES_32_LEN]>, _>>()?
-            .try_into()
-            .map_err(|_| {
-                deserialization_error!(
-                    "Bootstrap",
-                    "Failed to convert bytes into SyncCommitteeBranch"
-                )
-            })?;
+        let (cursor, current_sync_committee_branch) = extract_branch(
+            "Bootstrap",
+            bytes,
+            cursor,
+            fork.sync_committee_branch_depth(),
+        )?;
 
         // Check offset
-        let cursor = cursor + SYNC_COMMITTEE_BRANCH_NBR_SIBLINGS * BYTES_32_LEN;
         if cursor != offset as usize {
             return Err(deserialization_error!(
                 "Bootstrap",
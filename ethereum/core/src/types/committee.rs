@@ -26,30 +26,13 @@ use getset::Getters;
 /// Constant number of validators in the sync committee.
 pub const SYNC_COMMITTEE_SIZE: usize = 512;
 
-/// Current size of a merkle proof for a sync committee.
-///
-/// From [the Lighthouse implementation](https://github.com/sigp/lighthouse/blob/v5.2.1/consensus/types/src/light_client_update.rs#L34)
-/// and [the Altaïr specifications](https://github.com/ethereum/annotated-spec/blob/master/altair/sync-protocol.md#lightclientupdate).
-pub const SYNC_COMMITTEE_BRANCH_NBR_SIBLINGS: usize = 5;
-
-/// Merkle proof for a sync committee.
-pub type SyncCommitteeBranch = [Bytes32; SYNC_COMMITTEE_BRANCH_NBR_SIBLINGS];
+/// Merkle proof for a sync committee. Its depth depends on the fork, see
+/// [`crate::types::forks::Fork::sync_committee_branch_depth`].
+pub type SyncCommitteeBranch = Vec<Bytes32>;
 
 /// Length of the serialized `SyncCommittee` in bytes.
 pub const SYNC_COMMITTEE_BYTES_LEN: usize = SYNC_COMMITTEE_SIZE * PUB_KEY_LEN + PUB_KEY_LEN;
 
-/// The [generalized Merkle tree index](https://github.com/ethereum/consensus-specs/blob/81f3ea8322aff6b9fb15132d050f8f98b16bdba4/ssz/merkle-proofs.md#generalized-merkle-tree-index)
-/// for the current sync committee.
-///
-/// From [the Altair specifications](https://github.com/ethereum/consensus-specs/blob/v1.4.0/specs/altair/light-client/sync-protocol.md#constants).
-pub const CURRENT_SYNC_COMMITTEE_GENERALIZED_INDEX: usize = 54;
-
-/// The [generalized Merkle tree index](https://github.com/ethereum/consensus-specs/blob/81f3ea8322aff6b9fb15132d050f8f98b16bdba4/ssz/merkle-proofs.md#generalized-merkle-tree-index)
-/// for the next sync committee.
-///
-/// From [the Altair specifications](https://github.com/ethereum/consensus-specs/blob/v1.4.0/specs/altair/light-client/sync-protocol.md#constants).
-pub const NEXT_SYNC_COMMITTEE_GENERALIZED_INDEX: usize = 55;
-
 /// `SyncCommittee` is a committee of validators that are responsible for attesting to the latest
 /// block. The sync committee is a subset of the full validator set.
 ///
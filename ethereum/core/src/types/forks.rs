@@ -0,0 +1,341 @@
+// Copyright (c) Argument Computer Corporation
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Forks
+//!
+//! This module contains the hard forks of the Beacon chain that the Light Client is able to follow,
+//! along with the schedule at which they activate.
+//!
+//! Some of the data consumed by the Light Client depends on the fork that was active at a given slot:
+//! - The generalized indices of the finalized root and of the sync committees in the `BeaconState`,
+//!   and therefore the depth of their Merkle branches. Electra grew the `BeaconState` past 32 fields,
+//!   adding one level to the tree.
+//! - The domain used by the sync committee to sign block headers, as it is derived from the fork version.
+//!   See [`crate::types::signing_data::compute_domain`].
+//!
+//! The `ExecutionBlockHeader` layout is the same from Deneb onwards: the execution requests introduced
+//! in Electra ([EIP-7685](https://eips.ethereum.org/EIPS/eip-7685)) are committed in the beacon block
+//! body rather than in the execution payload header.
+
+use crate::types::error::TypesError;
+use crate::types::utils::{extract_fixed_bytes, extract_u64, U64_LEN};
+use getset::{CopyGetters, Getters};
+
+/// Length in bytes of a fork version.
+pub const VERSION_LEN: usize = 4;
+
+/// Version of a fork, used to compute signing domains.
+///
+/// From [the phase0 specifications](https://github.com/ethereum/consensus-specs/blob/v1.4.0/specs/phase0/beacon-chain.md#custom-types).
+pub type Version = [u8; VERSION_LEN];
+
+/// Length in bytes of a serialized `ForkSchedule`.
+pub const FORK_SCHEDULE_BYTES_LEN: usize = VERSION_LEN * 3 + U64_LEN * 2;
+
+/// Epoch used for forks that are not scheduled yet.
+///
+/// From [the phase0 specifications](https://github.com/ethereum/consensus-specs/blob/v1.4.0/specs/phase0/beacon-chain.md#constants).
+pub const FAR_FUTURE_EPOCH: u64 = u64::MAX;
+
+/// Fork schedule of the Ethereum mainnet.
+///
+/// From [the mainnet configuration](https://github.com/ethereum/consensus-specs/blob/v1.6.0/configs/mainnet.yaml).
+pub const MAINNET_FORK_SCHEDULE: ForkSchedule =
+    ForkSchedule::new([4, 0, 0, 0], [5, 0, 0, 0], 364032, [6, 0, 0, 0], 411392);
+
+/// Fork schedule of the Sepolia testnet.
+///
+/// From [the Sepolia configuration](https://github.com/eth-clients/sepolia/blob/main/metadata/config.yaml).
+pub const SEPOLIA_FORK_SCHEDULE: ForkSchedule = ForkSchedule::new(
+    [144, 0, 0, 115],
+    [144, 0, 0, 116],
+    222464,
+    [144, 0, 0, 117],
+    272640,
+);
+
+/// Fork schedule of the Holesky testnet.
+///
+/// From [the Holesky configuration](https://github.com/eth-clients/holesky/blob/main/metadata/config.yaml).
+pub const HOLESKY_FORK_SCHEDULE: ForkSchedule = ForkSchedule::new(
+    [5, 1, 112, 0],
+    [6, 1, 112, 0],
+    115968,
+    [7, 1, 112, 0],
+    165120,
+);
+
+/// Hard forks of the Beacon chain supported by the Light Client, ordered by activation.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub enum Fork {
+    Deneb,
+    Electra,
+    Fulu,
+}
+
+impl Fork {
+    /// All the forks supported by the Light Client, ordered by activation.
+    pub const ALL: [Fork; 3] = [Fork::Deneb, Fork::Electra, Fork::Fulu];
+
+    /// Returns the [generalized Merkle tree index](https://github.com/ethereum/consensus-specs/blob/81f3ea8322aff6b9fb15132d050f8f98b16bdba4/ssz/merkle-proofs.md#generalized-merkle-tree-index)
+    /// of the finalized block root in the `BeaconState`.
+    ///
+    /// # Returns
+    ///
+    /// The generalized index of the finalized root for the fork.
+    ///
+    /// # Notes
+    ///
+    /// From [the Altair specifications](https://github.com/ethereum/consensus-specs/blob/v1.4.0/specs/altair/light-client/sync-protocol.md#constants)
+    /// and [the Electra specifications](https://github.com/ethereum/consensus-specs/blob/v1.5.0/specs/electra/light-client/sync-protocol.md#new-constants).
+    pub const fn finalized_root_generalized_index(&self) -> usize {
+        match self {
+            Fork::Deneb => 105,
+            Fork::Electra | Fork::Fulu => 169,
+        }
+    }
+
+    /// Returns the [generalized Merkle tree index](https://github.com/ethereum/consensus-specs/blob/81f3ea8322aff6b9fb15132d050f8f98b16bdba4/ssz/merkle-proofs.md#generalized-merkle-tree-index)
+    /// of the current sync committee in the `BeaconState`.
+    ///
+    /// # Returns
+    ///
+    /// The generalized index of the current sync committee for the fork.
+    ///
+    /// # Notes
+    ///
+    /// From [the Altair specifications](https://github.com/ethereum/consensus-specs/blob/v1.4.0/specs/altair/light-client/sync-protocol.md#constants)
+    /// and [the Electra specifications](https://github.com/ethereum/consensus-specs/blob/v1.5.0/specs/electra/light-client/sync-protocol.md#new-constants).
+    pub const fn current_sync_committee_generalized_index(&self) -> usize {
+        match self {
+            Fork::Deneb => 54,
+            Fork::Electra | Fork::Fulu => 86,
+        }
+    }
+
+    /// Returns the [generalized Merkle tree index](https://github.com/ethereum/consensus-specs/blob/81f3ea8322aff6b9fb15132d050f8f98b16bdba4/ssz/merkle-proofs.md#generalized-merkle-tree-index)
+    /// of the next sync committee in the `BeaconState`.
+    ///
+    /// # Returns
+    ///
+    /// The generalized index of the next sync committee for the fork.
+    ///
+    /// # Notes
+    ///
+    /// From [the Altair specifications](https://github.com/ethereum/consensus-specs/blob/v1.4.0/specs/altair/light-client/sync-protocol.md#constants)
+    /// and [the Electra specifications](https://github.com/ethereum/consensus-specs/blob/v1.5.0/specs/electra/light-client/sync-protocol.md#new-constants).
+    pub const fn next_sync_committee_generalized_index(&self) -> usize {
+        match self {
+            Fork::Deneb => 55,
+            Fork::Electra | Fork::Fulu => 87,
+        }
+    }
+
+    /// Returns the number of siblings in a proof for the finalized block root.
+    ///
+    /// # Returns
+    ///
+    /// The depth of the finalized root branch for the fork.
+    pub const fn finalized_root_branch_depth(&self) -> usize {
+        floor_log2(self.finalized_root_generalized_index())
+    }
+
+    /// Returns the number of siblings in a proof for a sync committee.
+    ///
+    /// # Returns
+    ///
+    /// The depth of the sync committee branches for the fork.
+    pub const fn sync_committee_branch_depth(&self) -> usize {
+        floor_log2(self.next_sync_committee_generalized_index())
+    }
+}
+
+/// Returns the depth of a generalized index in its Merkle tree.
+const fn floor_log2(generalized_index: usize) -> usize {
+    (usize::BITS - 1 - generalized_index.leading_zeros()) as usize
+}
+
+/// `ForkSchedule` holds the version of each supported fork on a network and the epoch at which
+/// it activates. Deneb is the oldest supported fork and is considered active from genesis.
+///
+/// Forks that are not scheduled yet should be set to [`FAR_FUTURE_EPOCH`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Getters, CopyGetters)]
+pub struct ForkSchedule {
+    #[getset(get = "pub")]
+    deneb_fork_version: Version,
+    #[getset(get = "pub")]
+    electra_fork_version: Version,
+    #[getset(get_copy = "pub")]
+    electra_fork_epoch: u64,
+    #[getset(get = "pub")]
+    fulu_fork_version: Version,
+    #[getset(get_copy = "pub")]
+    fulu_fork_epoch: u64,
+}
+
+impl ForkSchedule {
+    /// Creates a new `ForkSchedule`.
+    ///
+    /// # Arguments
+    ///
+    /// * `deneb_fork_version` - The version of the Deneb fork.
+    /// * `electra_fork_version` - The version of the Electra fork.
+    /// * `electra_fork_epoch` - The epoch at which Electra activates.
+    /// * `fulu_fork_version` - The version of the Fulu fork.
+    /// * `fulu_fork_epoch` - The epoch at which Fulu activates.
+    ///
+    /// # Returns
+    ///
+    /// The new `ForkSchedule`.
+    pub const fn new(
+        deneb_fork_version: Version,
+        electra_fork_version: Version,
+        electra_fork_epoch: u64,
+        fulu_fork_version: Version,
+        fulu_fork_epoch: u64,
+    ) -> Self {
+        Self {
+            deneb_fork_version,
+            electra_fork_version,
+            electra_fork_epoch,
+            fulu_fork_version,
+            fulu_fork_epoch,
+        }
+    }
+
+    /// Returns the version of the given fork on the network.
+    ///
+    /// # Arguments
+    ///
+    /// * `fork` - The fork to look up.
+    ///
+    /// # Returns
+    ///
+    /// The `Version` of the fork.
+    pub const fn fork_version(&self, fork: Fork) -> &Version {
+        match fork {
+            Fork::Deneb => &self.deneb_fork_version,
+            Fork::Electra => &self.electra_fork_version,
+            Fork::Fulu => &self.fulu_fork_version,
+        }
+    }
+
+    /// Returns the fork active at the given epoch.
+    ///
+    /// # Arguments
+    ///
+    /// * `epoch` - The epoch to look up.
+    ///
+    /// # Returns
+    ///
+    /// The active `Fork`.
+    pub const fn fork_at_epoch(&self, epoch: u64) -> Fork {
+        if epoch >= self.fulu_fork_epoch {
+            Fork::Fulu
+        } else if epoch >= self.electra_fork_epoch {
+            Fork::Electra
+        } else {
+            Fork::Deneb
+        }
+    }
+
+    /// Serialize a `ForkSchedule` data structure to an SSZ formatted vector of bytes.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<u8>` containing the SSZ serialized `ForkSchedule` data structure.
+    pub fn to_ssz_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(FORK_SCHEDULE_BYTES_LEN);
+
+        bytes.extend_from_slice(&self.deneb_fork_version);
+        bytes.extend_from_slice(&self.electra_fork_version);
+        bytes.extend_from_slice(&self.electra_fork_epoch.to_le_bytes());
+        bytes.extend_from_slice(&self.fulu_fork_version);
+        bytes.extend_from_slice(&self.fulu_fork_epoch.to_le_bytes());
+
+        bytes
+    }
+
+    /// Deserialize a `ForkSchedule` data structure from SSZ formatted bytes.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The SSZ formatted bytes to deserialize the `ForkSchedule` data structure from.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either the deserialized `ForkSchedule` data structure or a `TypesError`.
+    pub fn from_ssz_bytes(bytes: &[u8]) -> Result<Self, TypesError> {
+        if bytes.len() != FORK_SCHEDULE_BYTES_LEN {
+            return Err(TypesError::InvalidLength {
+                structure: "ForkSchedule".into(),
+                expected: FORK_SCHEDULE_BYTES_LEN,
+                actual: bytes.len(),
+            });
+        }
+
+        let cursor = 0;
+        let (cursor, deneb_fork_version) = extract_fixed_bytes("ForkSchedule", bytes, cursor)?;
+        let (cursor, electra_fork_version) = extract_fixed_bytes("ForkSchedule", bytes, cursor)?;
+        let (cursor, electra_fork_epoch) = extract_u64("ForkSchedule", bytes, cursor)?;
+        let (cursor, fulu_fork_version) = extract_fixed_bytes("ForkSchedule", bytes, cursor)?;
+        let (_, fulu_fork_epoch) = extract_u64("ForkSchedule", bytes, cursor)?;
+
+        Ok(Self {
+            deneb_fork_version,
+            electra_fork_version,
+            electra_fork_epoch,
+            fulu_fork_version,
+            fulu_fork_epoch,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::types::forks::{
+        Fork, ForkSchedule, FAR_FUTURE_EPOCH, FORK_SCHEDULE_BYTES_LEN, MAINNET_FORK_SCHEDULE,
+    };
+
+    #[test]
+    fn test_branch_depths() {
+        assert_eq!(Fork::Deneb.finalized_root_branch_depth(), 6);
+        assert_eq!(Fork::Deneb.sync_committee_branch_depth(), 5);
+        assert_eq!(Fork::Electra.finalized_root_branch_depth(), 7);
+        assert_eq!(Fork::Electra.sync_committee_branch_depth(), 6);
+        assert_eq!(Fork::Fulu.finalized_root_branch_depth(), 7);
+        assert_eq!(Fork::Fulu.sync_committee_branch_depth(), 6);
+    }
+
+    #[test]
+    fn test_fork_at_epoch() {
+        let schedule = ForkSchedule::new([4, 0, 0, 0], [5, 0, 0, 0], 10, [6, 0, 0, 0], 20);
+
+        assert_eq!(schedule.fork_at_epoch(0), Fork::Deneb);
+        assert_eq!(schedule.fork_at_epoch(9), Fork::Deneb);
+        assert_eq!(schedule.fork_at_epoch(10), Fork::Electra);
+        assert_eq!(schedule.fork_at_epoch(19), Fork::Electra);
+        assert_eq!(schedule.fork_at_epoch(20), Fork::Fulu);
+
+        let schedule = ForkSchedule::new(
+            [4, 0, 0, 0],
+            [5, 0, 0, 0],
+            FAR_FUTURE_EPOCH,
+            [6, 0, 0, 0],
+            FAR_FUTURE_EPOCH,
+        );
+
+        assert_eq!(schedule.fork_at_epoch(u64::MAX - 1), Fork::Deneb);
+    }
+
+    #[test]
+    fn test_ssz_serde_fork_schedule() {
+        let bytes = MAINNET_FORK_SCHEDULE.to_ssz_bytes();
+
+        assert_eq!(bytes.len(), FORK_SCHEDULE_BYTES_LEN);
+        assert_eq!(
+            ForkSchedule::from_ssz_bytes(&bytes).unwrap(),
+            MAINNET_FORK_SCHEDULE
+        );
+    }
+}
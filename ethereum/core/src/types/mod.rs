@@ -13,6 +13,8 @@
 //! - `bootstrap`: This module contains the data structures available for a Light Client to bootstrap
 //!   to the network.
 //! - `committee`: This sub-module contains all the structures related to committees on the Beacon chain.
+//! - `forks`: This sub-module contains the hard forks supported by the Light Client and their schedule.
+//! - `network`: This sub-module contains the configuration identifying the Beacon chain network followed.
 //! - `signing_data`: This sub-module contains the data structure that represents the message signed
 //!    by Validators on the Beacon chain.
 //! - `store`: This sub-module contains the data structure representing a Light Client Store containing
@@ -27,6 +29,8 @@ pub mod block;
 pub mod bootstrap;
 pub mod committee;
 pub mod error;
+pub mod forks;
+pub mod network;
 pub mod signing_data;
 pub mod store;
 pub mod update;
@@ -44,21 +48,9 @@ pub const ADDRESS_BYTES_LEN: usize = 20;
 /// An ethereum address.
 pub type Address = [u8; ADDRESS_BYTES_LEN];
 
-/// The [generalized Merkle tree index](https://github.com/ethereum/consensus-specs/blob/81f3ea8322aff6b9fb15132d050f8f98b16bdba4/ssz/merkle-proofs.md#generalized-merkle-tree-index)
-/// for finalized block.
-///
-///
-/// From [the Altair specifications](https://github.com/ethereum/consensus-specs/blob/dev/specs/altair/light-client/sync-protocol.md#constants).
-pub const FINALIZED_ROOT_GENERALIZED_INDEX: usize = 105;
-
-/// Number of siblings in a proof for a finalized block root in a merkle tree.
-///
-/// From [the Altaïr specifications](https://github.com/ethereum/annotated-spec/blob/master/altair/sync-protocol.md#lightclientupdate)
-/// and [the Lighthouse implementation](https://github.com/sigp/lighthouse/blob/v5.2.1/consensus/types/src/light_client_update.rs#L32).
-pub const FINALIZED_CHECKPOINT_BRANCH_NBR_SIBLINGS: usize = 6;
-
-/// Merkle proof for a finalized block root.
-pub type FinalizedRootBranch = [Bytes32; FINALIZED_CHECKPOINT_BRANCH_NBR_SIBLINGS];
+/// Merkle proof for a finalized block root. Its depth depends on the fork, see
+/// [`forks::Fork::finalized_root_branch_depth`].
+pub type FinalizedRootBranch = Vec<Bytes32>;
 
 /// ForkDigest representing the fork the data originated from.
 ///
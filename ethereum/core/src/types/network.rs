@@ -0,0 +1,413 @@
+// Copyright (c) Argument Computer Corporation
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Network configuration
+//!
+//! This module contains the `NetworkConfig` data structure, holding the parameters that identify the
+//! Beacon chain network the Light Client is following. They are needed to compute the domains
+//! used by the sync committee to sign block headers and to map slots to sync committee periods,
+//! which differ from one network to another.
+//!
+//! Presets are provided for the Ethereum mainnet and the Sepolia and Holesky testnets.
+
+use crate::crypto::error::CryptoError;
+use crate::deserialization_error;
+use crate::types::error::TypesError;
+use crate::types::forks::{
+    Fork, ForkSchedule, FORK_SCHEDULE_BYTES_LEN, HOLESKY_FORK_SCHEDULE, MAINNET_FORK_SCHEDULE,
+    SEPOLIA_FORK_SCHEDULE,
+};
+use crate::types::signing_data::{compute_domain, DOMAIN_SYNC_COMMITTEE};
+use crate::types::utils::{extract_fixed_bytes, extract_u64, GENESIS_ROOT, U64_LEN};
+use crate::types::{Bytes32, BYTES_32_LEN};
+use getset::{CopyGetters, Getters};
+
+/// Length in bytes of a serialized `NetworkConfig`.
+pub const NETWORK_CONFIG_BYTES_LEN: usize = U64_LEN * 5 + BYTES_32_LEN + FORK_SCHEDULE_BYTES_LEN;
+
+/// Duration of a slot in seconds on mainnet and the public testnets.
+///
+/// From [the phase0 specifications](https://github.com/ethereum/consensus-specs/blob/v1.4.0/specs/phase0/beacon-chain.md#time-parameters-1).
+pub const SECONDS_PER_SLOT: u64 = 12;
+
+/// Number of slots in an epoch on mainnet and the public testnets.
+///
+/// From [the phase0 specifications](https://github.com/ethereum/consensus-specs/blob/v1.4.0/specs/phase0/beacon-chain.md#time-parameters-1).
+pub const SLOTS_PER_EPOCH: u64 = 32;
+
+/// Number of epochs in a sync committee period on mainnet and the public testnets.
+///
+/// From [the Altair specifications](https://github.com/ethereum/consensus-specs/blob/v1.4.0/specs/altair/beacon-chain.md#time-parameters).
+pub const EPOCHS_PER_SYNC_COMMITTEE_PERIOD: u64 = 256;
+
+/// Genesis validators root of the Beacon chain on Sepolia.
+pub const SEPOLIA_GENESIS_ROOT: Bytes32 = [
+    216, 234, 23, 31, 60, 148, 174, 162, 30, 188, 66, 161, 237, 97, 5, 42, 207, 63, 146, 9, 192,
+    14, 78, 251, 170, 221, 172, 9, 237, 155, 128, 120,
+];
+
+/// Genesis validators root of the Beacon chain on Holesky.
+pub const HOLESKY_GENESIS_ROOT: Bytes32 = [
+    145, 67, 170, 124, 97, 90, 127, 113, 21, 226, 182, 170, 195, 25, 192, 53, 41, 223, 130, 66,
+    174, 112, 95, 186, 157, 243, 155, 121, 197, 159, 168, 177,
+];
+
+/// Configuration of the Ethereum mainnet.
+pub const MAINNET_NETWORK_CONFIG: NetworkConfig = NetworkConfig::new(
+    1,
+    1606824023,
+    GENESIS_ROOT,
+    SECONDS_PER_SLOT,
+    SLOTS_PER_EPOCH,
+    EPOCHS_PER_SYNC_COMMITTEE_PERIOD,
+    MAINNET_FORK_SCHEDULE,
+);
+
+/// Configuration of the Sepolia testnet.
+pub const SEPOLIA_NETWORK_CONFIG: NetworkConfig = NetworkConfig::new(
+    11155111,
+    1655733600,
+    SEPOLIA_GENESIS_ROOT,
+    SECONDS_PER_SLOT,
+    SLOTS_PER_EPOCH,
+    EPOCHS_PER_SYNC_COMMITTEE_PERIOD,
+    SEPOLIA_FORK_SCHEDULE,
+);
+
+/// Configuration of the Holesky testnet.
+pub const HOLESKY_NETWORK_CONFIG: NetworkConfig = NetworkConfig::new(
+    17000,
+    1695902400,
+    HOLESKY_GENESIS_ROOT,
+    SECONDS_PER_SLOT,
+    SLOTS_PER_EPOCH,
+    EPOCHS_PER_SYNC_COMMITTEE_PERIOD,
+    HOLESKY_FORK_SCHEDULE,
+);
+
+/// `NetworkConfig` holds the parameters identifying a Beacon chain network.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Getters, CopyGetters)]
+pub struct NetworkConfig {
+    #[getset(get_copy = "pub")]
+    chain_id: u64,
+    #[getset(get_copy = "pub")]
+    genesis_time: u64,
+    #[getset(get = "pub")]
+    genesis_validators_root: Bytes32,
+    #[getset(get_copy = "pub")]
+    seconds_per_slot: u64,
+    #[getset(get_copy = "pub")]
+    slots_per_epoch: u64,
+    #[getset(get_copy = "pub")]
+    epochs_per_sync_committee_period: u64,
+    #[getset(get = "pub")]
+    fork_schedule: ForkSchedule,
+}
+
+impl NetworkConfig {
+    /// Creates a new `NetworkConfig`.
+    ///
+    /// # Arguments
+    ///
+    /// * `chain_id` - The chain id of the execution layer of the network.
+    /// * `genesis_time` - The Unix timestamp of the Beacon chain genesis.
+    /// * `genesis_validators_root` - The genesis validators root of the network.
+    /// * `seconds_per_slot` - The duration of a slot in seconds.
+    /// * `slots_per_epoch` - The number of slots in an epoch.
+    /// * `epochs_per_sync_committee_period` - The number of epochs in a sync committee period.
+    /// * `fork_schedule` - The fork schedule of the network.
+    ///
+    /// # Returns
+    ///
+    /// The new `NetworkConfig`.
+    pub const fn new(
+        chain_id: u64,
+        genesis_time: u64,
+        genesis_validators_root: Bytes32,
+        seconds_per_slot: u64,
+        slots_per_epoch: u64,
+        epochs_per_sync_committee_period: u64,
+        fork_schedule: ForkSchedule,
+    ) -> Self {
+        Self {
+            chain_id,
+            genesis_time,
+            genesis_validators_root,
+            seconds_per_slot,
+            slots_per_epoch,
+            epochs_per_sync_committee_period,
+            fork_schedule,
+        }
+    }
+
+    /// Calculate the slot at a given Unix timestamp.
+    ///
+    /// # Arguments
+    ///
+    /// * `timestamp` - The Unix timestamp, in seconds.
+    ///
+    /// # Returns
+    ///
+    /// The slot, or 0 if the timestamp is before genesis.
+    pub const fn calc_slot_at(&self, timestamp: u64) -> u64 {
+        timestamp.saturating_sub(self.genesis_time) / self.seconds_per_slot
+    }
+
+    /// Calculate the epoch for a given slot number.
+    ///
+    /// # Arguments
+    ///
+    /// * `slot` - The slot number.
+    ///
+    /// # Returns
+    ///
+    /// The epoch.
+    pub const fn calc_epoch(&self, slot: &u64) -> u64 {
+        *slot / self.slots_per_epoch
+    }
+
+    /// Calculate the sync period for a given slot number.
+    ///
+    /// # Arguments
+    ///
+    /// * `slot` - The slot number.
+    ///
+    /// # Returns
+    ///
+    /// The sync period.
+    pub const fn calc_sync_period(&self, slot: &u64) -> u64 {
+        self.calc_epoch(slot) / self.epochs_per_sync_committee_period
+    }
+
+    /// Returns the fork active at the given slot.
+    ///
+    /// # Arguments
+    ///
+    /// * `slot` - The slot to look up.
+    ///
+    /// # Returns
+    ///
+    /// The active `Fork`.
+    pub const fn fork_at_slot(&self, slot: &u64) -> Fork {
+        self.fork_schedule.fork_at_epoch(self.calc_epoch(slot))
+    }
+
+    /// Returns the fork whose domain was used for a sync committee signature included at the
+    /// given slot. The sync committee signs the block of the previous slot.
+    ///
+    /// # Arguments
+    ///
+    /// * `signature_slot` - The slot at which the signature was included.
+    ///
+    /// # Returns
+    ///
+    /// The `Fork` to use for the signing domain.
+    ///
+    /// # Notes
+    ///
+    /// From [the Altair specifications](https://github.com/ethereum/consensus-specs/blob/v1.4.0/specs/altair/light-client/sync-protocol.md#validate_light_client_update).
+    pub const fn signature_fork(&self, signature_slot: &u64) -> Fork {
+        self.fork_at_slot(&signature_slot.saturating_sub(1))
+    }
+
+    /// Computes the domain used by the sync committee for a signature included at the given slot.
+    ///
+    /// # Arguments
+    ///
+    /// * `signature_slot` - The slot at which the signature was included.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the signing domain or a `CryptoError`.
+    pub fn sync_committee_domain(&self, signature_slot: &u64) -> Result<Bytes32, CryptoError> {
+        let fork = self.signature_fork(signature_slot);
+
+        compute_domain(
+            &DOMAIN_SYNC_COMMITTEE,
+            self.fork_schedule.fork_version(fork),
+            &self.genesis_validators_root,
+        )
+    }
+
+    /// Serialize a `NetworkConfig` data structure to an SSZ formatted vector of bytes.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<u8>` containing the SSZ serialized `NetworkConfig` data structure.
+    pub fn to_ssz_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(NETWORK_CONFIG_BYTES_LEN);
+
+        bytes.extend_from_slice(&self.chain_id.to_le_bytes());
+        bytes.extend_from_slice(&self.genesis_time.to_le_bytes());
+        bytes.extend_from_slice(&self.genesis_validators_root);
+        bytes.extend_from_slice(&self.seconds_per_slot.to_le_bytes());
+        bytes.extend_from_slice(&self.slots_per_epoch.to_le_bytes());
+        bytes.extend_from_slice(&self.epochs_per_sync_committee_period.to_le_bytes());
+        bytes.extend(self.fork_schedule.to_ssz_bytes());
+
+        bytes
+    }
+
+    /// Deserialize a `NetworkConfig` data structure from SSZ formatted bytes.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The SSZ formatted bytes to deserialize the `NetworkConfig` data structure from.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either the deserialized `NetworkConfig` data structure or a `TypesError`.
+    pub fn from_ssz_bytes(bytes: &[u8]) -> Result<Self, TypesError> {
+        if bytes.len() != NETWORK_CONFIG_BYTES_LEN {
+            return Err(TypesError::InvalidLength {
+                structure: "NetworkConfig".into(),
+                expected: NETWORK_CONFIG_BYTES_LEN,
+                actual: bytes.len(),
+            });
+        }
+
+        let cursor = 0;
+        let (cursor, chain_id) = extract_u64("NetworkConfig", bytes, cursor)?;
+        let (cursor, genesis_time) = extract_u64("NetworkConfig", bytes, cursor)?;
+        let (cursor, genesis_validators_root) =
+            extract_fixed_bytes("NetworkConfig", bytes, cursor)?;
+        let (cursor, seconds_per_slot) = extract_u64("NetworkConfig", bytes, cursor)?;
+        let (cursor, slots_per_epoch) = extract_u64("NetworkConfig", bytes, cursor)?;
+        let (cursor, epochs_per_sync_committee_period) =
+            extract_u64("NetworkConfig", bytes, cursor)?;
+
+        if seconds_per_slot == 0 || slots_per_epoch == 0 || epochs_per_sync_committee_period == 0 {
+            return Err(deserialization_error!(
+                "NetworkConfig",
+                "Seconds per slot, slots per epoch and epochs per sync committee period must be non-zero"
+            ));
+        }
+
+        let fork_schedule = ForkSchedule::from_ssz_bytes(&bytes[cursor..])?;
+
+        Ok(Self {
+            chain_id,
+            genesis_time,
+            genesis_validators_root,
+            seconds_per_slot,
+            slots_per_epoch,
+            epochs_per_sync_committee_period,
+            fork_schedule,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::types::forks::{Fork, ForkSchedule, MAINNET_FORK_SCHEDULE};
+    use crate::types::network::{
+        NetworkConfig, HOLESKY_NETWORK_CONFIG, MAINNET_NETWORK_CONFIG, SEPOLIA_NETWORK_CONFIG,
+        SLOTS_PER_EPOCH,
+    };
+    use crate::types::utils::GENESIS_ROOT;
+
+    #[test]
+    fn test_ssz_serde_network_config() {
+        for config in [
+            MAINNET_NETWORK_CONFIG,
+            SEPOLIA_NETWORK_CONFIG,
+            HOLESKY_NETWORK_CONFIG,
+        ] {
+            let bytes = config.to_ssz_bytes();
+
+            assert_eq!(NetworkConfig::from_ssz_bytes(&bytes).unwrap(), config);
+        }
+
+        let zero_slots_per_epoch =
+            NetworkConfig::new(1, 0, GENESIS_ROOT, 12, 0, 256, MAINNET_FORK_SCHEDULE);
+
+        assert!(NetworkConfig::from_ssz_bytes(&zero_slots_per_epoch.to_ssz_bytes()).is_err());
+    }
+
+    #[test]
+    fn test_calc_slot_at() {
+        let genesis_time = MAINNET_NETWORK_CONFIG.genesis_time();
+
+        assert_eq!(MAINNET_NETWORK_CONFIG.calc_slot_at(0), 0);
+        assert_eq!(MAINNET_NETWORK_CONFIG.calc_slot_at(genesis_time + 11), 0);
+        assert_eq!(MAINNET_NETWORK_CONFIG.calc_slot_at(genesis_time + 12), 1);
+    }
+
+    #[test]
+    fn test_calc_sync_period() {
+        assert_eq!(MAINNET_NETWORK_CONFIG.calc_sync_period(&0), 0);
+        assert_eq!(MAINNET_NETWORK_CONFIG.calc_sync_period(&8191), 0);
+        assert_eq!(MAINNET_NETWORK_CONFIG.calc_sync_period(&8192), 1);
+
+        let config = NetworkConfig::new(1337, 0, GENESIS_ROOT, 6, 8, 8, MAINNET_FORK_SCHEDULE);
+
+        assert_eq!(config.calc_epoch(&63), 7);
+        assert_eq!(config.calc_sync_period(&63), 0);
+        assert_eq!(config.calc_sync_period(&64), 1);
+    }
+
+    #[test]
+    fn test_fork_at_slot() {
+        let config = NetworkConfig::new(
+            1337,
+            0,
+            GENESIS_ROOT,
+            12,
+            SLOTS_PER_EPOCH,
+            256,
+            ForkSchedule::new([4, 0, 0, 0], [5, 0, 0, 0], 10, [6, 0, 0, 0], 20),
+        );
+
+        assert_eq!(config.fork_at_slot(&0), Fork::Deneb);
+        assert_eq!(
+            config.fork_at_slot(&(10 * SLOTS_PER_EPOCH - 1)),
+            Fork::Deneb
+        );
+        assert_eq!(config.fork_at_slot(&(10 * SLOTS_PER_EPOCH)), Fork::Electra);
+        assert_eq!(config.fork_at_slot(&(20 * SLOTS_PER_EPOCH)), Fork::Fulu);
+
+        // The first block of a fork is signed with the domain of the previous one
+        assert_eq!(config.signature_fork(&(10 * SLOTS_PER_EPOCH)), Fork::Deneb);
+        assert_eq!(
+            config.signature_fork(&(10 * SLOTS_PER_EPOCH + 1)),
+            Fork::Electra
+        );
+
+        assert_eq!(MAINNET_NETWORK_CONFIG.fork_at_slot(&9_000_000), Fork::Deneb);
+        assert_eq!(
+            MAINNET_NETWORK_CONFIG.fork_at_slot(&12_000_000),
+            Fork::Electra
+        );
+        assert_eq!(MAINNET_NETWORK_CONFIG.fork_at_slot(&13_200_000), Fork::Fulu);
+    }
+
+    #[test]
+    fn test_sync_committee_domain() {
+        let electra_first_slot =
+            MAINNET_NETWORK_CONFIG.fork_schedule().electra_fork_epoch() * SLOTS_PER_EPOCH;
+
+        // Signatures included in the first slot of a fork are made with the previous fork version
+        assert_ne!(
+            MAINNET_NETWORK_CONFIG
+                .sync_committee_domain(&electra_first_slot)
+                .unwrap(),
+            MAINNET_NETWORK_CONFIG
+                .sync_committee_domain(&(electra_first_slot + 1))
+                .unwrap()
+        );
+        assert_eq!(
+            MAINNET_NETWORK_CONFIG
+                .sync_committee_domain(&electra_first_slot)
+                .unwrap(),
+            MAINNET_NETWORK_CONFIG
+                .sync_committee_domain(&(electra_first_slot - 1))
+                .unwrap()
+        );
+
+        // Networks sharing fork versions are still separated by their genesis validators root
+        assert_ne!(
+            MAINNET_NETWORK_CONFIG.sync_committee_domain(&0).unwrap(),
+            SEPOLIA_NETWORK_CONFIG.sync_committee_domain(&0).unwrap()
+        );
+    }
+}
@@ -8,14 +8,29 @@
 //!
 //! # Note
 //!
-//! The domain bytes is actually constant for Beacon blocks on the Deneb fork. Our constant representing
-//! it can be found as [`crate::types::utils::DOMAIN_BEACON_DENEB`].
+//! The domain bytes is constant for Beacon blocks within a given fork of a given network. It is
+//! derived from the fork version and the genesis validators root of the network through [`compute_domain`].
 
 use crate::crypto::error::CryptoError;
 use crate::crypto::hash::HashValue;
 use crate::merkle::utils::{merkle_root, DataType};
 use crate::merkle::Merkleized;
-use crate::types::Bytes32;
+use crate::types::forks::Version;
+use crate::types::utils::bytes_array_to_bytes32;
+use crate::types::{Bytes32, BYTES_32_LEN};
+
+/// Length in bytes of a domain type.
+pub const DOMAIN_TYPE_LEN: usize = 4;
+
+/// Type of a signing domain.
+///
+/// From [the phase0 specifications](https://github.com/ethereum/consensus-specs/blob/v1.4.0/specs/phase0/beacon-chain.md#custom-types).
+pub type DomainType = [u8; DOMAIN_TYPE_LEN];
+
+/// Domain type used by the sync committee to sign block headers.
+///
+/// From [the Altair specifications](https://github.com/ethereum/consensus-specs/blob/v1.4.0/specs/altair/beacon-chain.md#domain-types).
+pub const DOMAIN_SYNC_COMMITTEE: DomainType = [7, 0, 0, 0];
 
 pub struct SigningData {
     object_root: Bytes32,
@@ -38,3 +53,107 @@ impl Merkleized for SigningData {
         merkle_root(DataType::Struct(leaves))
     }
 }
+
+/// `ForkData` identifies a fork of a given network, it is used to compute signing domains.
+///
+/// From [the phase0 specifications](https://github.com/ethereum/consensus-specs/blob/v1.4.0/specs/phase0/beacon-chain.md#forkdata).
+pub struct ForkData {
+    current_version: Version,
+    genesis_validators_root: Bytes32,
+}
+
+impl ForkData {
+    pub const fn new(current_version: Version, genesis_validators_root: Bytes32) -> Self {
+        Self {
+            current_version,
+            genesis_validators_root,
+        }
+    }
+}
+
+impl Merkleized for ForkData {
+    fn hash_tree_root(&self) -> Result<HashValue, CryptoError> {
+        let leaves: Vec<HashValue> = vec![
+            bytes_array_to_bytes32(&self.current_version).into(),
+            self.genesis_validators_root.into(),
+        ];
+
+        merkle_root(DataType::Struct(leaves))
+    }
+}
+
+/// Computes the root of the `ForkData` for the given fork version and genesis validators root.
+///
+/// # Arguments
+///
+/// * `current_version` - The version of the fork.
+/// * `genesis_validators_root` - The genesis validators root of the network.
+///
+/// # Returns
+///
+/// A `Result` containing the fork data root or a `CryptoError`.
+///
+/// # Notes
+///
+/// From [the phase0 specifications](https://github.com/ethereum/consensus-specs/blob/v1.4.0/specs/phase0/beacon-chain.md#compute_fork_data_root).
+pub fn compute_fork_data_root(
+    current_version: &Version,
+    genesis_validators_root: &Bytes32,
+) -> Result<HashValue, CryptoError> {
+    ForkData::new(*current_version, *genesis_validators_root).hash_tree_root()
+}
+
+/// Computes the signing domain for the given domain type, fork version and genesis validators root.
+///
+/// # Arguments
+///
+/// * `domain_type` - The type of the domain.
+/// * `fork_version` - The version of the fork.
+/// * `genesis_validators_root` - The genesis validators root of the network.
+///
+/// # Returns
+///
+/// A `Result` containing the signing domain or a `CryptoError`.
+///
+/// # Notes
+///
+/// From [the phase0 specifications](https://github.com/ethereum/consensus-specs/blob/v1.4.0/specs/phase0/beacon-chain.md#compute_domain).
+pub fn compute_domain(
+    domain_type: &DomainType,
+    fork_version: &Version,
+    genesis_validators_root: &Bytes32,
+) -> Result<Bytes32, CryptoError> {
+    let fork_data_root = compute_fork_data_root(fork_version, genesis_validators_root)?;
+
+    let mut domain = [0; BYTES_32_LEN];
+    domain[..DOMAIN_TYPE_LEN].copy_from_slice(domain_type);
+    domain[DOMAIN_TYPE_LEN..]
+        .copy_from_slice(&fork_data_root.as_ref()[..BYTES_32_LEN - DOMAIN_TYPE_LEN]);
+
+    Ok(domain)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::types::forks::MAINNET_FORK_SCHEDULE;
+    use crate::types::signing_data::{compute_domain, DOMAIN_SYNC_COMMITTEE};
+    use crate::types::utils::GENESIS_ROOT;
+
+    #[test]
+    fn test_compute_domain() {
+        // Sync committee domain on mainnet for the Deneb fork
+        let expected = [
+            7, 0, 0, 0, 106, 149, 161, 169, 103, 133, 93, 103, 109, 72, 190, 105, 136, 59, 113, 38,
+            7, 249, 82, 213, 25, 141, 15, 86, 119, 86, 70, 54,
+        ];
+
+        let domain = compute_domain(
+            &DOMAIN_SYNC_COMMITTEE,
+            MAINNET_FORK_SCHEDULE.deneb_fork_version(),
+            &GENESIS_ROOT,
+        )
+        .unwrap();
+
+        assert_eq!(domain, expected);
+    }
+}
@@ -17,26 +17,29 @@ use crate::merkle::update_proofs::{
 use crate::merkle::Merkleized;
 use crate::types::block::{LightClientHeader, LIGHT_CLIENT_HEADER_BASE_BYTES_LEN};
 use crate::types::bootstrap::Bootstrap;
-use crate::types::committee::{SyncCommittee, SyncCommitteeBranch, SYNC_COMMITTEE_BYTES_LEN};
+use crate::types::committee::{SyncCommittee, SYNC_COMMITTEE_BYTES_LEN};
 use crate::types::error::{ConsensusError, StoreError, TypesError};
+use crate::types::network::{NetworkConfig, NETWORK_CONFIG_BYTES_LEN};
 use crate::types::signing_data::SigningData;
 use crate::types::update::{CompactUpdate, Update};
-use crate::types::utils::{
-    calc_sync_period, extract_u32, extract_u64, DOMAIN_BEACON_DENEB, OFFSET_BYTE_LENGTH, U64_LEN,
-};
+use crate::types::utils::{extract_u32, extract_u64, OFFSET_BYTE_LENGTH, U64_LEN};
 use crate::types::Bytes32;
 use crate::{deserialization_error, serialization_error};
 use anyhow::Result;
 use getset::Getters;
 
-pub const LIGHT_CLIENT_STORE_BASE_LENGTH: usize =
-    LIGHT_CLIENT_HEADER_BASE_BYTES_LEN * 2 + SYNC_COMMITTEE_BYTES_LEN + U64_LEN * 2 + 1;
+pub const LIGHT_CLIENT_STORE_BASE_LENGTH: usize = LIGHT_CLIENT_HEADER_BASE_BYTES_LEN * 2
+    + SYNC_COMMITTEE_BYTES_LEN
+    + U64_LEN * 2
+    + NETWORK_CONFIG_BYTES_LEN
+    + 1;
 
 pub const FINALIZED_HEADER_OFFSET: usize = OFFSET_BYTE_LENGTH
     + SYNC_COMMITTEE_BYTES_LEN
     + OFFSET_BYTE_LENGTH
     + OFFSET_BYTE_LENGTH
-    + U64_LEN * 2;
+    + U64_LEN * 2
+    + NETWORK_CONFIG_BYTES_LEN;
 
 /// The `LightClientStore` represents the fill state for our Light Client. It includes the necessary
 /// data to be maintained to verify the consensus rules in future updates.
@@ -51,6 +54,7 @@ pub struct LightClientStore {
     optimistic_header: LightClientHeader,
     previous_max_active_participants: u64,
     current_max_active_participants: u64,
+    network_config: NetworkConfig,
 }
 
 impl LightClientStore {
@@ -88,6 +92,7 @@ impl LightClientStore {
     ///
     /// * `trusted_block_root` - The block root of the trusted checkpoint.
     /// * `bootstrap` - The `Bootstrap` data to initialize the store.
+    /// * `network_config` - The configuration of the network the Light Client follows.
     ///
     /// # Returns
     ///
@@ -95,6 +100,7 @@ impl LightClientStore {
     pub fn initialize(
         trusted_block_root: Bytes32,
         bootstrap: &Bootstrap,
+        network_config: &NetworkConfig,
     ) -> Result<Self, StoreError> {
         // Ensure that we receive the `Bootstrap` for the correct checkpoint
         let bootstrap_block_root = bootstrap
@@ -114,6 +120,7 @@ impl LightClientStore {
             bootstrap.header().beacon().state_root(),
             bootstrap.current_sync_committee(),
             bootstrap.current_sync_committee_branch(),
+            network_config.fork_at_slot(bootstrap.header().beacon().slot()),
         )
         .map_err(|err| StoreError::InvalidCurrentCommitteeProof { source: err.into() })?;
 
@@ -130,6 +137,7 @@ impl LightClientStore {
             optimistic_header: bootstrap.header().clone(),
             previous_max_active_participants: 0,
             current_max_active_participants: 0,
+            network_config: *network_config,
         })
     }
 
@@ -173,8 +181,12 @@ impl LightClientStore {
         }
 
         let update_has_finalized_next_sync_committee = self.next_sync_committee().is_none()
-            && calc_sync_period(update.attested_header().beacon().slot())
-                == calc_sync_period(update.finalized_header().beacon().slot());
+            && self
+                .network_config
+                .calc_sync_period(update.attested_header().beacon().slot())
+                == self
+                    .network_config
+                    .calc_sync_period(update.finalized_header().beacon().slot());
 
         // Apply update if:
         // - enough signatures
@@ -229,8 +241,12 @@ impl LightClientStore {
         // We either want to receive:
         // - at initialization, an update for the same period that contains the next_sync_committee
         // - during the light client lifetime, an update for a new period
-        let snapshot_period = calc_sync_period(self.finalized_header().beacon().slot());
-        let update_sig_period = calc_sync_period(update.signature_slot());
+        let snapshot_period = self
+            .network_config
+            .calc_sync_period(self.finalized_header().beacon().slot());
+        let update_sig_period = self
+            .network_config
+            .calc_sync_period(update.signature_slot());
         let valid_period = if self.next_sync_committee().is_some() {
             update_sig_period == snapshot_period || update_sig_period == snapshot_period + 1
         } else {
@@ -244,8 +260,12 @@ impl LightClientStore {
         // If the update we receive slot is less than or equal to the latest verified finalized slot,
         // it is not relevant. The only exception is at initialization when we still don't know the
         // next_sync_committee
-        let update_attested_period = calc_sync_period(update.attested_header().beacon().slot());
-        let store_period = calc_sync_period(self.finalized_header().beacon().slot());
+        let update_attested_period = self
+            .network_config
+            .calc_sync_period(update.attested_header().beacon().slot());
+        let store_period = self
+            .network_config
+            .calc_sync_period(self.finalized_header().beacon().slot());
 
         let update_has_next_committee =
             self.next_sync_committee().is_none() && update_attested_period == store_period;
@@ -256,11 +276,17 @@ impl LightClientStore {
             return Err(ConsensusError::NotRelevant);
         }
 
+        // The proofs are built against the state of the attested block, in the fork it belongs to
+        let attested_fork = self
+            .network_config
+            .fork_at_slot(update.attested_header().beacon().slot());
+
         // Ensure that the received finality proof is valid
         let is_valid = is_finality_proof_valid(
             update.attested_header().beacon().state_root(),
             update.finalized_header().beacon(),
             update.finality_branch(),
+            attested_fork,
         )
         .map_err(|err| ConsensusError::MerkleError { source: err.into() })?;
 
@@ -269,7 +295,11 @@ impl LightClientStore {
         }
 
         // Ensure that the next sync committee proof is valid
-        if update.next_sync_committee_branch() == &SyncCommitteeBranch::default() {
+        if update
+            .next_sync_committee_branch()
+            .iter()
+            .all(|sibling| sibling == &Bytes32::default())
+        {
             if update.next_sync_committee() != &SyncCommittee::default() {
                 return Err(ConsensusError::ExpectedFinalityUpdate);
             }
@@ -278,6 +308,7 @@ impl LightClientStore {
                 update.attested_header().beacon().state_root(),
                 update.next_sync_committee(),
                 update.next_sync_committee_branch(),
+                attested_fork,
             )
             .map_err(|err| ConsensusError::MerkleError { source: err.into() })?;
 
@@ -302,7 +333,12 @@ impl LightClientStore {
             .hash_tree_root()
             .map_err(|err| ConsensusError::MerkleError { source: err.into() })?;
 
-        let signing_data = SigningData::new(header_root.hash(), DOMAIN_BEACON_DENEB);
+        let domain = self
+            .network_config
+            .sync_committee_domain(update.signature_slot())
+            .map_err(|err| ConsensusError::MerkleError { source: err.into() })?;
+
+        let signing_data = SigningData::new(header_root.hash(), domain);
 
         let signing_root = signing_data
             .hash_tree_root()
@@ -328,8 +364,12 @@ impl LightClientStore {
     ///
     /// From [the Altaïr specifications](https://github.com/ethereum/consensus-specs/blob/5cce790decfb362bef300a4ca9f8075b1699ccb1/specs/altair/light-client/sync-protocol.md#apply_light_client_update).
     fn apply_light_client_update(&mut self, update: &Update) {
-        let snapshot_period = calc_sync_period(self.finalized_header().beacon().slot());
-        let update_period = calc_sync_period(update.attested_header().beacon().slot());
+        let snapshot_period = self
+            .network_config
+            .calc_sync_period(self.finalized_header().beacon().slot());
+        let update_period = self
+            .network_config
+            .calc_sync_period(update.attested_header().beacon().slot());
 
         if self.next_sync_committee().is_none() {
             self.next_sync_committee = Some(update.next_sync_committee().clone());
@@ -398,6 +438,9 @@ impl LightClientStore {
         // Serialize current max active participants
         bytes.extend_from_slice(&self.current_max_active_participants.to_le_bytes());
 
+        // Serialize the network configuration
+        bytes.extend(self.network_config.to_ssz_bytes());
+
         if bytes.len() != FINALIZED_HEADER_OFFSET {
             return Err(serialization_error!(
                 "LightClientStore",
@@ -462,6 +505,11 @@ impl LightClientStore {
         let (cursor, current_max_active_participants) =
             extract_u64("LightClientStore", bytes, cursor)?;
 
+        // Deserialize the network configuration
+        let network_config =
+            NetworkConfig::from_ssz_bytes(&bytes[cursor..cursor + NETWORK_CONFIG_BYTES_LEN])?;
+        let cursor = cursor + NETWORK_CONFIG_BYTES_LEN;
+
         // Deserialize the finalized header
         if cursor != finalized_header_offset as usize {
             return Err(deserialization_error!(
@@ -503,6 +551,7 @@ impl LightClientStore {
             optimistic_header,
             previous_max_active_participants,
             current_max_active_participants,
+            network_config,
         })
     }
 }
@@ -515,24 +564,31 @@ impl LightClientStore {
 pub struct CompactStore {
     finalized_beacon_header_slot: u64,
     sync_committee: SyncCommittee,
+    network_config: NetworkConfig,
 }
 
 impl CompactStore {
     /// Initializes the `CompactStore` with the given finalized beacon
-    /// header slot and `SyncCommittee`.
+    /// header slot, `SyncCommittee` and `NetworkConfig`.
     ///
     /// # Arguments
     ///
     /// * `finalized_beacon_header_slot` - The slot of the finalized beacon header.
     /// * `sync_committee` - The `SyncCommittee` to initialize the store.
+    /// * `network_config` - The configuration of the network the Light Client follows.
     ///
     /// # Returns
     ///
     /// The initialized `CompactStore`.
-    pub const fn new(finalized_beacon_header_slot: u64, sync_committee: SyncCommittee) -> Self {
+    pub const fn new(
+        finalized_beacon_header_slot: u64,
+        sync_committee: SyncCommittee,
+        network_config: NetworkConfig,
+    ) -> Self {
         Self {
             finalized_beacon_header_slot,
             sync_committee,
+            network_config,
         }
     }
 
@@ -547,6 +603,7 @@ impl CompactStore {
         // Serialize the snapshot period
         bytes.extend_from_slice(&self.finalized_beacon_header_slot.to_le_bytes());
         bytes.extend_from_slice(&self.sync_committee.to_ssz_bytes());
+        bytes.extend(self.network_config.to_ssz_bytes());
 
         bytes
     }
@@ -562,9 +619,9 @@ impl CompactStore {
     /// A `Result` containing the deserialized `CompactStore` or a `TypesError` if the bytes are
     /// invalid.
     pub fn from_ssz_bytes(bytes: &[u8]) -> Result<Self, TypesError> {
-        if bytes.len() != U64_LEN + SYNC_COMMITTEE_BYTES_LEN {
+        if bytes.len() != U64_LEN + SYNC_COMMITTEE_BYTES_LEN + NETWORK_CONFIG_BYTES_LEN {
             return Err(TypesError::UnderLength {
-                minimum: U64_LEN + SYNC_COMMITTEE_BYTES_LEN,
+                minimum: U64_LEN + SYNC_COMMITTEE_BYTES_LEN + NETWORK_CONFIG_BYTES_LEN,
                 actual: bytes.len(),
                 structure: "CompactStore".into(),
             });
@@ -574,11 +631,17 @@ impl CompactStore {
         let finalized_beacon_header_slot = u64::from_le_bytes(bytes[..U64_LEN].try_into().unwrap());
 
         // Deserialize the sync committee
-        let sync_committee = SyncCommittee::from_ssz_bytes(&bytes[U64_LEN..])?;
+        let sync_committee =
+            SyncCommittee::from_ssz_bytes(&bytes[U64_LEN..U64_LEN + SYNC_COMMITTEE_BYTES_LEN])?;
+
+        // Deserialize the network configuration
+        let network_config =
+            NetworkConfig::from_ssz_bytes(&bytes[U64_LEN + SYNC_COMMITTEE_BYTES_LEN..])?;
 
         Ok(Self {
             finalized_beacon_header_slot,
             sync_committee,
+            network_config,
         })
     }
 
@@ -614,8 +677,12 @@ impl CompactStore {
             return Err(ConsensusError::InvalidTimestamp);
         }
 
-        let snapshot_period = calc_sync_period(self.finalized_beacon_header_slot());
-        let update_sig_period = calc_sync_period(update.signature_slot());
+        let snapshot_period = self
+            .network_config
+            .calc_sync_period(self.finalized_beacon_header_slot());
+        let update_sig_period = self
+            .network_config
+            .calc_sync_period(update.signature_slot());
         if snapshot_period != update_sig_period {
             return Err(ConsensusError::InvalidPeriod);
         }
@@ -625,6 +692,8 @@ impl CompactStore {
             update.attested_beacon_header().state_root(),
             update.finalized_header().beacon(),
             update.finality_branch(),
+            self.network_config
+                .fork_at_slot(update.attested_beacon_header().slot()),
         )
         .map_err(|err| ConsensusError::MerkleError { source: err.into() })?;
 
@@ -641,7 +710,12 @@ impl CompactStore {
             .hash_tree_root()
             .map_err(|err| ConsensusError::MerkleError { source: err.into() })?;
 
-        let signing_data = SigningData::new(header_root.hash(), DOMAIN_BEACON_DENEB);
+        let domain = self
+            .network_config
+            .sync_committee_domain(update.signature_slot())
+            .map_err(|err| ConsensusError::MerkleError { source: err.into() })?;
+
+        let signing_data = SigningData::new(header_root.hash(), domain);
 
         let signing_root = signing_data
             .hash_tree_root()
@@ -663,7 +737,10 @@ impl CompactStore {
 mod test {
     use crate::merkle::Merkleized;
     use crate::test_utils::generate_committee_change_test_assets;
+    use crate::types::forks::MAINNET_FORK_SCHEDULE;
+    use crate::types::network::{NetworkConfig, MAINNET_NETWORK_CONFIG};
     use crate::types::store::{CompactStore, LightClientStore};
+    use crate::types::utils::GENESIS_ROOT;
 
     #[test]
     fn test_simple_validate_and_apply_update() {
@@ -767,6 +844,35 @@ mod test {
         )
     }
 
+    #[test]
+    fn test_validate_update_other_network() {
+        let test_assets = generate_committee_change_test_assets();
+
+        test_assets
+            .store
+            .validate_light_client_update(&test_assets.update)
+            .unwrap();
+
+        // A different genesis validators root yields a different signing domain
+        let mut genesis_validators_root = GENESIS_ROOT;
+        genesis_validators_root[0] ^= 1;
+        let mut store = test_assets.store.clone();
+        store.network_config = NetworkConfig::new(
+            MAINNET_NETWORK_CONFIG.chain_id(),
+            MAINNET_NETWORK_CONFIG.genesis_time(),
+            genesis_validators_root,
+            MAINNET_NETWORK_CONFIG.seconds_per_slot(),
+            MAINNET_NETWORK_CONFIG.slots_per_epoch(),
+            MAINNET_NETWORK_CONFIG.epochs_per_sync_committee_period(),
+            MAINNET_FORK_SCHEDULE,
+        );
+
+        assert!(store
+            .validate_light_client_update(&test_assets.update)
+            .is_err());
+        assert_eq!(test_assets.store.network_config(), &MAINNET_NETWORK_CONFIG);
+    }
+
     #[test]
     fn test_ssz_serde_light_client_store() {
         let test_assets = generate_committee_change_test_assets();
@@ -785,6 +891,7 @@ mod test {
         let compact_store = CompactStore::new(
             *test_assets.store.finalized_header().beacon().slot(),
             test_assets.store.current_sync_committee().clone(),
+            *test_assets.store.network_config(),
         );
 
         let serialized_store = compact_store.to_ssz_bytes();
@@ -13,25 +13,43 @@ use crate::merkle::error::MerkleError;
 use crate::merkle::update_proofs::is_execution_payload_proof_valid;
 use crate::types::block::consensus::BeaconBlockHeader;
 use crate::types::block::{LightClientHeader, LIGHT_CLIENT_HEADER_BASE_BYTES_LEN};
-use crate::types::committee::{
-    SyncCommittee, SyncCommitteeBranch, SYNC_COMMITTEE_BRANCH_NBR_SIBLINGS,
-    SYNC_COMMITTEE_BYTES_LEN,
-};
+use crate::types::committee::{SyncCommittee, SyncCommitteeBranch, SYNC_COMMITTEE_BYTES_LEN};
 use crate::types::error::TypesError;
-use crate::types::utils::{extract_u32, extract_u64, OFFSET_BYTE_LENGTH, U64_LEN};
-use crate::types::{
-    Bytes32, FinalizedRootBranch, BYTES_32_LEN, FINALIZED_CHECKPOINT_BRANCH_NBR_SIBLINGS,
-};
+use crate::types::forks::Fork;
+use crate::types::utils::{extract_branch, extract_u32, extract_u64, OFFSET_BYTE_LENGTH, U64_LEN};
+use crate::types::{Bytes32, FinalizedRootBranch, BYTES_32_LEN};
 use getset::Getters;
 
 /// Base length of a `Update` struct in bytes.
 pub const UPDATE_BASE_BYTES_LEN: usize = LIGHT_CLIENT_HEADER_BASE_BYTES_LEN * 2
     + SYNC_COMMITTEE_BYTES_LEN
-    + SYNC_COMMITTEE_BRANCH_NBR_SIBLINGS * BYTES_32_LEN
-    + FINALIZED_CHECKPOINT_BRANCH_NBR_SIBLINGS * BYTES_32_LEN
+    + Fork::Deneb.sync_committee_branch_depth() * BYTES_32_LEN
+    + Fork::Deneb.finalized_root_branch_depth() * BYTES_32_LEN
     + SYNC_AGGREGATE_BYTES_LEN
     + U64_LEN;
 
+/// Computes the offset of the attested header in a serialized `Update`.
+///
+/// # Arguments
+///
+/// * `sync_committee_branch_depth` - The number of siblings in the next sync committee branch.
+/// * `finality_branch_depth` - The number of siblings in the finality branch.
+///
+/// # Returns
+///
+/// The offset of the attested header.
+const fn update_attested_header_offset(
+    sync_committee_branch_depth: usize,
+    finality_branch_depth: usize,
+) -> usize {
+    OFFSET_BYTE_LENGTH * 2
+        + SYNC_COMMITTEE_BYTES_LEN
+        + sync_committee_branch_depth * BYTES_32_LEN
+        + finality_branch_depth * BYTES_32_LEN
+        + SYNC_AGGREGATE_BYTES_LEN
+        + U64_LEN
+}
+
 /// A data structure containing the necessary data for a light client to update its state from the Beacon chain.
 ///
 /// From [the Altaïr specifications](https://github.com/ethereum/consensus-specs/blob/81f3ea8322aff6b9fb15132d050f8f98b16bdba4/specs/altair/light-client/sync-protocol.md#lightclientupdate).
@@ -52,7 +70,11 @@ impl From<FinalityUpdate> for Update {
         Self {
             attested_header: finality_update.attested_header,
             next_sync_committee: SyncCommittee::default(),
-            next_sync_committee_branch: SyncCommitteeBranch::default(),
+            // The finalized root sits one level below the sync committees in the `BeaconState`
+            next_sync_committee_branch: vec![
+                Bytes32::default();
+                finality_update.finality_branch.len().saturating_sub(1)
+            ],
             finalized_header: finality_update.finalized_header,
             finality_branch: finality_update.finality_branch,
             sync_aggregate: finality_update.sync_aggregate,
@@ -71,12 +93,10 @@ impl Update {
         let mut bytes = vec![];
 
         // Serialize offset for the attested header
-        let attested_header_offset = OFFSET_BYTE_LENGTH * 2
-            + SYNC_COMMITTEE_BYTES_LEN
-            + SYNC_COMMITTEE_BRANCH_NBR_SIBLINGS * BYTES_32_LEN
-            + FINALIZED_CHECKPOINT_BRANCH_NBR_SIBLINGS * BYTES_32_LEN
-            + SYNC_AGGREGATE_BYTES_LEN
-            + U64_LEN;
+        let attested_header_offset = update_attested_header_offset(
+            self.next_sync_committee_branch.len(),
+            self.finality_branch.len(),
+        );
         bytes.extend_from_slice(&(attested_header_offset as u32).to_le_bytes());
         let attested_header_bytes = self.attested_header.to_ssz_bytes();
 
@@ -135,55 +155,36 @@ impl Update {
         // Deserialize `LightClientHeader` offset
         let (cursor, offset_attested_header) = extract_u32("Update", bytes, cursor)?;
 
+        // The branch depths depend on the fork, which is reflected in the offset
+        let fork = Fork::ALL
+            .into_iter()
+            .find(|fork| {
+                update_attested_header_offset(
+                    fork.sync_committee_branch_depth(),
+                    fork.finalized_root_branch_depth(),
+                ) == offset_attested_header as usize
+            })
+            .ok_or_else(|| {
+                deserialization_error!("Update", "Unknown offset for attested header")
+            })?;
+
         // Deserialize `SyncCommittee`
         let current_sync_committee =
             SyncCommittee::from_ssz_bytes(&bytes[cursor..cursor + SYNC_COMMITTEE_BYTES_LEN])?;
 
         // Deserialize `SyncCommitteeBranch`
         let cursor = cursor + SYNC_COMMITTEE_BYTES_LEN;
-        let current_sync_committee_branch = (0..SYNC_COMMITTEE_BRANCH_NBR_SIBLINGS)
-            .map(|i| {
-                let start = cursor + i * BYTES_32_LEN;
-                let end = start + BYTES_32_LEN;
-                let returned_bytes = &bytes[start..end];
-                returned_bytes.try_into().map_err(|_| {
-                    deserialization_error!(
-                        "Update",
-                        "Failed to convert bytes into SyncCommitteeBranch"
-                    )
-                })
-            })
-            .collect::<Result<Vec<[u8; BYTES_32_LEN]>, _>>()?
-            .try_into()
-            .map_err(|_| {
-                deserialization_error!("Update", "Failed to convert bytes into SyncCommitteeBranch")
-            })?;
+        let (cursor, current_sync_committee_branch) =
+            extract_branch("Update", bytes, cursor, fork.sync_committee_branch_depth())?;
 
         // Deserialize `LightClientHeader` offset
-        let cursor = cursor + SYNC_COMMITTEE_BRANCH_NBR_SIBLINGS * BYTES_32_LEN;
         let (cursor, offset_finalized_header) = extract_u32("Update", bytes, cursor)?;
 
         // Deserialize `FinalizedRootBranch`
-        let finality_branch = (0..FINALIZED_CHECKPOINT_BRANCH_NBR_SIBLINGS)
-            .map(|i| {
-                let start = cursor + i * BYTES_32_LEN;
-                let end = start + BYTES_32_LEN;
-                let returned_bytes = &bytes[start..end];
-                returned_bytes.try_into().map_err(|_| {
-                    deserialization_error!(
-                        "Update",
-                        "Failed to convert bytes into FinalizedRootBranch"
-                    )
-                })
-            })
-            .collect::<Result<Vec<[u8; BYTES_32_LEN]>, _>>()?
-            .try_into()
-            .map_err(|_| {
-                deserialization_error!("Update", "Failed to convert bytes into FinalizedRootBranch")
-            })?;
+        let (cursor, finality_branch) =
+            extract_branch("Update", bytes, cursor, fork.finalized_root_branch_depth())?;
 
         // Deserialize `SyncAggregate`
-        let cursor = cursor + FINALIZED_CHECKPOINT_BRANCH_NBR_SIBLINGS * BYTES_32_LEN;
         let sync_aggregate =
             SyncAggregate::from_ssz_bytes(&bytes[cursor..cursor + SYNC_AGGREGATE_BYTES_LEN])?;
 
@@ -228,10 +229,26 @@ impl Update {
 
 /// Base length of a `Update` struct in bytes.
 pub const FINALITY_UPDATE_BASE_BYTES_LEN: usize = LIGHT_CLIENT_HEADER_BASE_BYTES_LEN * 2
-    + FINALIZED_CHECKPOINT_BRANCH_NBR_SIBLINGS * BYTES_32_LEN
+    + Fork::Deneb.finalized_root_branch_depth() * BYTES_32_LEN
     + SYNC_AGGREGATE_BYTES_LEN
     + U64_LEN;
 
+/// Computes the offset of the attested header in a serialized `FinalityUpdate`.
+///
+/// # Arguments
+///
+/// * `finality_branch_depth` - The number of siblings in the finality branch.
+///
+/// # Returns
+///
+/// The offset of the attested header.
+const fn finality_update_attested_header_offset(finality_branch_depth: usize) -> usize {
+    OFFSET_BYTE_LENGTH * 2
+        + finality_branch_depth * BYTES_32_LEN
+        + SYNC_AGGREGATE_BYTES_LEN
+        + U64_LEN
+}
+
 /// Structure representing a finality update that can be fetched from the Beacon network.
 ///
 /// From [the Altaïr specifications](https://github.com/ethereum/consensus-specs/blob/v1.3.0/specs/altair/light-client/sync-protocol.md#lightclientfinalityupdate).
@@ -255,10 +272,8 @@ impl FinalityUpdate {
         let mut bytes = vec![];
 
         // Serialize offset for the attested header
-        let attested_header_offset = OFFSET_BYTE_LENGTH * 2
-            + FINALIZED_CHECKPOINT_BRANCH_NBR_SIBLINGS * BYTES_32_LEN
-            + SYNC_AGGREGATE_BYTES_LEN
-            + U64_LEN;
+        let attested_header_offset =
+            finality_update_attested_header_offset(self.finality_branch.len());
         bytes.extend_from_slice(&(attested_header_offset as u32).to_le_bytes());
         let attested_header_bytes = self.attested_header.to_ssz_bytes();
 
@@ -309,30 +324,25 @@ impl FinalityUpdate {
         // Deserialize `LightClientHeader` offset
         let (cursor, offset_attested_header) = extract_u32("Update", bytes, cursor)?;
 
+        // The branch depth depends on the fork, which is reflected in the offset
+        let fork = Fork::ALL
+            .into_iter()
+            .find(|fork| {
+                finality_update_attested_header_offset(fork.finalized_root_branch_depth())
+                    == offset_attested_header as usize
+            })
+            .ok_or_else(|| {
+                deserialization_error!("Update", "Unknown offset for attested header")
+            })?;
+
         // Deserialize `LightClientHeader` offset
         let (cursor, offset_finalized_header) = extract_u32("Update", bytes, cursor)?;
 
         // Deserialize `FinalizedRootBranch`
-        let finality_branch = (0..FINALIZED_CHECKPOINT_BRANCH_NBR_SIBLINGS)
-            .map(|i| {
-                let start = cursor + i * BYTES_32_LEN;
-                let end = start + BYTES_32_LEN;
-                let returned_bytes = &bytes[start..end];
-                returned_bytes.try_into().map_err(|_| {
-                    deserialization_error!(
-                        "Update",
-                        "Failed to convert bytes into FinalizedRootBranch"
-                    )
-                })
-            })
-            .collect::<Result<Vec<[u8; BYTES_32_LEN]>, _>>()?
-            .try_into()
-            .map_err(|_| {
-                deserialization_error!("Update", "Failed to convert bytes into FinalizedRootBranch")
-            })?;
+        let (cursor, finality_branch) =
+            extract_branch("Update", bytes, cursor, fork.finalized_root_branch_depth())?;
 
         // Deserialize `SyncAggregate`
-        let cursor = cursor + FINALIZED_CHECKPOINT_BRANCH_NBR_SIBLINGS * BYTES_32_LEN;
         let sync_aggregate =
             SyncAggregate::from_ssz_bytes(&bytes[cursor..cursor + SYNC_AGGREGATE_BYTES_LEN])?;
 
@@ -374,11 +384,25 @@ impl FinalityUpdate {
 }
 
 /// Base length of a `CompactUpdate` struct in SSZ bytes.
-pub const COMPACT_ATTESTED_BEACON_OFFSET: usize = OFFSET_BYTE_LENGTH * 2
-    + BYTES_32_LEN
-    + FINALIZED_CHECKPOINT_BRANCH_NBR_SIBLINGS * BYTES_32_LEN
-    + SYNC_AGGREGATE_BYTES_LEN
-    + U64_LEN;
+pub const COMPACT_ATTESTED_BEACON_OFFSET: usize =
+    compact_update_attested_beacon_offset(Fork::Deneb.finalized_root_branch_depth());
+
+/// Computes the offset of the attested beacon header in a serialized `CompactUpdate`.
+///
+/// # Arguments
+///
+/// * `finality_branch_depth` - The number of siblings in the finality branch.
+///
+/// # Returns
+///
+/// The offset of the attested beacon header.
+const fn compact_update_attested_beacon_offset(finality_branch_depth: usize) -> usize {
+    OFFSET_BYTE_LENGTH * 2
+        + BYTES_32_LEN
+        + finality_branch_depth * BYTES_32_LEN
+        + SYNC_AGGREGATE_BYTES_LEN
+        + U64_LEN
+}
 
 /// A compact representation of a `Update` struct.
 #[derive(Debug, Clone, Eq, PartialEq, Getters)]
@@ -431,12 +455,13 @@ impl CompactUpdate {
         let mut bytes = vec![];
 
         // Serialize attested beacon header
-        bytes.extend_from_slice(&(COMPACT_ATTESTED_BEACON_OFFSET as u32).to_le_bytes());
+        let attested_beacon_offset =
+            compact_update_attested_beacon_offset(self.finality_branch.len());
+        bytes.extend_from_slice(&(attested_beacon_offset as u32).to_le_bytes());
         let attested_header_bytes = self.attested_beacon_header.to_ssz_bytes();
 
         // Serialize finalized beacon block header
-        let finalized_block_header_offset =
-            attested_header_bytes.len() + COMPACT_ATTESTED_BEACON_OFFSET;
+        let finalized_block_header_offset = attested_header_bytes.len() + attested_beacon_offset;
         bytes.extend_from_slice(&(finalized_block_header_offset as u32).to_le_bytes());
         let finalized_block_header_bytes = self.finalized_header.to_ssz_bytes();
 
@@ -486,6 +511,17 @@ impl CompactUpdate {
         // Deserialize attested beacon header offset
         let (cursor, offset_attested_beacon_header) = extract_u32("CompactUpdate", bytes, cursor)?;
 
+        // The branch depth depends on the fork, which is reflected in the offset
+        let fork = Fork::ALL
+            .into_iter()
+            .find(|fork| {
+                compact_update_attested_beacon_offset(fork.finalized_root_branch_depth())
+                    == offset_attested_beacon_header as usize
+            })
+            .ok_or_else(|| {
+                deserialization_error!("CompactUpdate", "Unknown offset for attested beacon header")
+            })?;
+
         // Deserialize finalized beacon block header offset
         let (cursor, offset_finalized_beacon_block_header) =
             extract_u32("CompactUpdate", bytes, cursor)?;
@@ -499,29 +535,14 @@ impl CompactUpdate {
 
         // Deserialize finality branch
         let cursor = cursor + BYTES_32_LEN;
-        let finality_branch = (0..FINALIZED_CHECKPOINT_BRANCH_NBR_SIBLINGS)
-            .map(|i| {
-                let start = cursor + i * BYTES_32_LEN;
-                let end = start + BYTES_32_LEN;
-                let returned_bytes = &bytes[start..end];
-                returned_bytes.try_into().map_err(|_| {
-                    deserialization_error!(
-                        "CompactUpdate",
-                        "Failed to convert bytes into FinalizedRootBranch"
-                    )
-                })
-            })
-            .collect::<Result<Vec<[u8; BYTES_32_LEN]>, _>>()?
-            .try_into()
-            .map_err(|_| {
-                deserialization_error!(
-                    "CompactUpdate",
-                    "Failed to convert bytes into FinalizedRootBranch"
-                )
-            })?;
+        let (cursor, finality_branch) = extract_branch(
+            "CompactUpdate",
+            bytes,
+            cursor,
+            fork.finalized_root_branch_depth(),
+        )?;
 
         // Deserialize sync aggregate
-        let cursor = cursor + FINALIZED_CHECKPOINT_BRANCH_NBR_SIBLINGS * BYTES_32_LEN;
         let sync_aggregate =
             SyncAggregate::from_ssz_bytes(&bytes[cursor..cursor + SYNC_AGGREGATE_BYTES_LEN])?;
 
@@ -583,6 +604,7 @@ impl CompactUpdate {
 mod test {
     use super::*;
     use crate::merkle::update_proofs::{is_finality_proof_valid, is_next_committee_proof_valid};
+    use crate::types::forks::Fork;
     use std::env::current_dir;
     use std::fs;
 
@@ -615,6 +637,7 @@ mod test {
             update.attested_header().beacon().state_root(),
             update.next_sync_committee(),
             update.next_sync_committee_branch(),
+            Fork::Deneb,
         )
         .unwrap();
 
@@ -624,6 +647,7 @@ mod test {
             update.attested_header().beacon().state_root(),
             update.finalized_header().beacon(),
             update.finality_branch(),
+            Fork::Deneb,
         )
         .unwrap();
 
@@ -13,16 +13,11 @@ pub const OFFSET_BYTE_LENGTH: usize = 4;
 /// Length of u64 in bytes.
 pub const U64_LEN: usize = (u64::BITS / 8) as usize;
 
-/// Genesis root of the Beacon chain.
+/// Genesis validators root of the Beacon chain on mainnet.
 pub const GENESIS_ROOT: Bytes32 = [
     75, 54, 61, 185, 78, 40, 97, 32, 215, 110, 185, 5, 52, 15, 221, 78, 84, 191, 233, 240, 107,
     243, 63, 246, 207, 90, 210, 127, 81, 27, 254, 149,
 ];
-/// Domain type for the Beacon chain.
-pub const DOMAIN_BEACON_DENEB: Bytes32 = [
-    7, 0, 0, 0, 106, 149, 161, 169, 103, 133, 93, 103, 109, 72, 190, 105, 136, 59, 113, 38, 7, 249,
-    82, 213, 25, 141, 15, 86, 119, 86, 70, 54,
-];
 
 /// Utility method to extract the N bytes at a given cursor from a byte array.
 ///
@@ -115,6 +110,35 @@ pub fn extract_u32(
     Ok((cursor + OFFSET_BYTE_LENGTH, result))
 }
 
+/// Utility method to extract a Merkle branch of a given depth at a given cursor from a byte array.
+///
+/// # Arguments
+///
+/// * `structure` - The name of the structure being extracted.
+/// * `bytes` - The byte array to extract from.
+/// * `cursor` - The current cursor position in the byte array.
+/// * `depth` - The number of siblings in the branch.
+///
+/// # Returns
+///
+/// A tuple containing the new cursor position and the extracted branch.
+pub fn extract_branch(
+    structure: &str,
+    bytes: &[u8],
+    cursor: usize,
+    depth: usize,
+) -> Result<(usize, Vec<Bytes32>), TypesError> {
+    (0..depth).try_fold(
+        (cursor, Vec::with_capacity(depth)),
+        |(cursor, mut branch), _| {
+            let (cursor, node) = extract_fixed_bytes::<BYTES_32_LEN>(structure, bytes, cursor)?;
+            branch.push(node);
+
+            Ok((cursor, branch))
+        },
+    )
+}
+
 /// Utility to convert a slice of bits into a slice of bytes.
 ///
 /// # Arguments
@@ -284,17 +308,3 @@ pub fn bytes_array_to_bytes32(bytes: &[u8]) -> [u8; BYTES_32_LEN] {
     padded[..cmp::min(bytes.len(), BYTES_32_LEN)].copy_from_slice(bytes);
     padded
 }
-
-/// Calculate the sync period for a given slot number.
-///
-/// # Arguments
-///
-/// * `slot` - The slot number.
-///
-/// # Returns
-///
-/// The sync period.
-pub fn calc_sync_period(slot: &u64) -> u64 {
-    let epoch = slot / 32; // 32 slots per epoch
-    epoch / 256 // 256 epochs per sync committee
-}
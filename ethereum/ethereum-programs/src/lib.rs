@@ -4,3 +4,6 @@
 pub const INCLUSION_PROGRAM: &[u8] = include_bytes!("../artifacts/inclusion-program");
 
 pub const COMMITTEE_CHANGE_PROGRAM: &[u8] = include_bytes!("../artifacts/committee-change-program");
+
+pub const COMMITTEE_CHANGE_AGGREGATION_PROGRAM: &[u8] =
+    include_bytes!("../artifacts/committee-change-aggregation-program");
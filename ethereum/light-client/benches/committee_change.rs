@@ -4,6 +4,7 @@
 use ethereum_lc::proofs::committee_change::{CommitteeChangeIn, CommitteeChangeProver};
 use ethereum_lc::proofs::{Prover, ProvingMode};
 use ethereum_lc_core::types::bootstrap::Bootstrap;
+use ethereum_lc_core::types::network::MAINNET_NETWORK_CONFIG;
 use ethereum_lc_core::types::store::LightClientStore;
 use ethereum_lc_core::types::update::Update;
 use serde::Serialize;
@@ -54,7 +55,9 @@ impl BenchmarkAssets {
             .try_into()
             .unwrap();
 
-        let store = LightClientStore::initialize(trusted_block_root, &bootstrap).unwrap();
+        let store =
+            LightClientStore::initialize(trusted_block_root, &bootstrap, &MAINNET_NETWORK_CONFIG)
+                .unwrap();
 
         let prover = CommitteeChangeProver::new();
 
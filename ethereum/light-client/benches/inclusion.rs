@@ -6,6 +6,7 @@ use ethereum_lc::proofs::{Prover, ProvingMode};
 use ethereum_lc::types::storage::GetProofResponse;
 use ethereum_lc_core::merkle::storage_proofs::EIP1186Proof;
 use ethereum_lc_core::types::bootstrap::Bootstrap;
+use ethereum_lc_core::types::network::MAINNET_NETWORK_CONFIG;
 use ethereum_lc_core::types::store::LightClientStore;
 use ethereum_lc_core::types::update::{FinalityUpdate, Update};
 use serde::Serialize;
@@ -65,7 +66,9 @@ impl BenchmarkAssets {
             .try_into()
             .unwrap();
 
-        let mut store = LightClientStore::initialize(trusted_block_root, &bootstrap).unwrap();
+        let mut store =
+            LightClientStore::initialize(trusted_block_root, &bootstrap, &MAINNET_NETWORK_CONFIG)
+                .unwrap();
 
         store.process_light_client_update(&update).unwrap();
 
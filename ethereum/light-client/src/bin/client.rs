@@ -1,23 +1,28 @@
 // Copyright (c) Argument Computer Corporation
 // SPDX-License-Identifier: Apache-2.0
 
-use anyhow::Result;
-use clap::Parser;
+use anyhow::{anyhow, Result};
+use clap::{Parser, ValueEnum};
 use ethereum_lc::client::error::ClientError;
+use ethereum_lc::client::persistence::{StorePersistence, DEFAULT_WEAK_SUBJECTIVITY_PERIOD};
 use ethereum_lc::client::Client;
 use ethereum_lc::proofs::committee_change::CommitteeChangeOut;
 use ethereum_lc::proofs::inclusion::StorageInclusionOut;
 use ethereum_lc::proofs::{ProofType, ProvingMode};
-use ethereum_lc_core::crypto::hash::HashValue;
+use ethereum_lc::types::config::load_network_config;
+use ethereum_lc_core::crypto::hash::{keccak256_hash, HashValue};
 use ethereum_lc_core::merkle::storage_proofs::EIP1186Proof;
+use ethereum_lc_core::types::network::{
+    NetworkConfig, HOLESKY_NETWORK_CONFIG, MAINNET_NETWORK_CONFIG, SEPOLIA_NETWORK_CONFIG,
+};
 use ethereum_lc_core::types::store::LightClientStore;
 use ethereum_lc_core::types::update::Update;
-use ethereum_lc_core::types::utils::calc_sync_period;
-use log::{debug, error, info};
+use log::{debug, error, info, warn};
 use std::env;
 use std::fmt::Display;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use tokio::sync::{mpsc, OwnedSemaphorePermit, RwLock, Semaphore};
 use tokio::task::JoinHandle;
 
@@ -26,7 +31,7 @@ use tokio::task::JoinHandle;
 /// From [the Altair specifications](https://github.com/ethereum/consensus-specs/blob/dev/specs/altair/light-client/p2p-interface.md#configuration).
 pub const MAX_REQUEST_LIGHT_CLIENT_UPDATES: u8 = 128;
 
-/// Address for which we fetch the proof of storage.
+/// Address for which we fetch the proof of storage on mainnet, if none is specified.
 /// From [the Uniswap v2 documentation](https://docs.uniswap.org/contracts/v2/reference/smart-contracts/v2-deployments).
 pub const UNISWAP_V2_ADDRESS: &str = "0x5C69bEe701ef814a2B6a3EDD4B1652CB9cc5aA6f";
 
@@ -38,7 +43,8 @@ pub const ALL_PAIRS_STORAGE_KEY: &str =
 /// The CLI for the light client.
 #[derive(Parser)]
 struct Cli {
-    /// The address of the checkpoint service provider.
+    /// The address of the checkpoint service provider. It should expose the checkpointz API
+    /// for the selected network.
     ///
     /// See https://eth-clients.github.io/checkpoint-sync-endpoints
     #[arg(short, long)]
@@ -57,6 +63,48 @@ struct Cli {
     /// The address of the RPC provider
     #[arg(short, long)]
     rpc_provider_address: String,
+
+    /// The network followed by the remote services.
+    ///
+    /// Use `custom` along with `--network-config` for networks without a preset.
+    #[arg(short, long, value_enum, default_value_t = Network::Mainnet)]
+    network: Network,
+
+    /// Path to a TOML or YAML file describing the network to follow, for the `custom` network.
+    #[arg(long, required_if_eq("network", "custom"))]
+    network_config: Option<PathBuf>,
+
+    /// The address of the contract for which we prove storage inclusion.
+    ///
+    /// Defaults to the Uniswap v2 factory on mainnet, required on other networks.
+    #[arg(long, requires = "storage_key")]
+    contract_address: Option<String>,
+
+    /// The storage key for which we prove inclusion in the contract.
+    ///
+    /// Defaults to the `allPairs` mapping of the Uniswap v2 factory on mainnet, required on other networks.
+    #[arg(long, requires = "contract_address")]
+    storage_key: Option<String>,
+
+    /// Directory in which snapshots of the store are saved, to resume from them after a restart.
+    ///
+    /// If not specified, the light client bootstraps from the latest checkpoint on every start.
+    #[arg(long)]
+    store_directory: Option<PathBuf>,
+
+    /// Weak subjectivity period, in epochs. Snapshots whose finalized header is older than this
+    /// are not resumed from.
+    #[arg(long, default_value_t = DEFAULT_WEAK_SUBJECTIVITY_PERIOD)]
+    weak_subjectivity_period: u64,
+}
+
+/// The networks that the light client can follow.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, ValueEnum)]
+enum Network {
+    Mainnet,
+    Sepolia,
+    Holesky,
+    Custom,
 }
 
 #[derive(Debug, Clone)]
@@ -106,12 +154,36 @@ async fn main() -> Result<()> {
         beacon_node_address,
         proof_server_address,
         rpc_provider_address,
-        ..
+        network,
+        network_config,
+        contract_address,
+        storage_key,
+        store_directory,
+        weak_subjectivity_period,
     } = Cli::parse();
 
     // Initialize the logger.
     env_logger::init();
 
+    let network_config = resolve_network_config(network, network_config.as_deref())?;
+
+    // Resolve the storage slot to prove inclusion for.
+    let (contract_address, storage_key) = match (contract_address, storage_key) {
+        (Some(contract_address), Some(storage_key)) => (contract_address, storage_key),
+        _ if network == Network::Mainnet => (
+            String::from(UNISWAP_V2_ADDRESS),
+            String::from(ALL_PAIRS_STORAGE_KEY),
+        ),
+        _ => {
+            return Err(anyhow!(
+                "--contract-address and --storage-key are required for the {network:?} network"
+            ))
+        }
+    };
+
+    let persistence =
+        store_directory.map(|directory| StorePersistence::new(directory, weak_subjectivity_period));
+
     let checkpoint_provider_address = Arc::new(checkpoint_provider_address);
     let beacon_node_address = Arc::new(beacon_node_address);
     let proof_server_address = Arc::new(proof_server_address);
@@ -124,6 +196,8 @@ async fn main() -> Result<()> {
         beacon_node_address,
         proof_server_address,
         rpc_provider_address,
+        network_config,
+        persistence.as_ref(),
     ))
     .await
     .expect("Failed to initialize light client");
@@ -151,6 +225,7 @@ async fn main() -> Result<()> {
         verifier_state,
         client.clone(),
         store.clone(),
+        persistence,
     ));
 
     debug!("Start listening for Eth data");
@@ -172,8 +247,8 @@ async fn main() -> Result<()> {
             info!("Fetching EIP1186 proof...");
             // Fetch EIP1186 proof.
             let inclusion_merkle_proof = Box::pin(client.get_proof(
-                UNISWAP_V2_ADDRESS,
-                &[String::from(ALL_PAIRS_STORAGE_KEY)],
+                &contract_address,
+                &[storage_key.clone()],
                 &format!(
                         "0x{}",
                         hex::encode(
@@ -258,6 +333,8 @@ async fn initialize_light_client(
     beacon_node_address: Arc<String>,
     proof_server_address: Arc<String>,
     rpc_provider_address: Arc<String>,
+    network_config: NetworkConfig,
+    persistence: Option<&StorePersistence>,
 ) -> Result<(Client, Box<LightClientStore>, VerifierState)> {
     // Instantiate client.
     let client = Client::new(
@@ -265,55 +342,55 @@ async fn initialize_light_client(
         &beacon_node_address,
         &proof_server_address,
         &rpc_provider_address,
+        &network_config,
     );
 
     info!("Testing connection to endpoints...");
 
-    info!("Fetching latest state checkpoint and bootstrap data...");
-
-    // Fetch latest state checkpoint.
-    let checkpoint = client
-        .get_checkpoint(None)
+    client
+        .check_network()
         .await
-        .expect("Failed to fetch checkpoint");
-
-    let checkpoint_block_root = match checkpoint.block_root() {
-        Some(block_root) => block_root,
-        None => panic!("No block root found in checkpoint"),
-    };
-
-    info!("Latest checkpoint: {:?}", checkpoint_block_root);
-
-    // Fetch bootstrap data.
-    let bootstrap = client
-        .get_bootstrap_data(checkpoint_block_root)
-        .await
-        .expect("Failed to fetch bootstrap data");
+        .expect("Remote services do not follow the configured network");
+
+    // Resume from the latest snapshot of the store, if any.
+    let current_slot =
+        network_config.calc_slot_at(SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs());
+    let snapshot =
+        persistence.and_then(
+            |persistence| match persistence.load(&network_config, current_slot) {
+                Ok(snapshot) => snapshot,
+                Err(err) => {
+                    warn!("Could not resume from the store snapshot: {err}");
+                    None
+                }
+            },
+        );
 
-    info!(
-        "Initializing Light Client store at checkpoint: {:?}",
-        checkpoint_block_root
-    );
+    let mut store = match snapshot {
+        Some(store) => {
+            info!(
+                "Resuming from store snapshot at slot: {:?}",
+                store.finalized_header().beacon().slot()
+            );
+            store
+        }
+        None => {
+            let store = Box::pin(bootstrap_store(&client, &network_config)).await;
 
-    // Initialize store
-    let trusted_block_root = hex::decode(
-        checkpoint_block_root
-            .strip_prefix("0x")
-            .expect("Checkpoint should start with \"0x\""),
-    )
-    .expect("Failed to decode checkpoint block root")
-    .try_into()
-    .expect("Failed to convert checkpoint bytes to Bytes32");
+            if let Some(persistence) = persistence {
+                persistence
+                    .save(&store)
+                    .expect("Failed to save store snapshot");
+            }
 
-    let mut store = Box::new(
-        LightClientStore::initialize(trusted_block_root, &bootstrap)
-            .expect("Could not initialize the store based on bootstrap data"),
-    );
+            store
+        }
+    };
 
     info!("Fetching updates...");
 
     // Fetch updates
-    let sync_period = calc_sync_period(bootstrap.header().beacon().slot());
+    let sync_period = network_config.calc_sync_period(store.finalized_header().beacon().slot());
 
     let update_response = client
         .get_update_data(sync_period, MAX_REQUEST_LIGHT_CLIENT_UPDATES)
@@ -326,50 +403,137 @@ async fn initialize_light_client(
     );
 
     let mut verifier_state = VerifierState {
-        current_sync_committee: HashValue::default(),
-        next_sync_committee: HashValue::default(),
+        current_sync_committee: keccak256_hash(&store.current_sync_committee().to_ssz_bytes())?,
+        next_sync_committee: match store.next_sync_committee() {
+            Some(next_sync_committee) => keccak256_hash(&next_sync_committee.to_ssz_bytes())?,
+            None => HashValue::default(),
+        },
     };
 
-    let n = update_response.updates().len();
-    for (i, update) in update_response.updates.into_iter().enumerate() {
-        let update = update.update;
+    // Skip the updates that were already applied before the snapshot was taken.
+    let updates = update_response
+        .updates
+        .into_iter()
+        .map(|update| update.update)
+        .filter(|update| {
+            let applied = update.attested_header().beacon().slot()
+                <= store.finalized_header().beacon().slot()
+                && store.next_sync_committee().is_some();
+
+            if applied {
+                debug!(
+                    "Skipping already applied update at slot: {:?}",
+                    update.attested_header().beacon().slot()
+                );
+            }
+
+            !applied
+        })
+        .collect::<Vec<_>>();
+
+    if !updates.is_empty() {
         info!(
-            "Processing update at slot: {:?}",
-            update.attested_header().beacon().slot()
+            "Catching up with {} updates, from slot {:?} to slot {:?}",
+            updates.len(),
+            updates[0].attested_header().beacon().slot(),
+            updates[updates.len() - 1].attested_header().beacon().slot()
         );
 
-        if calc_sync_period(bootstrap.header().beacon().slot())
-            != calc_sync_period(update.attested_header().beacon().slot())
-        {
-            info!("Sync period changed, updating store...");
-        }
-
-        let proof =
-            Box::pin(client.prove_committee_change(proving_mode, store.clone(), update.clone()))
-                .await
-                .expect("Failed to prove committee change");
+        // Prove all the committee changes at once.
+        let proof = Box::pin(client.prove_committee_change_batch(
+            proving_mode,
+            store.clone(),
+            updates.clone(),
+        ))
+        .await
+        .expect("Failed to prove committee change");
 
         client
             .verify_committee_change(proof.clone())
             .await
             .expect("Failed to prove committee change");
 
-        if i == n - 1 {
-            let outputs: CommitteeChangeOut = CommitteeChangeOut::from(&mut proof.public_values());
+        let outputs: CommitteeChangeOut = CommitteeChangeOut::from(&mut proof.public_values());
 
-            verifier_state.current_sync_committee = outputs.new_sync_committee();
-            verifier_state.next_sync_committee = outputs.new_next_sync_committee();
+        if outputs.signer_sync_committee() != verifier_state.current_sync_committee {
+            return Err(anyhow!(
+                "Signer sync committee does not match the current sync committee"
+            ));
         }
 
+        verifier_state.current_sync_committee = outputs.new_sync_committee();
+        verifier_state.next_sync_committee = outputs.new_next_sync_committee();
+
         // TODO this is redundant, to simplify
-        store
-            .process_light_client_update(&update)
-            .expect("Failed to process update");
+        for update in &updates {
+            store
+                .process_light_client_update(update)
+                .expect("Failed to process update");
+        }
+
+        if let Some(persistence) = persistence {
+            persistence
+                .save(&store)
+                .expect("Failed to save store snapshot");
+        }
     }
 
     Ok((client, store, verifier_state))
 }
 
+/// This method initializes a new store from the bootstrap data at the latest checkpoint.
+///
+/// # Arguments
+///
+/// * `client` - The client.
+/// * `network_config` - The configuration of the network the light client follows.
+///
+/// # Returns
+///
+/// The initialized store.
+async fn bootstrap_store(client: &Client, network_config: &NetworkConfig) -> Box<LightClientStore> {
+    info!("Fetching latest state checkpoint and bootstrap data...");
+
+    // Fetch latest state checkpoint.
+    let checkpoint = client
+        .get_checkpoint(None)
+        .await
+        .expect("Failed to fetch checkpoint");
+
+    let checkpoint_block_root = match checkpoint.block_root() {
+        Some(block_root) => block_root,
+        None => panic!("No block root found in checkpoint"),
+    };
+
+    info!("Latest checkpoint: {:?}", checkpoint_block_root);
+
+    // Fetch bootstrap data.
+    let bootstrap = client
+        .get_bootstrap_data(checkpoint_block_root)
+        .await
+        .expect("Failed to fetch bootstrap data");
+
+    info!(
+        "Initializing Light Client store at checkpoint: {:?}",
+        checkpoint_block_root
+    );
+
+    // Initialize store
+    let trusted_block_root = hex::decode(
+        checkpoint_block_root
+            .strip_prefix("0x")
+            .expect("Checkpoint should start with \"0x\""),
+    )
+    .expect("Failed to decode checkpoint block root")
+    .try_into()
+    .expect("Failed to convert checkpoint bytes to Bytes32");
+
+    Box::new(
+        LightClientStore::initialize(trusted_block_root, &bootstrap, network_config)
+            .expect("Could not initialize the store based on bootstrap data"),
+    )
+}
+
 /// This method creates a listener for new tasks to verify proofs and processes them.
 ///
 /// # Arguments
@@ -378,13 +542,14 @@ async fn initialize_light_client(
 /// * `initial_verifier_state` - The initial verifier state.
 /// * `client` - The client.
 /// * `store` - The store.
-
+/// * `persistence` - The persistence layer in which the store is saved after each update, if any.
 async fn verifier_task(
     task_sender: Arc<mpsc::Sender<VerificationTask>>,
     mut task_receiver: mpsc::Receiver<VerificationTask>,
     initial_verifier_state: VerifierState,
     client: Arc<Client>,
     store: Arc<RwLock<Box<LightClientStore>>>,
+    persistence: Option<StorePersistence>,
 ) {
     let mut verifier_state = initial_verifier_state;
 
@@ -434,9 +599,15 @@ async fn verifier_task(
                                     verifier_state.next_sync_committee =
                                         outputs.new_next_sync_committee();
 
-                                    let mut lock = store.blocking_write();
+                                    let mut lock = store.write().await;
                                     lock.process_light_client_update(&update).unwrap();
 
+                                    if let Some(persistence) = &persistence {
+                                        if let Err(e) = persistence.save(&lock) {
+                                            error!("Failed to save store snapshot: {:?}", e);
+                                        }
+                                    }
+
                                     drop(permit);
                                 } else {
                                     error!("Signer sync committee does not match the current sync committee");
@@ -520,9 +691,38 @@ async fn check_update(
     store: Arc<RwLock<Box<LightClientStore>>>,
 ) -> Result<Option<Update>> {
     let store = store.read().await;
-    let known_period = calc_sync_period(store.finalized_header().beacon().slot());
+    let network_config = store.network_config();
+    let known_period = network_config.calc_sync_period(store.finalized_header().beacon().slot());
     let update = client
         .get_update_data(known_period, MAX_REQUEST_LIGHT_CLIENT_UPDATES)
         .await?;
-    update.extract_committee_change(known_period)
+    update.extract_committee_change(known_period, network_config)
+}
+
+/// This method returns the configuration of the network the light client follows.
+///
+/// # Arguments
+///
+/// * `network` - The network selected on the command line.
+/// * `network_config_path` - The path to the configuration file for a custom network.
+///
+/// # Returns
+///
+/// The configuration of the network.
+fn resolve_network_config(
+    network: Network,
+    network_config_path: Option<&Path>,
+) -> Result<NetworkConfig> {
+    match (network, network_config_path) {
+        (Network::Custom, Some(path)) => load_network_config(path),
+        (Network::Custom, None) => Err(anyhow!(
+            "A network configuration file is required for the custom network"
+        )),
+        (_, Some(_)) => Err(anyhow!(
+            "A network configuration file can only be used with the custom network"
+        )),
+        (Network::Mainnet, None) => Ok(MAINNET_NETWORK_CONFIG),
+        (Network::Sepolia, None) => Ok(SEPOLIA_NETWORK_CONFIG),
+        (Network::Holesky, None) => Ok(HOLESKY_NETWORK_CONFIG),
+    }
 }
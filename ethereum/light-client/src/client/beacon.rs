@@ -10,6 +10,7 @@
 
 use crate::client::error::ClientError;
 use crate::client::utils::test_connection;
+use crate::types::beacon::genesis::{Genesis, GenesisResponse};
 use crate::types::beacon::update::UpdateResponse;
 use ethereum_lc_core::types::bootstrap::Bootstrap;
 use ethereum_lc_core::types::update::FinalityUpdate;
@@ -117,7 +118,7 @@ impl BeaconClient {
 
     /// `get_update_data` makes an HTTP request to the Beacon Node API to get the update data. It fetches
     /// the update data starting at a given sync committee period and count. A sync committee period
-    /// can be calculated from a given slot with the [`ethereum_lc_core::types::utils::calc_sync_period`] function.
+    /// can be calculated from a given slot with the [`ethereum_lc_core::types::network::NetworkConfig::calc_sync_period`] method.
     ///
     /// # Arguments
     ///
@@ -230,4 +231,51 @@ impl BeaconClient {
 
         Ok(finality_update)
     }
+
+    /// `get_genesis` makes an HTTP request to the Beacon Node API to get the details about the
+    /// genesis of the chain it follows.
+    ///
+    /// # Returns
+    ///
+    /// The genesis details.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response is not successful or properly formatted.
+    pub(crate) async fn get_genesis(&self) -> Result<Genesis, ClientError> {
+        // Format the endpoint for the call
+        let url = format!("{}/eth/v1/beacon/genesis", self.beacon_node_address);
+
+        // Send the HTTP request
+        let response = self
+            .inner
+            .get(&url)
+            .header(ACCEPT, "application/json")
+            .send()
+            .await
+            .map_err(|err| ClientError::Request {
+                endpoint: url.clone(),
+                source: Box::new(err),
+            })?;
+
+        if !response.status().is_success() {
+            return Err(ClientError::Request {
+                endpoint: url,
+                source: format!(
+                    "Request not successful, got HTTP code {}",
+                    response.status().as_str()
+                )
+                .into(),
+            });
+        }
+
+        // Deserialize the response
+        let genesis_response: GenesisResponse =
+            response.json().await.map_err(|err| ClientError::Request {
+                endpoint: url,
+                source: Box::new(err),
+            })?;
+
+        Ok(genesis_response.data().clone())
+    }
 }
@@ -20,4 +20,22 @@ pub enum ClientError {
     },
     #[error("Could not connect to the given address, {address}")]
     Connection { address: String },
+    #[error("Failed to access store snapshot at {path}: {source}")]
+    Persistence {
+        path: String,
+        #[source]
+        source: Box<dyn std::error::Error + Sync + Send>,
+    },
+    #[error("Store snapshot finalized at epoch {finalized_epoch} is out of the weak subjectivity period of {period} epochs, current epoch is {current_epoch}")]
+    WeakSubjectivity {
+        finalized_epoch: u64,
+        current_epoch: u64,
+        period: u64,
+    },
+    #[error("Remote service does not follow the configured network: expected {parameter} {expected}, got {actual}")]
+    NetworkMismatch {
+        parameter: String,
+        expected: String,
+        actual: String,
+    },
 }
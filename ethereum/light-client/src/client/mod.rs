@@ -11,6 +11,11 @@
 //! - `beacon`: The Beacon Client is responsible for fetching the data necessary to prove sync committee changes
 //!   and value inclusion in the state of the Ethereum network.
 //! - `checkpoint`: The Checkpoint Client is responsible for fetching the data of the latest finalized block root.
+//! - `persistence`: The persistence layer saving snapshots of the Light Client store, so that it can resume
+//!   after a restart.
+//!
+//! The client is bound to a [`NetworkConfig`], describing the network that all the remote services
+//! are expected to follow.
 
 use crate::client::beacon::BeaconClient;
 use crate::client::checkpoint::CheckpointClient;
@@ -22,20 +27,25 @@ use crate::types::beacon::update::UpdateResponse;
 use crate::types::checkpoint::Checkpoint;
 use ethereum_lc_core::merkle::storage_proofs::EIP1186Proof;
 use ethereum_lc_core::types::bootstrap::Bootstrap;
+use ethereum_lc_core::types::network::NetworkConfig;
 use ethereum_lc_core::types::store::LightClientStore;
 use ethereum_lc_core::types::update::{FinalityUpdate, Update};
 use ethers_core::types::EIP1186ProofResponse;
+use getset::Getters;
 
 pub(crate) mod beacon;
 pub(crate) mod checkpoint;
 pub mod error;
+pub mod persistence;
 pub(crate) mod proof_server;
 pub mod storage;
 mod utils;
 
 /// The client for the light client. It is the entrypoint for any needed remote call.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Getters)]
 pub struct Client {
+    #[getset(get = "pub")]
+    network_config: NetworkConfig,
     beacon_client: BeaconClient,
     checkpoint_client: CheckpointClient,
     proof_server_client: ProofServerClient,
@@ -51,6 +61,7 @@ impl Client {
     /// * `beacon_node_address` - The address of the Beacon Node API.
     /// * `proof_server_address` - The address of the Proof Server API.
     /// * `storage_provider_address` - The address of the RPC Provider API.
+    /// * `network_config` - The configuration of the network the remote services follow.
     ///
     /// # Returns
     ///
@@ -60,8 +71,10 @@ impl Client {
         beacon_node_address: &str,
         proof_server_address: &str,
         storage_provider_address: &str,
+        network_config: &NetworkConfig,
     ) -> Self {
         Self {
+            network_config: *network_config,
             beacon_client: BeaconClient::new(beacon_node_address),
             checkpoint_client: CheckpointClient::new(checkpoint_provider_address),
             proof_server_client: ProofServerClient::new(proof_server_address),
@@ -85,6 +98,56 @@ impl Client {
         Ok(())
     }
 
+    /// Checks that the Beacon Node and the RPC Provider follow the network the client is configured for.
+    ///
+    /// # Returns
+    ///
+    /// A result indicating whether the remote services follow the configured network.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the requests fail or if the genesis of the Beacon chain or the chain id of
+    /// the execution layer do not match the configuration.
+    pub async fn check_network(&self) -> Result<(), ClientError> {
+        let (genesis, chain_id) = tokio::try_join!(
+            self.beacon_client.get_genesis(),
+            self.storage_client.get_chain_id()
+        )?;
+
+        let genesis_validators_root = format!(
+            "0x{}",
+            hex::encode(self.network_config.genesis_validators_root())
+        );
+        if !genesis
+            .genesis_validators_root()
+            .eq_ignore_ascii_case(&genesis_validators_root)
+        {
+            return Err(ClientError::NetworkMismatch {
+                parameter: "genesis validators root".into(),
+                expected: genesis_validators_root,
+                actual: genesis.genesis_validators_root().clone(),
+            });
+        }
+
+        if genesis.genesis_time() != &self.network_config.genesis_time().to_string() {
+            return Err(ClientError::NetworkMismatch {
+                parameter: "genesis time".into(),
+                expected: self.network_config.genesis_time().to_string(),
+                actual: genesis.genesis_time().clone(),
+            });
+        }
+
+        if chain_id != self.network_config.chain_id() {
+            return Err(ClientError::NetworkMismatch {
+                parameter: "chain id".into(),
+                expected: self.network_config.chain_id().to_string(),
+                actual: chain_id.to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
     /// `get_bootstrap_data` makes an HTTP request to the Beacon Node API to get the bootstrap data.
     ///
     /// # Arguments
@@ -178,7 +241,36 @@ impl Client {
     ) -> Result<ProofType, ClientError> {
         Box::pin(
             self.proof_server_client
-                .prove_committee_change(proving_mode, store, update),
+                .prove_committee_change(proving_mode, store, vec![update]),
+        )
+        .await
+    }
+
+    /// `prove_committee_change_batch` makes a request to the Proof Server API to generate a single
+    /// proof of the committee changes resulting from applying a sequence of updates.
+    ///
+    /// # Arguments
+    ///
+    /// * `proving_mode` - The proving mode, either STARK or SNARK.
+    /// * `store` - The light client store.
+    /// * `updates` - The update data, in order.
+    ///
+    /// # Returns
+    ///
+    /// The proof of the committee changes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response is not successful or properly formatted.
+    pub async fn prove_committee_change_batch(
+        &self,
+        proving_mode: ProvingMode,
+        store: Box<LightClientStore>,
+        updates: Vec<Update>,
+    ) -> Result<ProofType, ClientError> {
+        Box::pin(
+            self.proof_server_client
+                .prove_committee_change(proving_mode, store, updates),
         )
         .await
     }
@@ -255,7 +347,40 @@ impl Client {
             proving_mode,
             store,
             update,
-            eip1186_proof,
+            vec![eip1186_proof],
+        ))
+        .await
+    }
+
+    /// `prove_storage_inclusion_batch` makes a request to the Proof Server API to generate a single
+    /// proof of the storage inclusion of several accounts at the same finalized block.
+    ///
+    /// # Arguments
+    ///
+    /// * `proving_mode` - The proving mode, either STARK or SNARK.
+    /// * `store` - The light client store.
+    /// * `update` - The update data.
+    /// * `eip1186_proofs` - The EIP1186 proofs, one per account.
+    ///
+    /// # Returns
+    ///
+    /// The proof of the storage inclusion.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response is not successful or properly formatted.
+    pub async fn prove_storage_inclusion_batch(
+        &self,
+        proving_mode: ProvingMode,
+        store: Box<LightClientStore>,
+        update: Update,
+        eip1186_proofs: Vec<EIP1186Proof>,
+    ) -> Result<ProofType, ClientError> {
+        Box::pin(self.proof_server_client.prove_storage_inclusion(
+            proving_mode,
+            store,
+            update,
+            eip1186_proofs,
         ))
         .await
     }
@@ -0,0 +1,242 @@
+// Copyright (c) Argument Computer Corporation
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Persistence module
+//!
+//! This module contains the persistence layer for the [`LightClientStore`]. It allows the Light Client
+//! to resume from its last known state after a restart, instead of bootstrapping again from the latest
+//! checkpoint.
+//!
+//! The store is saved as an SSZ encoded snapshot in a given directory. Snapshots are written
+//! atomically: the store is first written to a temporary file which is then renamed over the
+//! previous snapshot, so that a crash while saving never leaves a partially written snapshot behind.
+//!
+//! A snapshot is only loaded if its finalized header is within the weak subjectivity period, as
+//! resuming from an older state would not be safe. In that case the Light Client is expected to
+//! bootstrap again from a recent checkpoint.
+
+use crate::client::error::ClientError;
+use ethereum_lc_core::types::network::NetworkConfig;
+use ethereum_lc_core::types::store::LightClientStore;
+use getset::{CopyGetters, Getters};
+use std::fs;
+use std::fs::File;
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+
+/// Name of the file holding the store snapshot.
+pub const STORE_SNAPSHOT_FILE: &str = "store.ssz";
+
+/// Name of the temporary file used while writing a store snapshot.
+const STORE_SNAPSHOT_TMP_FILE: &str = "store.ssz.tmp";
+
+/// Default weak subjectivity period, in epochs. Set to two weeks of epochs, which is a conservative
+/// bound for networks with a large validator set.
+///
+/// See [the weak subjectivity guide](https://github.com/ethereum/consensus-specs/blob/v1.4.0/specs/phase0/weak-subjectivity.md#compute_weak_subjectivity_period).
+pub const DEFAULT_WEAK_SUBJECTIVITY_PERIOD: u64 = 3150;
+
+/// Persistence layer for the [`LightClientStore`], saving snapshots of the store in a directory.
+#[derive(Debug, Clone, Getters, CopyGetters)]
+pub struct StorePersistence {
+    /// The directory holding the snapshots.
+    #[getset(get = "pub")]
+    directory: PathBuf,
+    /// The weak subjectivity period, in epochs.
+    #[getset(get_copy = "pub")]
+    weak_subjectivity_period: u64,
+}
+
+impl StorePersistence {
+    /// Create a new persistence layer saving snapshots in the given directory.
+    ///
+    /// # Arguments
+    ///
+    /// * `directory` - The directory holding the snapshots. Created on the first save if needed.
+    /// * `weak_subjectivity_period` - The weak subjectivity period, in epochs.
+    ///
+    /// # Returns
+    ///
+    /// A new `StorePersistence`.
+    pub fn new(directory: impl Into<PathBuf>, weak_subjectivity_period: u64) -> Self {
+        Self {
+            directory: directory.into(),
+            weak_subjectivity_period,
+        }
+    }
+
+    /// Returns the path to the store snapshot.
+    ///
+    /// # Returns
+    ///
+    /// The path to the store snapshot.
+    pub fn snapshot_path(&self) -> PathBuf {
+        self.directory.join(STORE_SNAPSHOT_FILE)
+    }
+
+    /// Atomically saves a snapshot of the given store, replacing the previous one.
+    ///
+    /// # Arguments
+    ///
+    /// * `store` - The store to save.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the store could not be serialized or written to disk.
+    pub fn save(&self, store: &LightClientStore) -> Result<(), ClientError> {
+        let path = self.snapshot_path();
+        let tmp_path = self.directory.join(STORE_SNAPSHOT_TMP_FILE);
+
+        let bytes = store
+            .to_ssz_bytes()
+            .map_err(|err| persistence_error(&path, err))?;
+
+        fs::create_dir_all(&self.directory)
+            .map_err(|err| persistence_error(&self.directory, err))?;
+
+        // Write the snapshot to a temporary file and make sure it reached the disk
+        let mut file = File::create(&tmp_path).map_err(|err| persistence_error(&tmp_path, err))?;
+        file.write_all(&bytes)
+            .and_then(|_| file.sync_all())
+            .map_err(|err| persistence_error(&tmp_path, err))?;
+
+        // Replace the previous snapshot
+        fs::rename(&tmp_path, &path).map_err(|err| persistence_error(&path, err))?;
+
+        // Persist the rename itself
+        #[cfg(unix)]
+        File::open(&self.directory)
+            .and_then(|directory| directory.sync_all())
+            .map_err(|err| persistence_error(&self.directory, err))?;
+
+        Ok(())
+    }
+
+    /// Loads the latest snapshot of the store, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `network_config` - The configuration of the network the Light Client follows.
+    /// * `current_slot` - The current slot of the network.
+    ///
+    /// # Returns
+    ///
+    /// The store from the snapshot, or `None` if no snapshot was saved yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the snapshot could not be read or deserialized, if it was taken on a
+    /// different network or if its finalized header is older than the weak subjectivity period.
+    pub fn load(
+        &self,
+        network_config: &NetworkConfig,
+        current_slot: u64,
+    ) -> Result<Option<Box<LightClientStore>>, ClientError> {
+        let path = self.snapshot_path();
+
+        let bytes = match fs::read(&path) {
+            Ok(bytes) => bytes,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(persistence_error(&path, err)),
+        };
+
+        let store = Box::new(
+            LightClientStore::from_ssz_bytes(&bytes)
+                .map_err(|err| persistence_error(&path, err))?,
+        );
+
+        if store.network_config() != network_config {
+            return Err(persistence_error(
+                &path,
+                "Snapshot was taken on a different network",
+            ));
+        }
+
+        let finalized_epoch = network_config.calc_epoch(store.finalized_header().beacon().slot());
+        let current_epoch = network_config.calc_epoch(&current_slot);
+
+        if current_epoch.saturating_sub(finalized_epoch) > self.weak_subjectivity_period {
+            return Err(ClientError::WeakSubjectivity {
+                finalized_epoch,
+                current_epoch,
+                period: self.weak_subjectivity_period,
+            });
+        }
+
+        Ok(Some(store))
+    }
+}
+
+/// Utility to create a `ClientError::Persistence` for the given path.
+///
+/// # Arguments
+///
+/// * `path` - The path that could not be accessed.
+/// * `source` - The source of the error.
+///
+/// # Returns
+///
+/// The `ClientError`.
+fn persistence_error(
+    path: &Path,
+    source: impl Into<Box<dyn std::error::Error + Sync + Send>>,
+) -> ClientError {
+    ClientError::Persistence {
+        path: path.display().to_string(),
+        source: source.into(),
+    }
+}
+
+#[cfg(all(test, feature = "ethereum"))]
+mod test {
+    use crate::client::error::ClientError;
+    use crate::client::persistence::{StorePersistence, DEFAULT_WEAK_SUBJECTIVITY_PERIOD};
+    use crate::test_utils::generate_committee_change_test_assets;
+    use ethereum_lc_core::types::network::{MAINNET_NETWORK_CONFIG, SEPOLIA_NETWORK_CONFIG};
+    use std::fs;
+
+    #[test]
+    fn test_store_persistence() {
+        let directory = std::env::temp_dir().join(format!(
+            "ethereum-lc-store-persistence-{}",
+            std::process::id()
+        ));
+        let persistence = StorePersistence::new(&directory, DEFAULT_WEAK_SUBJECTIVITY_PERIOD);
+
+        let store = generate_committee_change_test_assets().store;
+        let finalized_slot = *store.finalized_header().beacon().slot();
+
+        // Nothing to resume from before the first save
+        assert!(persistence
+            .load(&MAINNET_NETWORK_CONFIG, finalized_slot)
+            .unwrap()
+            .is_none());
+
+        persistence.save(&store).unwrap();
+        persistence.save(&store).unwrap();
+
+        let snapshot = persistence
+            .load(&MAINNET_NETWORK_CONFIG, finalized_slot)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(*snapshot, store);
+
+        // Snapshots are refused once out of the weak subjectivity period
+        let stale_slot = finalized_slot
+            + (DEFAULT_WEAK_SUBJECTIVITY_PERIOD + 1) * MAINNET_NETWORK_CONFIG.slots_per_epoch();
+
+        assert!(matches!(
+            persistence.load(&MAINNET_NETWORK_CONFIG, stale_slot),
+            Err(ClientError::WeakSubjectivity { .. })
+        ));
+
+        // Snapshots are refused for other networks
+        assert!(matches!(
+            persistence.load(&SEPOLIA_NETWORK_CONFIG, finalized_slot),
+            Err(ClientError::Persistence { .. })
+        ));
+
+        fs::remove_dir_all(&directory).unwrap();
+    }
+}
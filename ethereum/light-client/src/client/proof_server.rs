@@ -55,13 +55,13 @@ impl ProofServerClient {
     }
 
     /// Prove a sync committee change by executing the [`LightClientStore::process_light_client_update`]
-    /// and proving its correct execution.
+    /// for each of the given updates and proving its correct execution.
     ///
     /// # Arguments
     ///
     /// * `proving_mode` - The proving mode to use, either STARK or SNARK.
     /// * `store` - The light client store.
-    /// * `update` - The update to process.
+    /// * `updates` - The updates to process, in order.
     ///
     /// # Returns
     ///
@@ -70,11 +70,11 @@ impl ProofServerClient {
         &self,
         proving_mode: ProvingMode,
         store: Box<LightClientStore>,
-        update: Update,
+        updates: Vec<Update>,
     ) -> Result<ProofType, ClientError> {
         let url = format!("http://{}/committee/proof", self.address);
 
-        let inputs = CommitteeChangeIn::new(*store, update);
+        let inputs = CommitteeChangeIn::new_batch(*store, updates);
         let request = Request::ProveCommitteeChange(Box::new((proving_mode, inputs)));
 
         let response = self
@@ -123,15 +123,15 @@ impl ProofServerClient {
         Ok(response.first().unwrap_or(&0) == &1)
     }
 
-    /// Prove the inclusion of a given value in the chain storage by executing [`EIP1186Proof::verify`]
-    /// and proving its correct execution.
+    /// Prove the inclusion of given values in the chain storage by executing [`EIP1186Proof::verify`]
+    /// for each of the given proofs and proving its correct execution.
     ///
     /// # Arguments
     ///
     /// * `proving_mode` - The proving mode to use, either STARK or SNARK.
     /// * `store` - The light client store.
     /// * `update` - The update to process.
-    /// * `eip1186_proof` - The EIP1186 proof to verify.
+    /// * `eip1186_proofs` - The EIP1186 proofs to verify, one per account.
     ///
     /// # Returns
     ///
@@ -141,11 +141,11 @@ impl ProofServerClient {
         proving_mode: ProvingMode,
         store: Box<LightClientStore>,
         update: Update,
-        eip1186_proof: EIP1186Proof,
+        eip1186_proofs: Vec<EIP1186Proof>,
     ) -> Result<ProofType, ClientError> {
         let url = format!("http://{}/inclusion/proof", self.address);
 
-        let inputs = StorageInclusionIn::new(*store, update, eip1186_proof);
+        let inputs = StorageInclusionIn::new_batch(*store, update, eip1186_proofs);
         let request = Request::ProveInclusion(Box::new((proving_mode, inputs)));
 
         let response = self
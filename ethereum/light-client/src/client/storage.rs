@@ -16,7 +16,7 @@
 
 use crate::client::error::ClientError;
 use crate::client::utils::test_connection;
-use crate::types::storage::GetProofResponse;
+use crate::types::storage::{ChainIdResponse, GetProofResponse};
 use ethers_core::types::EIP1186ProofResponse;
 use getset::Getters;
 use reqwest::header::CONTENT_TYPE;
@@ -126,4 +126,65 @@ impl StorageClient {
 
         Ok(deserialized.result().clone())
     }
+
+    /// `get_chain_id` makes an HTTP request to the RPC Provider API to get the id of the chain it
+    /// is connected to.
+    ///
+    /// # Returns
+    ///
+    /// The chain id.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response is not successful or properly formatted.
+    pub(crate) async fn get_chain_id(&self) -> Result<u64, ClientError> {
+        // Generate body
+        let body = format!(
+            "{}",
+            serde_json::json!({
+                "jsonrpc": "2.0",
+                "method": "eth_chainId",
+                "id": 1,
+                "params": []
+            })
+        );
+
+        // Send the HTTP request
+        let response = self
+            .inner
+            .post(&self.storage_provider_address)
+            .header(CONTENT_TYPE, "application/json")
+            .body(body)
+            .send()
+            .await
+            .map_err(|err| ClientError::Request {
+                endpoint: "eth_chainId".into(),
+                source: Box::new(err),
+            })?;
+
+        if !response.status().is_success() {
+            return Err(ClientError::Request {
+                endpoint: "eth_chainId".into(),
+                source: format!(
+                    "Request not successful, got HTTP code {}",
+                    response.status().as_str()
+                )
+                .into(),
+            });
+        }
+
+        // Deserialize the response
+        let deserialized: ChainIdResponse =
+            response.json().await.map_err(|err| ClientError::Request {
+                endpoint: "eth_chainId".into(),
+                source: err.into(),
+            })?;
+
+        u64::from_str_radix(deserialized.result().trim_start_matches("0x"), 16).map_err(|err| {
+            ClientError::Response {
+                endpoint: "eth_chainId".into(),
+                source: err.into(),
+            }
+        })
+    }
 }
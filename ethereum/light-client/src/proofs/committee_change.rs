@@ -14,7 +14,9 @@ use ethereum_lc_core::deserialization_error;
 use ethereum_lc_core::types::error::TypesError;
 use ethereum_lc_core::types::store::LightClientStore;
 use ethereum_lc_core::types::update::Update;
-use ethereum_lc_core::types::utils::{extract_u32, OFFSET_BYTE_LENGTH};
+use ethereum_lc_core::types::utils::{
+    extract_u32, ssz_decode_list_bytes, ssz_encode_list_bytes, OFFSET_BYTE_LENGTH,
+};
 use ethereum_programs::COMMITTEE_CHANGE_PROGRAM;
 use getset::CopyGetters;
 use sphinx_sdk::{
@@ -54,17 +56,44 @@ impl CommitteeChangeProver {
     pub const fn get_vk(&self) -> &SphinxVerifyingKey {
         &self.keys.1
     }
+
+    /// Generate a compressed STARK proof for the program, that can be verified by the sync
+    /// committee change aggregation program.
+    ///
+    /// # Arguments
+    ///
+    /// * `inputs` - The input for the prover.
+    ///
+    /// # Returns
+    ///
+    /// The compressed STARK proof.
+    pub fn prove_compressed(&self, inputs: &CommitteeChangeIn) -> Result<ProofType, ProverError> {
+        let stdin = self.generate_sphinx_stdin(inputs)?;
+
+        self.client
+            .prove(&self.keys.0, stdin)
+            .compressed()
+            .run()
+            .map_err(|err| ProverError::Proving {
+                proof_type: ProvingMode::STARK.into(),
+                source: err.into(),
+            })
+            .map(ProofType::STARK)
+    }
 }
 
 /// The input for the sync committee change proof.
+///
+/// The program applies the updates to the store in order, so that a single proof can cover
+/// several sync committee periods.
 #[derive(Debug, Eq, PartialEq)]
 pub struct CommitteeChangeIn {
     store: LightClientStore,
-    update: Update,
+    updates: Vec<Update>,
 }
 
 impl CommitteeChangeIn {
-    /// Create a new `CommitteeChangeIn`.
+    /// Create a new `CommitteeChangeIn` for a single update.
     ///
     /// # Arguments
     ///
@@ -74,8 +103,39 @@ impl CommitteeChangeIn {
     /// # Returns
     ///
     /// A new `CommitteeChangeIn`.
-    pub const fn new(store: LightClientStore, update: Update) -> Self {
-        Self { store, update }
+    pub fn new(store: LightClientStore, update: Update) -> Self {
+        Self {
+            store,
+            updates: vec![update],
+        }
+    }
+
+    /// Create a new `CommitteeChangeIn` for a sequence of updates.
+    ///
+    /// # Arguments
+    ///
+    /// * `store` - The `LightClientStore` that wil be passed to the program.
+    /// * `updates` - The `Update`s that will be applied in order by the program.
+    ///
+    /// # Returns
+    ///
+    /// A new `CommitteeChangeIn`.
+    ///
+    /// # Notes
+    ///
+    /// The sequence of updates should not be empty, otherwise the input will be rejected by the
+    /// prover.
+    pub const fn new_batch(store: LightClientStore, updates: Vec<Update>) -> Self {
+        Self { store, updates }
+    }
+
+    /// Serialize the updates of the `CommitteeChangeIn` as a list of SSZ encoded updates.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<Vec<u8>>` containing the SSZ serialized updates.
+    fn updates_ssz_bytes(&self) -> Result<Vec<Vec<u8>>, TypesError> {
+        self.updates.iter().map(Update::to_ssz_bytes).collect()
     }
 
     /// Serialize the `CommitteeChangeIn` struct to SSZ bytes.
@@ -90,12 +150,12 @@ impl CommitteeChangeIn {
         let store_bytes = self.store.to_ssz_bytes()?;
         bytes.extend_from_slice(&store_offset.to_le_bytes());
 
-        let update_offset = store_offset + store_bytes.len() as u32;
-        let update_bytes = self.update.to_ssz_bytes()?;
-        bytes.extend_from_slice(&update_offset.to_le_bytes());
+        let updates_offset = store_offset + store_bytes.len() as u32;
+        let updates_bytes = ssz_encode_list_bytes(&self.updates_ssz_bytes()?);
+        bytes.extend_from_slice(&updates_offset.to_le_bytes());
 
         bytes.extend_from_slice(&store_bytes);
-        bytes.extend_from_slice(&update_bytes);
+        bytes.extend_from_slice(&updates_bytes);
 
         Ok(bytes)
     }
@@ -112,7 +172,7 @@ impl CommitteeChangeIn {
     pub fn from_ssz_bytes(bytes: &[u8]) -> Result<Self, TypesError> {
         let cursor = 0;
         let (cursor, store_offset) = extract_u32("CommmitteeChangeIn", bytes, cursor)?;
-        let (cursor, update_offset) = extract_u32("CommmitteeChangeIn", bytes, cursor)?;
+        let (cursor, updates_offset) = extract_u32("CommmitteeChangeIn", bytes, cursor)?;
 
         // Deserialize the Light Client store
         if cursor != store_offset as usize {
@@ -121,12 +181,22 @@ impl CommitteeChangeIn {
                 "Invalid offset for store"
             ));
         }
-        let store = LightClientStore::from_ssz_bytes(&bytes[cursor..update_offset as usize])?;
+        let store = LightClientStore::from_ssz_bytes(&bytes[cursor..updates_offset as usize])?;
 
-        // Deserialize the Update
-        let update = Update::from_ssz_bytes(&bytes[update_offset as usize..])?;
+        // Deserialize the Updates
+        let updates = ssz_decode_list_bytes(&bytes[updates_offset as usize..])?
+            .iter()
+            .map(|update_bytes| Update::from_ssz_bytes(update_bytes))
+            .collect::<Result<Vec<_>, _>>()?;
 
-        Ok(Self { store, update })
+        if updates.is_empty() {
+            return Err(deserialization_error!(
+                "CommmitteeChangeIn",
+                "At least one update is expected"
+            ));
+        }
+
+        Ok(Self { store, updates })
     }
 }
 
@@ -163,6 +233,12 @@ impl Prover for CommitteeChangeProver {
     type StdOut = CommitteeChangeOut;
 
     fn generate_sphinx_stdin(&self, inputs: &Self::StdIn) -> Result<SphinxStdin, Self::Error> {
+        if inputs.updates.is_empty() {
+            return Err(ProverError::SphinxInput {
+                source: "At least one update is expected".into(),
+            });
+        }
+
         let mut stdin = SphinxStdin::new();
         stdin.write(
             &inputs
@@ -172,8 +248,7 @@ impl Prover for CommitteeChangeProver {
         );
         stdin.write(
             &inputs
-                .update
-                .to_ssz_bytes()
+                .updates_ssz_bytes()
                 .map_err(|err| ProverError::SphinxInput { source: err.into() })?,
         );
         Ok(stdin)
@@ -252,10 +327,10 @@ mod test {
 
         let prover = CommitteeChangeProver::new();
 
-        let new_period_inputs = CommitteeChangeIn {
-            store: test_assets.store.clone(),
-            update: test_assets.update_new_period.clone(),
-        };
+        let new_period_inputs = CommitteeChangeIn::new(
+            test_assets.store.clone(),
+            test_assets.update_new_period.clone(),
+        );
 
         let new_period_output = prover.execute(&new_period_inputs).unwrap();
 
@@ -295,6 +370,61 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_execute_committee_change_batch() {
+        let test_assets = generate_committee_change_test_assets();
+
+        let prover = CommitteeChangeProver::new();
+
+        let batch_inputs = CommitteeChangeIn::new_batch(
+            test_assets.store.clone(),
+            vec![
+                test_assets.update.clone(),
+                test_assets.update_new_period.clone(),
+            ],
+        );
+
+        assert_eq!(
+            CommitteeChangeIn::from_ssz_bytes(&batch_inputs.to_ssz_bytes().unwrap()).unwrap(),
+            batch_inputs
+        );
+
+        let batch_output = prover.execute(&batch_inputs).unwrap();
+
+        let mut store = test_assets.store.clone();
+        store
+            .process_light_client_update(&test_assets.update)
+            .unwrap();
+        store
+            .process_light_client_update(&test_assets.update_new_period)
+            .unwrap();
+
+        assert_eq!(
+            &batch_output.finalized_block_height,
+            test_assets
+                .update_new_period
+                .finalized_header()
+                .beacon()
+                .slot()
+        );
+        assert_eq!(
+            batch_output.signer_sync_committee,
+            keccak256_hash(&test_assets.store.current_sync_committee().to_ssz_bytes()).unwrap()
+        );
+        assert_eq!(
+            batch_output.new_sync_committee,
+            keccak256_hash(&store.current_sync_committee().to_ssz_bytes()).unwrap()
+        );
+        assert_eq!(
+            batch_output.new_next_sync_committee,
+            keccak256_hash(&store.next_sync_committee().clone().unwrap().to_ssz_bytes()).unwrap()
+        );
+
+        let empty_inputs = CommitteeChangeIn::new_batch(test_assets.store, vec![]);
+
+        assert!(prover.execute(&empty_inputs).is_err());
+    }
+
     #[test]
     #[ignore = "This test is too slow for CI"]
     fn test_prove_stark_committee_change() {
@@ -309,10 +439,10 @@ mod test {
 
         let prover = CommitteeChangeProver::new();
 
-        let new_period_inputs = CommitteeChangeIn {
-            store: test_assets.store.clone(),
-            update: test_assets.update_new_period.clone(),
-        };
+        let new_period_inputs = CommitteeChangeIn::new(
+            test_assets.store.clone(),
+            test_assets.update_new_period.clone(),
+        );
 
         println!("Starting STARK proving for sync committee change...");
         let start = Instant::now();
@@ -337,10 +467,10 @@ mod test {
 
         let prover = CommitteeChangeProver::new();
 
-        let new_period_inputs = CommitteeChangeIn {
-            store: test_assets.store.clone(),
-            update: test_assets.update_new_period.clone(),
-        };
+        let new_period_inputs = CommitteeChangeIn::new(
+            test_assets.store.clone(),
+            test_assets.update_new_period.clone(),
+        );
 
         println!("Starting SNARK proving for sync committee change...");
         let start = Instant::now();
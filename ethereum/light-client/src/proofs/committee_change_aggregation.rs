@@ -0,0 +1,312 @@
+// Copyright (c) Argument Computer Corporation
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Sync Committee Change Aggregation Prover module
+//!
+//! This module provides the prover implementation for the aggregation of sync committee change
+//! proofs. The aggregation program verifies a previous proof, either a sync committee change proof
+//! or a previous aggregation proof, and applies a new `Update` on top of it. Its outputs carry the
+//! signer committee hash of the first proof of the chain, so that a verifier only has to keep track
+//! of this trusted anchor to accept the latest sync committee from a single proof.
+//!
+//! Previous proofs have to be compressed STARK proofs, as generated by
+//! [`CommitteeChangeProver::prove_compressed`](crate::proofs::committee_change::CommitteeChangeProver::prove_compressed)
+//! or by this prover in STARK mode.
+
+use crate::proofs::committee_change::CommitteeChangeOut;
+use crate::proofs::error::ProverError;
+use crate::proofs::{ProofType, Prover, ProvingMode};
+use anyhow::Result;
+use ethereum_lc_core::types::store::LightClientStore;
+use ethereum_lc_core::types::update::Update;
+use ethereum_programs::{COMMITTEE_CHANGE_AGGREGATION_PROGRAM, COMMITTEE_CHANGE_PROGRAM};
+use getset::CopyGetters;
+use sphinx_sdk::{
+    HashableKey, ProverClient, SphinxProof, SphinxProvingKey, SphinxPublicValues, SphinxStdin,
+    SphinxVerifyingKey,
+};
+
+/// The prover for the aggregation of sync committee change proofs.
+pub struct CommitteeChangeAggregationProver {
+    client: ProverClient,
+    keys: (SphinxProvingKey, SphinxVerifyingKey),
+    committee_change_vk: SphinxVerifyingKey,
+}
+
+impl Default for CommitteeChangeAggregationProver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CommitteeChangeAggregationProver {
+    /// Create a new `CommitteeChangeAggregationProver`.
+    ///
+    /// # Returns
+    ///
+    /// A new `CommitteeChangeAggregationProver`.
+    pub fn new() -> Self {
+        let client = ProverClient::new();
+        let keys = client.setup(COMMITTEE_CHANGE_AGGREGATION_PROGRAM);
+        let (_, committee_change_vk) = client.setup(COMMITTEE_CHANGE_PROGRAM);
+
+        Self {
+            client,
+            keys,
+            committee_change_vk,
+        }
+    }
+
+    /// Gets a `SphinxVerifyingKey`.
+    ///
+    /// # Returns
+    ///
+    /// A `SphinxVerifyingKey` that can be used for verifying the aggregation proof.
+    pub const fn get_vk(&self) -> &SphinxVerifyingKey {
+        &self.keys.1
+    }
+}
+
+/// The previous proof of the chain to aggregate.
+#[derive(Debug, Clone)]
+pub enum PreviousProof {
+    /// A compressed STARK proof generated by the sync committee change program.
+    CommitteeChange(ProofType),
+    /// A compressed STARK proof generated by the aggregation program.
+    Aggregation(ProofType),
+}
+
+/// The input for the aggregation of sync committee change proofs.
+#[derive(Debug, Clone)]
+pub struct CommitteeChangeAggregationIn {
+    previous_proof: PreviousProof,
+    store: LightClientStore,
+    update: Update,
+}
+
+impl CommitteeChangeAggregationIn {
+    /// Create a new `CommitteeChangeAggregationIn`.
+    ///
+    /// # Arguments
+    ///
+    /// * `previous_proof` - The previous proof of the chain.
+    /// * `store` - The `LightClientStore` resulting from the previous proof, that will be passed to
+    ///   the program.
+    /// * `update` - The `Update` that will be passed to the program.
+    ///
+    /// # Returns
+    ///
+    /// A new `CommitteeChangeAggregationIn`.
+    pub const fn new(
+        previous_proof: PreviousProof,
+        store: LightClientStore,
+        update: Update,
+    ) -> Self {
+        Self {
+            previous_proof,
+            store,
+            update,
+        }
+    }
+}
+
+/// The output for the aggregation of sync committee change proofs.
+///
+/// The outputs of the sync committee change program are extended with the digests of the verifying
+/// keys of the programs in the chain. In the aggregated outputs, the signer sync committee is the one
+/// that signed the first update of the chain, i.e. the trusted anchor.
+#[derive(Debug, Clone, Copy, CopyGetters)]
+#[getset(get_copy = "pub")]
+pub struct CommitteeChangeAggregationOut {
+    committee_change: CommitteeChangeOut,
+    committee_change_vkey: [u32; 8],
+    aggregation_vkey: [u32; 8],
+}
+
+impl From<&mut SphinxPublicValues> for CommitteeChangeAggregationOut {
+    fn from(public_values: &mut SphinxPublicValues) -> Self {
+        let committee_change = CommitteeChangeOut::from(&mut *public_values);
+        let committee_change_vkey = public_values.read::<[u32; 8]>();
+        let aggregation_vkey = public_values.read::<[u32; 8]>();
+
+        Self {
+            committee_change,
+            committee_change_vkey,
+            aggregation_vkey,
+        }
+    }
+}
+
+impl Prover for CommitteeChangeAggregationProver {
+    const PROGRAM: &'static [u8] = COMMITTEE_CHANGE_AGGREGATION_PROGRAM;
+    type Error = ProverError;
+    type StdIn = CommitteeChangeAggregationIn;
+    type StdOut = CommitteeChangeAggregationOut;
+
+    fn generate_sphinx_stdin(&self, inputs: &Self::StdIn) -> Result<SphinxStdin, Self::Error> {
+        let (previous_is_aggregation, previous_proof, previous_vk) = match &inputs.previous_proof {
+            PreviousProof::CommitteeChange(proof) => (false, proof, &self.committee_change_vk),
+            PreviousProof::Aggregation(proof) => (true, proof, &self.keys.1),
+        };
+
+        let ProofType::STARK(previous_proof) = previous_proof else {
+            return Err(ProverError::SphinxInput {
+                source: "Previous proof should be a STARK proof".into(),
+            });
+        };
+        let SphinxProof::Compressed(compressed_proof) = &previous_proof.proof else {
+            return Err(ProverError::SphinxInput {
+                source: "Previous proof should be a compressed STARK proof".into(),
+            });
+        };
+
+        let mut stdin = SphinxStdin::new();
+        stdin.write(&self.committee_change_vk.hash_u32());
+        stdin.write(&self.keys.1.hash_u32());
+        stdin.write(&previous_is_aggregation);
+        stdin.write(&previous_proof.public_values.to_vec());
+        stdin.write(
+            &inputs
+                .store
+                .to_ssz_bytes()
+                .map_err(|err| ProverError::SphinxInput { source: err.into() })?,
+        );
+        stdin.write(
+            &inputs
+                .update
+                .to_ssz_bytes()
+                .map_err(|err| ProverError::SphinxInput { source: err.into() })?,
+        );
+        stdin.write_proof(compressed_proof.clone(), previous_vk.vk.clone());
+
+        Ok(stdin)
+    }
+
+    fn execute(&self, inputs: &Self::StdIn) -> Result<Self::StdOut, Self::Error> {
+        sphinx_sdk::utils::setup_logger();
+
+        let stdin = self.generate_sphinx_stdin(inputs)?;
+
+        let (mut public_values, _) = self
+            .client
+            .execute(Self::PROGRAM, stdin)
+            .run()
+            .map_err(|err| ProverError::Execution { source: err.into() })?;
+
+        Ok(CommitteeChangeAggregationOut::from(&mut public_values))
+    }
+
+    fn prove(&self, inputs: &Self::StdIn, mode: ProvingMode) -> Result<ProofType, Self::Error> {
+        let stdin = self.generate_sphinx_stdin(inputs)?;
+
+        match mode {
+            // STARK proofs are compressed so that they can be aggregated again.
+            ProvingMode::STARK => self
+                .client
+                .prove(&self.keys.0, stdin)
+                .compressed()
+                .run()
+                .map_err(|err| ProverError::Proving {
+                    proof_type: mode.into(),
+                    source: err.into(),
+                })
+                .map(ProofType::STARK),
+            ProvingMode::SNARK => self
+                .client
+                .prove(&self.keys.0, stdin)
+                .plonk()
+                .run()
+                .map_err(|err| ProverError::Proving {
+                    proof_type: mode.into(),
+                    source: err.into(),
+                })
+                .map(ProofType::SNARK),
+        }
+    }
+
+    fn verify(&self, proof: &ProofType) -> Result<(), Self::Error> {
+        let vk = &self.keys.1;
+
+        match proof {
+            ProofType::STARK(proof) => self
+                .client
+                .verify(proof, vk)
+                .map_err(|err| ProverError::Verification { source: err.into() }),
+            ProofType::SNARK(proof) => self
+                .client
+                .verify(proof, vk)
+                .map_err(|err| ProverError::Verification { source: err.into() }),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "ethereum"))]
+mod test {
+    use super::*;
+    use crate::proofs::committee_change::{CommitteeChangeIn, CommitteeChangeProver};
+    use crate::test_utils::generate_committee_change_test_assets;
+    use ethereum_lc_core::crypto::hash::keccak256_hash;
+
+    #[test]
+    #[ignore = "This test is too slow for CI"]
+    fn test_prove_stark_committee_change_aggregation() {
+        use std::time::Instant;
+
+        let test_assets = generate_committee_change_test_assets();
+
+        let committee_change_prover = CommitteeChangeProver::new();
+        let aggregation_prover = CommitteeChangeAggregationProver::new();
+
+        // Prove the first committee change, that is the trusted anchor of the chain.
+        let committee_change_proof = committee_change_prover
+            .prove_compressed(&CommitteeChangeIn::new(
+                test_assets.store.clone(),
+                test_assets.update.clone(),
+            ))
+            .unwrap();
+
+        let mut store = test_assets.store.clone();
+        store
+            .process_light_client_update(&test_assets.update)
+            .unwrap();
+
+        let inputs = CommitteeChangeAggregationIn::new(
+            PreviousProof::CommitteeChange(committee_change_proof),
+            store.clone(),
+            test_assets.update_new_period.clone(),
+        );
+
+        println!("Starting STARK proving for sync committee change aggregation...");
+        let start = Instant::now();
+
+        let proof = aggregation_prover
+            .prove(&inputs, ProvingMode::STARK)
+            .unwrap();
+        println!("Proving took {:?}", start.elapsed());
+
+        aggregation_prover.verify(&proof).unwrap();
+
+        store
+            .process_light_client_update(&test_assets.update_new_period)
+            .unwrap();
+
+        let outputs = CommitteeChangeAggregationOut::from(&mut proof.public_values());
+
+        assert_eq!(
+            outputs.committee_change().signer_sync_committee(),
+            keccak256_hash(&test_assets.store.current_sync_committee().to_ssz_bytes()).unwrap()
+        );
+        assert_eq!(
+            outputs.committee_change().new_sync_committee(),
+            keccak256_hash(&store.current_sync_committee().to_ssz_bytes()).unwrap()
+        );
+        assert_eq!(
+            outputs.committee_change_vkey(),
+            committee_change_prover.get_vk().hash_u32()
+        );
+        assert_eq!(
+            outputs.aggregation_vkey(),
+            aggregation_prover.get_vk().hash_u32()
+        );
+    }
+}
@@ -5,6 +5,9 @@
 //!
 //! This module provides the prover implementation for the storage inclusion proof. The prover
 //! is responsible for generating, executing, proving, and verifying proofs for the light client.
+//!
+//! A single proof can cover several distinct accounts, as long as all of their `EIP1186Proof`s are
+//! made against the same finalized execution state root.
 
 use crate::proofs::error::ProverError;
 use crate::proofs::{ProofType, Prover, ProvingMode};
@@ -15,8 +18,10 @@ use ethereum_lc_core::merkle::storage_proofs::EIP1186Proof;
 use ethereum_lc_core::types::error::TypesError;
 use ethereum_lc_core::types::store::{CompactStore, LightClientStore};
 use ethereum_lc_core::types::update::{CompactUpdate, Update};
-use ethereum_lc_core::types::utils::{calc_sync_period, extract_u32, OFFSET_BYTE_LENGTH};
-use ethereum_lc_core::types::{Address, ADDRESS_BYTES_LEN};
+use ethereum_lc_core::types::utils::{
+    extract_u32, ssz_decode_list_bytes, ssz_encode_list_bytes, OFFSET_BYTE_LENGTH,
+};
+use ethereum_lc_core::types::{Address, Bytes32, ADDRESS_BYTES_LEN};
 use ethereum_programs::INCLUSION_PROGRAM;
 use getset::{CopyGetters, Getters};
 use sphinx_sdk::{
@@ -59,15 +64,17 @@ impl StorageInclusionProver {
 }
 
 /// The input for the storage inclusion proof.
+///
+/// All the `EIP1186Proof`s are verified against the finalized execution state root of the update.
 #[derive(Debug, Eq, PartialEq)]
 pub struct StorageInclusionIn {
     store: LightClientStore,
     update: Update,
-    eip1186_proof: EIP1186Proof,
+    eip1186_proofs: Vec<EIP1186Proof>,
 }
 
 impl StorageInclusionIn {
-    /// Create a new `StorageInclusionIn`.
+    /// Create a new `StorageInclusionIn` for a single account.
     ///
     /// # Arguments
     ///
@@ -78,14 +85,70 @@ impl StorageInclusionIn {
     /// # Returns
     ///
     /// A new `StorageInclusionIn`.
-    pub const fn new(store: LightClientStore, update: Update, eip1186_proof: EIP1186Proof) -> Self {
+    pub fn new(store: LightClientStore, update: Update, eip1186_proof: EIP1186Proof) -> Self {
+        Self {
+            store,
+            update,
+            eip1186_proofs: vec![eip1186_proof],
+        }
+    }
+
+    /// Create a new `StorageInclusionIn` for several accounts.
+    ///
+    /// # Arguments
+    ///
+    /// * `store` - The `LightClientStore` that wil be passed to the program.
+    /// * `update` - The `Update` that will be passed to the program.
+    /// * `eip1186_proofs` - The `EIP1186Proof`s that will be passed to the program, one per account.
+    ///
+    /// # Returns
+    ///
+    /// A new `StorageInclusionIn`.
+    ///
+    /// # Notes
+    ///
+    /// The list of proofs should not be empty nor contain two proofs for the same address, otherwise
+    /// the input will be rejected by the prover.
+    pub const fn new_batch(
+        store: LightClientStore,
+        update: Update,
+        eip1186_proofs: Vec<EIP1186Proof>,
+    ) -> Self {
         Self {
             store,
             update,
-            eip1186_proof,
+            eip1186_proofs,
         }
     }
 
+    /// Checks that each proof of the `StorageInclusionIn` is for a different address.
+    ///
+    /// # Returns
+    ///
+    /// `true` if no address is proven twice, `false` otherwise.
+    fn has_distinct_addresses(&self) -> bool {
+        let mut addresses = self
+            .eip1186_proofs
+            .iter()
+            .map(|eip1186_proof| eip1186_proof.address)
+            .collect::<Vec<_>>();
+        addresses.sort_unstable();
+
+        addresses.windows(2).all(|pair| pair[0] != pair[1])
+    }
+
+    /// Serialize the proofs of the `StorageInclusionIn` as a list of SSZ encoded proofs.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<Vec<u8>>` containing the SSZ serialized proofs.
+    fn eip1186_proofs_ssz_bytes(&self) -> Vec<Vec<u8>> {
+        self.eip1186_proofs
+            .iter()
+            .map(EIP1186Proof::to_ssz_bytes)
+            .collect()
+    }
+
     /// Serialize the `StorageInclusionIn` struct to SSZ bytes.
     ///
     /// # Returns
@@ -102,13 +165,13 @@ impl StorageInclusionIn {
         let update_bytes = self.update.to_ssz_bytes()?;
         bytes.extend_from_slice(&update_offset.to_le_bytes());
 
-        let eip1186_proof_offset = update_offset + update_bytes.len() as u32;
-        let eip1186_proof_bytes = self.eip1186_proof.to_ssz_bytes();
-        bytes.extend_from_slice(&eip1186_proof_offset.to_le_bytes());
+        let eip1186_proofs_offset = update_offset + update_bytes.len() as u32;
+        let eip1186_proofs_bytes = ssz_encode_list_bytes(&self.eip1186_proofs_ssz_bytes());
+        bytes.extend_from_slice(&eip1186_proofs_offset.to_le_bytes());
 
         bytes.extend_from_slice(&store_bytes);
         bytes.extend_from_slice(&update_bytes);
-        bytes.extend_from_slice(&eip1186_proof_bytes);
+        bytes.extend_from_slice(&eip1186_proofs_bytes);
 
         Ok(bytes)
     }
@@ -124,14 +187,14 @@ impl StorageInclusionIn {
     /// A `Result` containing either the deserialized `StorageInclusionIn` struct or a `TypesError`.
     pub fn from_ssz_bytes(bytes: &[u8]) -> Result<Self, TypesError> {
         let cursor = 0;
-        let (cursor, store_offset) = extract_u32("CommmitteeChangeIn", bytes, cursor)?;
-        let (cursor, update_offset) = extract_u32("CommmitteeChangeIn", bytes, cursor)?;
-        let (cursor, eip1186_proof_offset) = extract_u32("CommmitteeChangeIn", bytes, cursor)?;
+        let (cursor, store_offset) = extract_u32("StorageInclusionIn", bytes, cursor)?;
+        let (cursor, update_offset) = extract_u32("StorageInclusionIn", bytes, cursor)?;
+        let (cursor, eip1186_proofs_offset) = extract_u32("StorageInclusionIn", bytes, cursor)?;
 
         // Deserialize the Light Client store
         if cursor != store_offset as usize {
             return Err(deserialization_error!(
-                "CommmitteeChangeIn",
+                "StorageInclusionIn",
                 "Invalid offset for store"
             ));
         }
@@ -139,30 +202,70 @@ impl StorageInclusionIn {
 
         // Deserialize the Update
         let update =
-            Update::from_ssz_bytes(&bytes[update_offset as usize..eip1186_proof_offset as usize])?;
+            Update::from_ssz_bytes(&bytes[update_offset as usize..eip1186_proofs_offset as usize])?;
+
+        // Deserialize the EIP1186Proofs
+        let eip1186_proofs = ssz_decode_list_bytes(&bytes[eip1186_proofs_offset as usize..])?
+            .iter()
+            .map(|proof_bytes| EIP1186Proof::from_ssz_bytes(proof_bytes))
+            .collect::<Result<Vec<_>, _>>()?;
 
-        // Deserialize the EIP1186Proof
-        let eip1186_proof = EIP1186Proof::from_ssz_bytes(&bytes[eip1186_proof_offset as usize..])?;
+        if eip1186_proofs.is_empty() {
+            return Err(deserialization_error!(
+                "StorageInclusionIn",
+                "At least one EIP1186 proof is expected"
+            ));
+        }
 
-        Ok(Self {
+        let inclusion_in = Self {
             store,
             update,
-            eip1186_proof,
-        })
+            eip1186_proofs,
+        };
+
+        if !inclusion_in.has_distinct_addresses() {
+            return Err(deserialization_error!(
+                "StorageInclusionIn",
+                "Each EIP1186 proof is expected to be for a different address"
+            ));
+        }
+
+        Ok(inclusion_in)
     }
 }
 
-/// The output for the sync committee change proof.
+/// The output for the storage inclusion proof.
 #[derive(Debug, Clone, CopyGetters, Getters)]
 pub struct StorageInclusionOut {
     #[getset(get_copy = "pub")]
     finalized_block_height: u64,
     #[getset(get_copy = "pub")]
     sync_committee_hash: HashValue,
+    #[getset(get_copy = "pub")]
+    accounts_len: u64,
+    #[getset(get = "pub")]
+    accounts: Vec<AccountInclusion>,
+}
+
+/// Represents the values output for an account and its storage.
+///
+/// The account fields are the ones decoded from the RLP encoded account of the `EIP1186Proof`. An
+/// absent account has all of its fields set to the values of an empty account.
+#[derive(Debug, Clone, CopyGetters, Getters)]
+pub struct AccountInclusion {
     #[getset(get_copy = "pub")]
     account_key: Address,
     #[getset(get_copy = "pub")]
-    account_value: HashValue,
+    account_present: bool,
+    #[getset(get_copy = "pub")]
+    nonce: u64,
+    /// Balance of the account in wei, as a big-endian 256-bit integer.
+    #[getset(get_copy = "pub")]
+    balance: Bytes32,
+    #[getset(get_copy = "pub")]
+    storage_root: HashValue,
+    #[getset(get_copy = "pub")]
+    code_hash: HashValue,
     #[getset(get_copy = "pub")]
     storage_key_value_len: u64,
     #[getset(get = "pub")]
@@ -170,19 +273,44 @@ pub struct StorageInclusionOut {
 }
 
 /// Represents the triplet of values output for storage values
-#[derive(Debug, Clone, Getters)]
-#[getset(get = "pub")]
+#[derive(Debug, Clone, CopyGetters, Getters)]
 pub struct StorageKeyValue {
+    #[getset(get = "pub")]
     key: Vec<u8>,
+    #[getset(get = "pub")]
     value: Vec<u8>,
+    #[getset(get_copy = "pub")]
+    present: bool,
 }
 
 impl From<&mut SphinxPublicValues> for StorageInclusionOut {
     fn from(public_values: &mut SphinxPublicValues) -> Self {
         let finalized_block_height = public_values.read::<u64>();
         let sync_committee_hash = HashValue::new(public_values.read::<[u8; 32]>());
+
+        let accounts_len = public_values.read::<u64>();
+
+        let accounts = (0..accounts_len)
+            .map(|_| AccountInclusion::from(&mut *public_values))
+            .collect();
+
+        Self {
+            finalized_block_height,
+            sync_committee_hash,
+            accounts_len,
+            accounts,
+        }
+    }
+}
+
+impl From<&mut SphinxPublicValues> for AccountInclusion {
+    fn from(public_values: &mut SphinxPublicValues) -> Self {
         let account_key = public_values.read::<[u8; ADDRESS_BYTES_LEN]>();
-        let account_value = HashValue::new(public_values.read::<[u8; HASH_LENGTH]>());
+        let account_present = public_values.read::<bool>();
+        let nonce = public_values.read::<u64>();
+        let balance = public_values.read::<Bytes32>();
+        let storage_root = HashValue::new(public_values.read::<[u8; HASH_LENGTH]>());
+        let code_hash = HashValue::new(public_values.read::<[u8; HASH_LENGTH]>());
 
         let storage_key_value_len = public_values.read::<u64>();
 
@@ -191,14 +319,21 @@ impl From<&mut SphinxPublicValues> for StorageInclusionOut {
         for _ in 0..storage_key_value_len {
             let key = public_values.read::<Vec<u8>>();
             let value = public_values.read::<Vec<u8>>();
-            storage_key_value.push(StorageKeyValue { key, value });
+            let present = public_values.read::<bool>();
+            storage_key_value.push(StorageKeyValue {
+                key,
+                value,
+                present,
+            });
         }
 
         Self {
-            finalized_block_height,
-            sync_committee_hash,
             account_key,
-            account_value,
+            account_present,
+            nonce,
+            balance,
+            storage_root,
+            code_hash,
             storage_key_value_len,
             storage_key_value,
         }
@@ -212,10 +347,24 @@ impl Prover for StorageInclusionProver {
     type StdOut = StorageInclusionOut;
 
     fn generate_sphinx_stdin(&self, inputs: &Self::StdIn) -> Result<SphinxStdin, Self::Error> {
+        if inputs.eip1186_proofs.is_empty() {
+            return Err(ProverError::SphinxInput {
+                source: "At least one EIP1186 proof is expected".into(),
+            });
+        }
+
+        if !inputs.has_distinct_addresses() {
+            return Err(ProverError::SphinxInput {
+                source: "Each EIP1186 proof is expected to be for a different address".into(),
+            });
+        }
+
         let mut stdin = SphinxStdin::new();
 
-        let update_sig_period = calc_sync_period(inputs.update.signature_slot());
-        let store_period = calc_sync_period(inputs.store.finalized_header().beacon().slot());
+        let network_config = inputs.store.network_config();
+        let update_sig_period = network_config.calc_sync_period(inputs.update.signature_slot());
+        let store_period =
+            network_config.calc_sync_period(inputs.store.finalized_header().beacon().slot());
 
         let finalized_beacon_slot = *inputs.store.finalized_header().beacon().slot();
         let correct_sync_committee =
@@ -230,15 +379,19 @@ impl Prover for StorageInclusionProver {
             };
 
         stdin.write(
-            &CompactStore::new(finalized_beacon_slot, correct_sync_committee.clone())
-                .to_ssz_bytes(),
+            &CompactStore::new(
+                finalized_beacon_slot,
+                correct_sync_committee.clone(),
+                *network_config,
+            )
+            .to_ssz_bytes(),
         );
         stdin.write(
             &CompactUpdate::from(inputs.update.clone())
                 .to_ssz_bytes()
                 .map_err(|err| ProverError::SphinxInput { source: err.into() })?,
         );
-        stdin.write(&inputs.eip1186_proof.to_ssz_bytes());
+        stdin.write(&inputs.eip1186_proofs_ssz_bytes());
         Ok(stdin)
     }
 
@@ -301,20 +454,52 @@ impl Prover for StorageInclusionProver {
 #[cfg(all(test, feature = "ethereum"))]
 mod test {
     use super::*;
-    use crate::test_utils::generate_inclusion_test_assets;
+    use crate::test_utils::{generate_inclusion_test_assets, InclusionTestAssets};
     use ethereum_lc_core::crypto::hash::keccak256_hash;
 
+    /// Checks the outputs of the inclusion program for the account present in the test assets.
+    fn assert_account_inclusion(account: &AccountInclusion, test_assets: &InclusionTestAssets) {
+        let expected_account = test_assets.eip1186_proof().decode_account().unwrap();
+
+        assert_eq!(&account.account_key, test_assets.eip1186_proof().address());
+        assert!(account.account_present);
+        assert_eq!(account.nonce, expected_account.nonce());
+        assert_eq!(account.balance, expected_account.balance());
+        assert_eq!(
+            account.storage_root,
+            *test_assets.eip1186_proof().storage_hash()
+        );
+        assert_eq!(account.code_hash, expected_account.code_hash());
+        assert_eq!(
+            account.storage_key_value_len,
+            test_assets.eip1186_proof().storage_proof().len() as u64
+        );
+
+        for i in 0..account.storage_key_value_len as usize {
+            assert_eq!(
+                account.storage_key_value[i].key,
+                test_assets.eip1186_proof().storage_proof()[i].key.clone()
+            );
+            assert_eq!(
+                account.storage_key_value[i].value,
+                test_assets.eip1186_proof().storage_proof()[i].value.clone()
+            );
+            // The storage key of the test assets is not set in the contract storage
+            assert!(!account.storage_key_value[i].present);
+        }
+    }
+
     #[test]
     fn test_execute_inclusion() {
         let test_assets = generate_inclusion_test_assets();
 
         let prover = StorageInclusionProver::new();
 
-        let inclusion_input = StorageInclusionIn {
-            store: test_assets.store().clone(),
-            update: test_assets.finality_update().clone().into(),
-            eip1186_proof: test_assets.eip1186_proof().clone(),
-        };
+        let inclusion_input = StorageInclusionIn::new(
+            test_assets.store().clone(),
+            test_assets.finality_update().clone().into(),
+            test_assets.eip1186_proof().clone(),
+        );
 
         let inclusion_output = prover.execute(&inclusion_input).unwrap();
 
@@ -330,26 +515,106 @@ mod test {
                 .beacon()
                 .slot()
         );
+        assert_eq!(inclusion_output.accounts_len, 1);
+        assert_account_inclusion(&inclusion_output.accounts[0], &test_assets);
+    }
+
+    /// Checks the outputs of the inclusion program for the account absent in the test assets.
+    fn assert_account_exclusion(account: &AccountInclusion, test_assets: &InclusionTestAssets) {
+        let absent_proof = test_assets.absent_account_eip1186_proof();
+
+        assert_eq!(&account.account_key, absent_proof.address());
+        assert!(!account.account_present);
+        assert_eq!(account.nonce, 0);
+        assert_eq!(account.balance, [0; 32]);
+        assert_eq!(account.storage_root, *absent_proof.storage_hash());
         assert_eq!(
-            inclusion_output.account_value,
-            keccak256_hash(test_assets.eip1186_proof().address().as_ref())
-                .expect("could not hash account address")
+            account.code_hash,
+            absent_proof.decode_account().unwrap().code_hash()
         );
         assert_eq!(
-            inclusion_output.storage_key_value_len,
-            test_assets.eip1186_proof().storage_proof().len() as u64
+            account.storage_key_value_len,
+            absent_proof.storage_proof().len() as u64
         );
+        assert!(account
+            .storage_key_value
+            .iter()
+            .all(|storage_key_value| !storage_key_value.present));
+    }
 
-        for i in 0..inclusion_output.storage_key_value_len as usize {
-            assert_eq!(
-                inclusion_output.storage_key_value[i].key,
-                test_assets.eip1186_proof().storage_proof()[i].key.clone()
-            );
-            assert_eq!(
-                inclusion_output.storage_key_value[i].value,
-                test_assets.eip1186_proof().storage_proof()[i].value.clone()
-            );
-        }
+    #[test]
+    fn test_execute_inclusion_batch() {
+        let test_assets = generate_inclusion_test_assets();
+
+        let prover = StorageInclusionProver::new();
+
+        let inclusion_input = StorageInclusionIn::new_batch(
+            test_assets.store().clone(),
+            test_assets.finality_update().clone().into(),
+            vec![
+                test_assets.eip1186_proof().clone(),
+                test_assets.absent_account_eip1186_proof().clone(),
+            ],
+        );
+
+        let inclusion_output = prover.execute(&inclusion_input).unwrap();
+
+        // Accounts are output in the order of the input proofs
+        assert_eq!(inclusion_output.accounts_len, 2);
+        assert_account_inclusion(&inclusion_output.accounts[0], &test_assets);
+        assert_account_exclusion(&inclusion_output.accounts[1], &test_assets);
+
+        // The same address can not be proven twice
+        let duplicate_input = StorageInclusionIn::new_batch(
+            test_assets.store().clone(),
+            test_assets.finality_update().clone().into(),
+            vec![
+                test_assets.eip1186_proof().clone(),
+                test_assets.eip1186_proof().clone(),
+            ],
+        );
+
+        assert!(prover.execute(&duplicate_input).is_err());
+    }
+
+    #[test]
+    fn test_inclusion_in_ssz_round_trip() {
+        let test_assets = generate_inclusion_test_assets();
+
+        let inclusion_input = StorageInclusionIn::new_batch(
+            test_assets.store().clone(),
+            test_assets.finality_update().clone().into(),
+            vec![
+                test_assets.eip1186_proof().clone(),
+                test_assets.absent_account_eip1186_proof().clone(),
+            ],
+        );
+
+        let bytes = inclusion_input.to_ssz_bytes().unwrap();
+        let decoded = StorageInclusionIn::from_ssz_bytes(&bytes).unwrap();
+
+        assert_eq!(inclusion_input, decoded);
+
+        let empty_input = StorageInclusionIn::new_batch(
+            test_assets.store().clone(),
+            test_assets.finality_update().clone().into(),
+            vec![],
+        );
+        let empty_bytes = empty_input.to_ssz_bytes().unwrap();
+
+        assert!(StorageInclusionIn::from_ssz_bytes(&empty_bytes).is_err());
+
+        let duplicate_input = StorageInclusionIn::new_batch(
+            test_assets.store().clone(),
+            test_assets.finality_update().clone().into(),
+            vec![
+                test_assets.eip1186_proof().clone(),
+                test_assets.eip1186_proof().clone(),
+            ],
+        );
+        let duplicate_bytes = duplicate_input.to_ssz_bytes().unwrap();
+
+        assert!(StorageInclusionIn::from_ssz_bytes(&duplicate_bytes).is_err());
     }
 
     #[test]
@@ -361,11 +626,11 @@ mod test {
 
         let prover = StorageInclusionProver::new();
 
-        let inclusion_inputs = StorageInclusionIn {
-            store: test_assets.store().clone(),
-            update: test_assets.finality_update().clone().into(),
-            eip1186_proof: test_assets.eip1186_proof().clone(),
-        };
+        let inclusion_inputs = StorageInclusionIn::new(
+            test_assets.store().clone(),
+            test_assets.finality_update().clone().into(),
+            test_assets.eip1186_proof().clone(),
+        );
 
         println!("Starting STARK proving for storage inclusion...");
         let start = Instant::now();
@@ -383,11 +648,11 @@ mod test {
 
         let prover = StorageInclusionProver::new();
 
-        let inclusion_inputs = StorageInclusionIn {
-            store: test_assets.store().clone(),
-            update: test_assets.finality_update().clone().into(),
-            eip1186_proof: test_assets.eip1186_proof().clone(),
-        };
+        let inclusion_inputs = StorageInclusionIn::new(
+            test_assets.store().clone(),
+            test_assets.finality_update().clone().into(),
+            test_assets.eip1186_proof().clone(),
+        );
 
         println!("Starting SNARK proving for storage inclusion...");
         let start = Instant::now();
@@ -15,6 +15,8 @@
 //! ## Sub-modules
 //!
 //! - `committee_change`: The prover for the sync committee change proof.
+//! - `committee_change_aggregation`: The prover aggregating sync committee change proofs into a
+//!   single proof of the latest sync committee.
 //!
 //! For more detailed information, users should refer to the specific documentation for each
 //! sub-module.
@@ -24,6 +26,7 @@ use serde::{Deserialize, Serialize};
 use sphinx_sdk::{SphinxProofWithPublicValues, SphinxPublicValues, SphinxStdin};
 
 pub mod committee_change;
+pub mod committee_change_aggregation;
 pub mod error;
 pub mod inclusion;
 
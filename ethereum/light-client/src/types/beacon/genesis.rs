@@ -0,0 +1,23 @@
+// Copyright (c) Argument Computer Corporation
+// SPDX-License-Identifier: Apache-2.0
+
+use getset::Getters;
+use serde::Deserialize;
+
+/// Payload received from the Beacon Node when fetching the genesis of the chain.
+///
+/// From [the Beacon Node API](https://ethereum.github.io/beacon-APIs/#/Beacon/getGenesis).
+#[derive(Debug, Clone, Deserialize, Getters)]
+#[getset(get = "pub")]
+pub struct GenesisResponse {
+    data: Genesis,
+}
+
+/// Details about the genesis of the chain followed by the Beacon Node.
+#[derive(Debug, Clone, Deserialize, Getters)]
+#[getset(get = "pub")]
+pub struct Genesis {
+    genesis_time: String,
+    genesis_validators_root: String,
+    genesis_fork_version: String,
+}
@@ -8,9 +8,12 @@
 //!
 //! ## Sub-modules
 //!
+//! - `genesis`: This module contains the data structures describing the genesis of the chain, used to
+//!   make sure that the Beacon Node follows the configured network.
 //! - `update`: This module contains the data structures passed over RPC for a Light Client to update its
 //!   state. It mainly contains the [`ethereum_lc_core::types::update::Update`] structure that contains all the necessary data to attest
 //!   of a sync committee change.
 //!
 //! For more detailed information, users should refer to the specific documentation for each sub-module.
+pub mod genesis;
 pub mod update;
@@ -3,8 +3,9 @@
 
 use anyhow::Result;
 use ethereum_lc_core::types::error::TypesError;
+use ethereum_lc_core::types::network::NetworkConfig;
 use ethereum_lc_core::types::update::{Update, UPDATE_BASE_BYTES_LEN};
-use ethereum_lc_core::types::utils::{calc_sync_period, U64_LEN};
+use ethereum_lc_core::types::utils::U64_LEN;
 use ethereum_lc_core::types::ForkDigest;
 use getset::Getters;
 
@@ -69,14 +70,19 @@ impl UpdateResponse {
     /// # Arguments
     ///
     /// * `known_period` - The period for which we already have the committee.
+    /// * `network_config` - The configuration of the network the updates belong to.
     ///
     /// # Returns
     ///
     /// An `Option` containing the update if it exists.
-    pub fn extract_committee_change(self, known_period: u64) -> Result<Option<Update>> {
+    pub fn extract_committee_change(
+        self,
+        known_period: u64,
+        network_config: &NetworkConfig,
+    ) -> Result<Option<Update>> {
         for update_item in self.updates {
-            let update_period =
-                calc_sync_period(update_item.update.attested_header().beacon().slot());
+            let update_period = network_config
+                .calc_sync_period(update_item.update.attested_header().beacon().slot());
             if update_period == known_period + 1 {
                 return Ok(Some(update_item.update));
             }
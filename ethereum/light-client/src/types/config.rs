@@ -0,0 +1,272 @@
+// Copyright (c) Argument Computer Corporation
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Configuration module
+//!
+//! This module contains the data structures used to load the configuration of a custom network
+//! from a file, for networks that do not have a preset in [`ethereum_lc_core::types::network`].
+//!
+//! Both TOML and YAML files are supported, the format being picked from the file extension. Fork
+//! versions and the genesis validators root are expected as hexadecimal strings, e.g.:
+//!
+//! ```toml
+//! chain_id = 11155111
+//! genesis_time = 1655733600
+//! genesis_validators_root = "0xd8ea171f3c94aea21ebc42a1ed61052acf3f9209c00e4efbaaddac09ed9b8078"
+//! seconds_per_slot = 12
+//! slots_per_epoch = 32
+//! epochs_per_sync_committee_period = 256
+//!
+//! [fork_schedule]
+//! deneb_fork_version = "0x90000073"
+//! electra_fork_version = "0x90000074"
+//! electra_fork_epoch = 222464
+//! fulu_fork_version = "0x90000075"
+//! fulu_fork_epoch = 272640
+//! ```
+//!
+//! `seconds_per_slot`, `slots_per_epoch` and `epochs_per_sync_committee_period` default to their
+//! mainnet values, and
+//! fork epochs that are left out default to [`FAR_FUTURE_EPOCH`].
+
+use anyhow::{anyhow, Error, Result};
+use ethereum_lc_core::types::forks::{ForkSchedule, FAR_FUTURE_EPOCH};
+use ethereum_lc_core::types::network::{
+    NetworkConfig, EPOCHS_PER_SYNC_COMMITTEE_PERIOD, SECONDS_PER_SLOT, SLOTS_PER_EPOCH,
+};
+use serde::Deserialize;
+use std::fs;
+use std::path::Path;
+
+/// Representation of a [`NetworkConfig`] in a configuration file.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NetworkConfigFile {
+    chain_id: u64,
+    genesis_time: u64,
+    genesis_validators_root: String,
+    #[serde(default = "default_seconds_per_slot")]
+    seconds_per_slot: u64,
+    #[serde(default = "default_slots_per_epoch")]
+    slots_per_epoch: u64,
+    #[serde(default = "default_epochs_per_sync_committee_period")]
+    epochs_per_sync_committee_period: u64,
+    fork_schedule: ForkScheduleFile,
+}
+
+/// Representation of a [`ForkSchedule`] in a configuration file.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ForkScheduleFile {
+    deneb_fork_version: String,
+    electra_fork_version: String,
+    #[serde(default = "default_fork_epoch")]
+    electra_fork_epoch: u64,
+    fulu_fork_version: String,
+    #[serde(default = "default_fork_epoch")]
+    fulu_fork_epoch: u64,
+}
+
+const fn default_seconds_per_slot() -> u64 {
+    SECONDS_PER_SLOT
+}
+
+const fn default_slots_per_epoch() -> u64 {
+    SLOTS_PER_EPOCH
+}
+
+const fn default_epochs_per_sync_committee_period() -> u64 {
+    EPOCHS_PER_SYNC_COMMITTEE_PERIOD
+}
+
+const fn default_fork_epoch() -> u64 {
+    FAR_FUTURE_EPOCH
+}
+
+impl NetworkConfigFile {
+    /// Parses a `NetworkConfigFile` from a TOML document.
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - The TOML document.
+    ///
+    /// # Returns
+    ///
+    /// The parsed `NetworkConfigFile`.
+    pub fn from_toml(content: &str) -> Result<Self> {
+        toml::from_str(content).map_err(|err| anyhow!("Invalid TOML network configuration: {err}"))
+    }
+
+    /// Parses a `NetworkConfigFile` from a YAML document.
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - The YAML document.
+    ///
+    /// # Returns
+    ///
+    /// The parsed `NetworkConfigFile`.
+    pub fn from_yaml(content: &str) -> Result<Self> {
+        serde_yaml::from_str(content)
+            .map_err(|err| anyhow!("Invalid YAML network configuration: {err}"))
+    }
+}
+
+impl TryFrom<NetworkConfigFile> for NetworkConfig {
+    type Error = Error;
+
+    fn try_from(file: NetworkConfigFile) -> Result<Self> {
+        if file.seconds_per_slot == 0
+            || file.slots_per_epoch == 0
+            || file.epochs_per_sync_committee_period == 0
+        {
+            return Err(anyhow!(
+                "seconds_per_slot, slots_per_epoch and epochs_per_sync_committee_period must be non-zero"
+            ));
+        }
+
+        let ForkScheduleFile {
+            deneb_fork_version,
+            electra_fork_version,
+            electra_fork_epoch,
+            fulu_fork_version,
+            fulu_fork_epoch,
+        } = file.fork_schedule;
+
+        if electra_fork_epoch > fulu_fork_epoch {
+            return Err(anyhow!(
+                "electra_fork_epoch ({electra_fork_epoch}) is after fulu_fork_epoch ({fulu_fork_epoch})"
+            ));
+        }
+
+        let fork_schedule = ForkSchedule::new(
+            decode_hex("deneb_fork_version", &deneb_fork_version)?,
+            decode_hex("electra_fork_version", &electra_fork_version)?,
+            electra_fork_epoch,
+            decode_hex("fulu_fork_version", &fulu_fork_version)?,
+            fulu_fork_epoch,
+        );
+
+        Ok(NetworkConfig::new(
+            file.chain_id,
+            file.genesis_time,
+            decode_hex("genesis_validators_root", &file.genesis_validators_root)?,
+            file.seconds_per_slot,
+            file.slots_per_epoch,
+            file.epochs_per_sync_committee_period,
+            fork_schedule,
+        ))
+    }
+}
+
+/// Loads a [`NetworkConfig`] from a TOML (`.toml`) or YAML (`.yaml`, `.yml`) file.
+///
+/// # Arguments
+///
+/// * `path` - The path to the configuration file.
+///
+/// # Returns
+///
+/// The loaded `NetworkConfig`.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read, has an unsupported extension or does not
+/// describe a valid network configuration.
+pub fn load_network_config(path: &Path) -> Result<NetworkConfig> {
+    let content = fs::read_to_string(path)
+        .map_err(|err| anyhow!("Could not read network configuration {path:?}: {err}"))?;
+
+    let file = match path.extension().and_then(|extension| extension.to_str()) {
+        Some("toml") => NetworkConfigFile::from_toml(&content)?,
+        Some("yaml" | "yml") => NetworkConfigFile::from_yaml(&content)?,
+        _ => {
+            return Err(anyhow!(
+                "Unsupported network configuration format for {path:?}, expected a .toml, .yaml or .yml file"
+            ))
+        }
+    };
+
+    NetworkConfig::try_from(file)
+}
+
+/// Decodes a `0x`-prefixed hexadecimal string into a fixed-size array.
+///
+/// # Arguments
+///
+/// * `field` - The name of the field being decoded.
+/// * `value` - The hexadecimal string.
+///
+/// # Returns
+///
+/// The decoded bytes.
+fn decode_hex<const N: usize>(field: &str, value: &str) -> Result<[u8; N]> {
+    let bytes = hex::decode(value.strip_prefix("0x").unwrap_or(value))
+        .map_err(|err| anyhow!("Invalid hexadecimal value for {field}: {err}"))?;
+
+    bytes.try_into().map_err(|bytes: Vec<u8>| {
+        anyhow!(
+            "Invalid length for {field}: expected {N} bytes, got {}",
+            bytes.len()
+        )
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use crate::types::config::NetworkConfigFile;
+    use ethereum_lc_core::types::forks::FAR_FUTURE_EPOCH;
+    use ethereum_lc_core::types::network::{NetworkConfig, SEPOLIA_NETWORK_CONFIG};
+
+    const SEPOLIA_TOML: &str = r#"
+chain_id = 11155111
+genesis_time = 1655733600
+genesis_validators_root = "0xd8ea171f3c94aea21ebc42a1ed61052acf3f9209c00e4efbaaddac09ed9b8078"
+
+[fork_schedule]
+deneb_fork_version = "0x90000073"
+electra_fork_version = "0x90000074"
+electra_fork_epoch = 222464
+fulu_fork_version = "0x90000075"
+fulu_fork_epoch = 272640
+"#;
+
+    const DEVNET_YAML: &str = r#"
+chain_id: 1337
+genesis_time: 1700000000
+genesis_validators_root: "0x0000000000000000000000000000000000000000000000000000000000000001"
+slots_per_epoch: 8
+epochs_per_sync_committee_period: 8
+fork_schedule:
+  deneb_fork_version: "0x10000000"
+  electra_fork_version: "0x20000000"
+  electra_fork_epoch: 0
+  fulu_fork_version: "0x30000000"
+"#;
+
+    #[test]
+    fn test_network_config_from_toml() {
+        let file = NetworkConfigFile::from_toml(SEPOLIA_TOML).unwrap();
+
+        assert_eq!(
+            NetworkConfig::try_from(file).unwrap(),
+            SEPOLIA_NETWORK_CONFIG
+        );
+    }
+
+    #[test]
+    fn test_network_config_from_yaml() {
+        let file = NetworkConfigFile::from_yaml(DEVNET_YAML).unwrap();
+        let config = NetworkConfig::try_from(file).unwrap();
+
+        assert_eq!(config.chain_id(), 1337);
+        assert_eq!(config.calc_sync_period(&64), 1);
+        assert_eq!(config.fork_schedule().electra_fork_epoch(), 0);
+        assert_eq!(config.fork_schedule().fulu_fork_epoch(), FAR_FUTURE_EPOCH);
+
+        let invalid_version = DEVNET_YAML.replace("0x20000000", "0x200000");
+        let file = NetworkConfigFile::from_yaml(&invalid_version).unwrap();
+
+        assert!(NetworkConfig::try_from(file).is_err());
+    }
+}
@@ -14,6 +14,7 @@
 //!
 //! - `beacon`: This sub-module contains the data structures used by the Beacon Node.
 //! - `checkpoint`: This sub-module contains the data structures used by the Checkpoint service.
+//! - `config`: This sub-module contains the data structures used to load a custom network configuration.
 //! - `network`: This sub-module contains the data structures that serves as payload for the Proof Server.
 //! - `storage`: This sub-module contains the data structures used by the RPC Provider.
 //!
@@ -21,5 +22,6 @@
 
 pub mod beacon;
 pub mod checkpoint;
+pub mod config;
 pub mod network;
 pub mod storage;
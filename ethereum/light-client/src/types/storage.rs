@@ -10,3 +10,12 @@ pub struct GetProofResponse {
     #[getset(get = "pub")]
     result: EIP1186ProofResponse,
 }
+
+/// The response from the `eth_chainId` RPC method.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize, Serialize, Getters)]
+pub struct ChainIdResponse {
+    id: u64,
+    jsonrpc: String,
+    #[getset(get = "pub")]
+    result: String,
+}
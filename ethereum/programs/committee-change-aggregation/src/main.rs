@@ -0,0 +1,170 @@
+// Copyright (c) Argument Computer Corporation
+// SPDX-License-Identifier: Apache-2.0
+
+#![no_main]
+
+use ethereum_lc_core::crypto::hash::{keccak256_hash, sha2_hash, HASH_LENGTH};
+use ethereum_lc_core::types::store::LightClientStore;
+use ethereum_lc_core::types::update::Update;
+
+sphinx_zkvm::entrypoint!(main);
+
+/// Length in bytes of the outputs shared by the committee change and aggregation programs: the
+/// finalized block height followed by three committee hashes.
+const COMMITTEE_CHANGE_OUTPUT_LEN: usize = 8 + HASH_LENGTH * 3;
+
+/// Reads a committee hash from the public values of a previous proof.
+fn read_hash(public_values: &[u8], index: usize) -> [u8; HASH_LENGTH] {
+    let start = 8 + index * HASH_LENGTH;
+
+    public_values[start..start + HASH_LENGTH]
+        .try_into()
+        .expect("Previous proof public values are too short")
+}
+
+/// Reads a verifying key digest from the public values of a previous aggregation proof.
+fn read_vkey(public_values: &[u8], index: usize) -> [u32; 8] {
+    let start = COMMITTEE_CHANGE_OUTPUT_LEN + index * HASH_LENGTH;
+    let bytes = public_values
+        .get(start..start + HASH_LENGTH)
+        .expect("Previous proof public values are too short");
+
+    core::array::from_fn(|i| u32::from_le_bytes(bytes[i * 4..i * 4 + 4].try_into().unwrap()))
+}
+
+pub fn main() {
+    sphinx_zkvm::precompiles::unconstrained! {
+                println!("cycle-tracker-start: read_inputs");
+    }
+    let committee_change_vkey = sphinx_zkvm::io::read::<[u32; 8]>();
+    let aggregation_vkey = sphinx_zkvm::io::read::<[u32; 8]>();
+    let previous_is_aggregation = sphinx_zkvm::io::read::<bool>();
+    let previous_public_values = sphinx_zkvm::io::read::<Vec<u8>>();
+    let store_bytes = sphinx_zkvm::io::read::<Vec<u8>>();
+    let update_bytes = sphinx_zkvm::io::read::<Vec<u8>>();
+    sphinx_zkvm::precompiles::unconstrained! {
+                println!("cycle-tracker-end: read_inputs");
+    }
+
+    // Verify the previous proof, either a committee change proof or a previous aggregation proof
+    sphinx_zkvm::precompiles::unconstrained! {
+                println!("cycle-tracker-start: verify_previous_proof");
+    }
+    let previous_public_values_digest =
+        sha2_hash(&previous_public_values).expect("Could not hash previous proof public values");
+    let previous_vkey = if previous_is_aggregation {
+        // The previous aggregation proof has to be about the same programs
+        assert_eq!(
+            read_vkey(&previous_public_values, 0),
+            committee_change_vkey,
+            "Previous aggregation proof was made for another committee change program"
+        );
+        assert_eq!(
+            read_vkey(&previous_public_values, 1),
+            aggregation_vkey,
+            "Previous aggregation proof was made for another aggregation program"
+        );
+        aggregation_vkey
+    } else {
+        committee_change_vkey
+    };
+    sphinx_zkvm::lib::verify::verify_sphinx_proof(
+        &previous_vkey,
+        &previous_public_values_digest.hash(),
+    );
+    sphinx_zkvm::precompiles::unconstrained! {
+                println!("cycle-tracker-end: verify_previous_proof");
+    }
+
+    // The signer committee of the first proof of the chain is the trusted anchor
+    let trusted_sync_committee_hash = read_hash(&previous_public_values, 0);
+    let previous_sync_committee_hash = read_hash(&previous_public_values, 1);
+    let previous_next_sync_committee_hash = read_hash(&previous_public_values, 2);
+
+    // Deserialize data structure
+    sphinx_zkvm::precompiles::unconstrained! {
+                println!("cycle-tracker-start: deserialize_light_client_store");
+    }
+    let mut store = LightClientStore::from_ssz_bytes(&store_bytes)
+        .expect("LightClientStore::from_ssz_bytes: could not create store");
+    sphinx_zkvm::precompiles::unconstrained! {
+                println!("cycle-tracker-end: deserialize_light_client_store");
+    }
+
+    sphinx_zkvm::precompiles::unconstrained! {
+                println!("cycle-tracker-start: deserialize_update");
+    }
+    let update = Update::from_ssz_bytes(&update_bytes)
+        .expect("Update::from_ssz_bytes: could not create update");
+    sphinx_zkvm::precompiles::unconstrained! {
+                println!("cycle-tracker-end: deserialize_update");
+    }
+
+    // Check that the store continues the chain of the previous proof
+    sphinx_zkvm::precompiles::unconstrained! {
+                println!("cycle-tracker-start: check_store_committees");
+    }
+    let store_sync_committee_hash = keccak256_hash(&store.current_sync_committee().to_ssz_bytes())
+        .expect("LightClientStore::current_sync_committee: could not hash committee");
+    let store_next_sync_committee_hash = keccak256_hash(
+        &store
+            .next_sync_committee()
+            .as_ref()
+            .expect("Store should have a next sync committee")
+            .to_ssz_bytes(),
+    )
+    .expect("LightClientStore::next_sync_committee: could not hash committee");
+    assert_eq!(
+        store_sync_committee_hash.hash(),
+        previous_sync_committee_hash,
+        "Store current sync committee does not match the previous proof"
+    );
+    assert_eq!(
+        store_next_sync_committee_hash.hash(),
+        previous_next_sync_committee_hash,
+        "Store next sync committee does not match the previous proof"
+    );
+    sphinx_zkvm::precompiles::unconstrained! {
+                println!("cycle-tracker-end: check_store_committees");
+    }
+
+    // Process update
+    sphinx_zkvm::precompiles::unconstrained! {
+                println!("cycle-tracker-start: process_update");
+    }
+    store
+        .process_light_client_update(&update)
+        .expect("LightClientStore::process_light_client_update: could not process update");
+    sphinx_zkvm::precompiles::unconstrained! {
+                println!("cycle-tracker-end: process_update");
+    }
+
+    // Hash updated sync committees
+    sphinx_zkvm::precompiles::unconstrained! {
+                println!("cycle-tracker-start: hash_new_sync_committee");
+    }
+    let updated_sync_committee_hash = keccak256_hash(&store.current_sync_committee().to_ssz_bytes())
+        .expect("LightClientStore::current_sync_committee: could not hash committee after processing update");
+    let next_sync_committee_hash = keccak256_hash(
+        &store
+            .next_sync_committee()
+            .as_ref()
+            .expect("Store should have a next sync committee after processing update")
+            .to_ssz_bytes(),
+    )
+    .expect(
+        "LightClientStore::next_sync_committee: could not hash committee after processing update",
+    );
+    sphinx_zkvm::precompiles::unconstrained! {
+                println!("cycle-tracker-end: hash_new_sync_committee");
+    }
+
+    // Commit the block height, the trusted anchor hash, the current and next sync committee
+    // hashes, and the verifying keys of the programs in the chain
+    sphinx_zkvm::io::commit(update.finalized_header().beacon().slot());
+    sphinx_zkvm::io::commit(&trusted_sync_committee_hash);
+    sphinx_zkvm::io::commit(&updated_sync_committee_hash.hash());
+    sphinx_zkvm::io::commit(&next_sync_committee_hash.hash());
+    sphinx_zkvm::io::commit(&committee_change_vkey);
+    sphinx_zkvm::io::commit(&aggregation_vkey);
+}
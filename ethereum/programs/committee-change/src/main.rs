@@ -14,7 +14,7 @@ pub fn main() {
                 println!("cycle-tracker-start: read_inputs");
     }
     let store_bytes = sphinx_zkvm::io::read::<Vec<u8>>();
-    let update_bytes = sphinx_zkvm::io::read::<Vec<u8>>();
+    let updates_bytes = sphinx_zkvm::io::read::<Vec<Vec<u8>>>();
     sphinx_zkvm::precompiles::unconstrained! {
                 println!("cycle-tracker-end: read_inputs");
     }
@@ -30,12 +30,18 @@ pub fn main() {
     }
 
     sphinx_zkvm::precompiles::unconstrained! {
-                println!("cycle-tracker-start: deserialize_update");
+                println!("cycle-tracker-start: deserialize_updates");
     }
-    let update = Update::from_ssz_bytes(&update_bytes)
-        .expect("Update::from_ssz_bytes: could not create update");
+    let updates = updates_bytes
+        .iter()
+        .map(|update_bytes| {
+            Update::from_ssz_bytes(update_bytes)
+                .expect("Update::from_ssz_bytes: could not create update")
+        })
+        .collect::<Vec<_>>();
+    assert!(!updates.is_empty(), "At least one update is expected");
     sphinx_zkvm::precompiles::unconstrained! {
-                println!("cycle-tracker-end: deserialize_update");
+                println!("cycle-tracker-end: deserialize_updates");
     }
     // Hash current sync committee
     sphinx_zkvm::precompiles::unconstrained! {
@@ -54,15 +60,17 @@ pub fn main() {
                 println!("cycle-tracker-end: hash_current_sync_committee");
     }
 
-    // Process update
+    // Process updates in order
     sphinx_zkvm::precompiles::unconstrained! {
-                println!("cycle-tracker-start: process_update");
+                println!("cycle-tracker-start: process_updates");
+    }
+    for update in &updates {
+        store
+            .process_light_client_update(update)
+            .expect("LightClientStore::process_light_client_update: could not process update");
     }
-    store
-        .process_light_client_update(&update)
-        .expect("LightClientStore::process_light_client_update: could not process update");
     sphinx_zkvm::precompiles::unconstrained! {
-                println!("cycle-tracker-end: process_update");
+                println!("cycle-tracker-end: process_updates");
     }
     // Hash updated sync committee
     sphinx_zkvm::precompiles::unconstrained! {
@@ -85,8 +93,10 @@ pub fn main() {
     sphinx_zkvm::precompiles::unconstrained! {
                 println!("cycle-tracker-end: hash_new_sync_committee");
     }
-    // Commit the signer hash, the current and next sync committee hashes, and the block height
-    sphinx_zkvm::io::commit(update.finalized_header().beacon().slot());
+    // Commit the block height of the last update, the signer hash of the first update, and the
+    // final current and next sync committee hashes
+    let last_update = updates.last().expect("At least one update is expected");
+    sphinx_zkvm::io::commit(last_update.finalized_header().beacon().slot());
     sphinx_zkvm::io::commit(&signer_sync_committee_hash.hash());
     sphinx_zkvm::io::commit(&updated_sync_committee_hash.hash());
     sphinx_zkvm::io::commit(&next_sync_committee_hash.hash());
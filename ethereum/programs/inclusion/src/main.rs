@@ -16,7 +16,7 @@ pub fn main() {
     }
     let compact_store_bytes = sphinx_zkvm::io::read::<Vec<u8>>();
     let compact_update_bytes = sphinx_zkvm::io::read::<Vec<u8>>();
-    let eip1186_proof_bytes = sphinx_zkvm::io::read::<Vec<u8>>();
+    let eip1186_proofs_bytes = sphinx_zkvm::io::read::<Vec<Vec<u8>>>();
     sphinx_zkvm::precompiles::unconstrained! {
                 println!("cycle-tracker-end: read_inputs");
     }
@@ -28,8 +28,26 @@ pub fn main() {
         .expect("CompactStore::from_ssz_bytes: could not create store");
     let compact_update = CompactUpdate::from_ssz_bytes(&compact_update_bytes)
         .expect("CompactUpdate::from_ssz_bytes: could not create update");
-    let eip1186_proof = EIP1186Proof::from_ssz_bytes(&eip1186_proof_bytes)
-        .expect("EIP1186Proof::from_ssz_bytes: could not create proof");
+    let eip1186_proofs = eip1186_proofs_bytes
+        .iter()
+        .map(|eip1186_proof_bytes| {
+            EIP1186Proof::from_ssz_bytes(eip1186_proof_bytes)
+                .expect("EIP1186Proof::from_ssz_bytes: could not create proof")
+        })
+        .collect::<Vec<_>>();
+    assert!(
+        !eip1186_proofs.is_empty(),
+        "At least one EIP1186 proof is expected"
+    );
+    let mut addresses = eip1186_proofs
+        .iter()
+        .map(|eip1186_proof| eip1186_proof.address)
+        .collect::<Vec<_>>();
+    addresses.sort_unstable();
+    assert!(
+        addresses.windows(2).all(|pair| pair[0] != pair[1]),
+        "Each EIP1186 proof is expected to be for a different address"
+    );
     sphinx_zkvm::precompiles::unconstrained! {
                 println!("cycle-tracker-end: deserialize_inputs");
     }
@@ -60,18 +78,26 @@ pub fn main() {
                 println!("cycle-tracker-end: check_execution_inclusion");
     }
 
-    // Verify proof against finalized state root
+    // Verify all proofs against the same finalized state root
     sphinx_zkvm::precompiles::unconstrained! {
                 println!("cycle-tracker-start: verify_proof");
     }
-    eip1186_proof
-        .verify(compact_update.finalized_execution_state_root())
-        .expect("verify: could not verify proof");
+    let finalized_execution_state_root = compact_update.finalized_execution_state_root();
+    let memberships = eip1186_proofs
+        .iter()
+        .map(|eip1186_proof| {
+            eip1186_proof
+                .verify_membership(finalized_execution_state_root)
+                .expect("verify_membership: could not verify proof")
+                .expect("verify_membership: proof is invalid")
+        })
+        .collect::<Vec<_>>();
     sphinx_zkvm::precompiles::unconstrained! {
                 println!("cycle-tracker-end: verify_proof");
     }
 
-    // Output the signers sync committee hash, the attested block number, the hash of address + storage keys
+    // Output the signers sync committee hash, the attested block number, and for each account its
+    // address and storage keys
     sphinx_zkvm::precompiles::unconstrained! {
                 println!("cycle-tracker-start: output");
     }
@@ -81,21 +107,35 @@ pub fn main() {
     );
     sphinx_zkvm::io::commit(compact_update.finalized_header().beacon().slot());
     sphinx_zkvm::io::commit(sync_committee_hash.as_ref());
-    // Account key
-    sphinx_zkvm::io::commit(&eip1186_proof.address);
-    // Account value
-    sphinx_zkvm::io::commit(
-        keccak256_hash(&eip1186_proof.address)
-            .expect("could not hash account address")
-            .as_ref(),
-    );
 
-    // Length of storage key/value pair
-    sphinx_zkvm::io::commit(&(eip1186_proof.storage_proof().len() as u64));
-    // Commit storage keys & values
-    for storage_proof in eip1186_proof.storage_proof().iter() {
-        sphinx_zkvm::io::commit(&storage_proof.key);
-        sphinx_zkvm::io::commit(&storage_proof.value);
+    // Number of accounts
+    sphinx_zkvm::io::commit(&(eip1186_proofs.len() as u64));
+    for (eip1186_proof, membership) in eip1186_proofs.iter().zip(memberships.iter()) {
+        // Account key
+        sphinx_zkvm::io::commit(&eip1186_proof.address);
+        // Account presence
+        sphinx_zkvm::io::commit(&membership.account().is_present());
+        // Account fields
+        let account = eip1186_proof
+            .decode_account()
+            .expect("EIP1186Proof::decode_account: could not decode account");
+        sphinx_zkvm::io::commit(&account.nonce());
+        sphinx_zkvm::io::commit(&account.balance());
+        sphinx_zkvm::io::commit(account.storage_root().as_ref());
+        sphinx_zkvm::io::commit(account.code_hash().as_ref());
+
+        // Length of storage key/value pair
+        sphinx_zkvm::io::commit(&(eip1186_proof.storage_proof().len() as u64));
+        // Commit storage keys, values & presence
+        for (storage_proof, storage_membership) in eip1186_proof
+            .storage_proof()
+            .iter()
+            .zip(membership.storage().iter())
+        {
+            sphinx_zkvm::io::commit(&storage_proof.key);
+            sphinx_zkvm::io::commit(&storage_proof.value);
+            sphinx_zkvm::io::commit(&storage_membership.is_present());
+        }
     }
 
     sphinx_zkvm::precompiles::unconstrained! {
@@ -173,10 +173,15 @@ fn generate_fixture_inclusion_ethereum_lc(remote: &str) {
 
     let prover = StorageInclusionProver::new();
     let test_assets = generate_inclusion_test_assets();
-    let input = StorageInclusionIn::new(
+    // prove one account present in and one account absent from the state trie, so that the fixture covers the
+    // multi-account layout of the public values
+    let input = StorageInclusionIn::new_batch(
         test_assets.store().clone(),
         test_assets.finality_update().clone().into(),
-        test_assets.eip1186_proof().clone(),
+        vec![
+            test_assets.eip1186_proof().clone(),
+            test_assets.absent_account_eip1186_proof().clone(),
+        ],
     );
     let proof = match prover.prove(&input, ProvingMode::SNARK).unwrap() {
         ProofType::SNARK(inner_proof) => inner_proof,
@@ -8,4 +8,9 @@ use thiserror::Error;
 pub enum CryptoError {
     #[error("Invalid digest length: expected {expected}, got {actual}")]
     DigestLength { expected: usize, actual: usize },
+    #[error("Error while computing a Merkle proof: {source}")]
+    MerkleProof {
+        #[source]
+        source: Box<dyn std::error::Error + Sync + Send>,
+    },
 }
@@ -12,3 +12,9 @@ pub const U256_BYTES_LENGTH: usize = 32;
 construct_uint! {
     pub struct U256(4);
 }
+
+construct_uint! {
+    /// Wide integer used for intermediate computations on `U256` values
+    /// that could overflow, such as the difficulty adjustment.
+    pub struct U512(8);
+}
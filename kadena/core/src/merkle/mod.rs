@@ -1,3 +1,8 @@
+// Copyright (c) Argument Computer Corporation
+// SPDX-License-Identifier: Apache-2.0
+
+pub mod proof;
+
 /// Tag associated to the chain ID value in the Merkle tree.
 ///
 /// See [the `chainweb-node` wiki](https://github.com/kadena-io/chainweb-node/wiki/Chainweb-Merkle-Tree#chainweb-merkle-hash-function).
@@ -32,6 +37,16 @@ pub const CHAINWEB_VERSION_TAG: u16 = 0x0008;
 /// See [the `chainweb-node` wiki](https://github.com/kadena-io/chainweb-node/wiki/Chainweb-Merkle-Tree#chainweb-merkle-hash-function).
 pub const HASH_TARGET_TAG: u16 = 0x0011;
 
+/// Tag associated to the transaction output value in the Merkle tree.
+///
+/// See [the `chainweb-node` wiki](https://github.com/kadena-io/chainweb-node/wiki/Chainweb-Merkle-Tree#chainweb-merkle-hash-function).
+pub const TRANSACTION_OUTPUT_TAG: u16 = 0x0014;
+
+/// Tag associated to the coinbase output value in the Merkle tree.
+///
+/// See [the `chainweb-node` wiki](https://github.com/kadena-io/chainweb-node/wiki/Chainweb-Merkle-Tree#chainweb-merkle-hash-function).
+pub const COINBASE_OUTPUT_TAG: u16 = 0x0018;
+
 /// Tag associated to the epoch start value in the Merkle tree.
 ///
 /// See [the `chainweb-node` wiki](https://github.com/kadena-io/chainweb-node/wiki/Chainweb-Merkle-Tree#chainweb-merkle-hash-function).
@@ -0,0 +1,313 @@
+// Copyright (c) Argument Computer Corporation
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Merkle Proof Module
+//!
+//! This module provides the Merkle proofs used in the Chainweb Merkle
+//! trees. Chainweb trees are built by the `merkle-log` library: a tree
+//! of `n` leaves is made of a perfect tree over the largest power of two
+//! strictly below `n` leaves on its left, and of the tree over the
+//! remaining leaves on its right.
+//!
+//! See [the `chainweb-node` wiki](https://github.com/kadena-io/chainweb-node/wiki/Chainweb-Merkle-Tree#merke-log-trees).
+
+use crate::crypto::error::CryptoError;
+use crate::crypto::hash::sha512::hash_inner;
+use crate::crypto::hash::{HashValue, DIGEST_BYTES_LENGTH};
+use crate::deserialization_error;
+use crate::types::error::TypesError;
+use crate::types::utils::extract_fixed_bytes;
+use crate::types::U16_BYTES_LENGTH;
+use getset::{CopyGetters, Getters};
+
+/// Size in bytes of a serialized proof step.
+pub const MERKLE_PROOF_STEP_BYTES_LENGTH: usize = 1 + DIGEST_BYTES_LENGTH;
+
+/// Side of the sibling hash in a step of a Merkle proof.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Side {
+    /// The sibling hash is the left child of the parent node.
+    Left,
+    /// The sibling hash is the right child of the parent node.
+    Right,
+}
+
+/// A step of a Merkle proof, containing the sibling hash of the
+/// current node and its side.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, CopyGetters)]
+#[getset(get_copy = "pub")]
+pub struct MerkleProofStep {
+    side: Side,
+    hash: HashValue,
+}
+
+impl MerkleProofStep {
+    /// Create a new `MerkleProofStep`.
+    ///
+    /// # Arguments
+    ///
+    /// * `side` - The side of the sibling hash.
+    /// * `hash` - The sibling hash.
+    ///
+    /// # Returns
+    ///
+    /// A new `MerkleProofStep`.
+    pub const fn new(side: Side, hash: HashValue) -> Self {
+        Self { side, hash }
+    }
+}
+
+/// A Merkle proof in a Chainweb Merkle tree, made of the steps going
+/// from a node up to the root of the tree.
+#[derive(Debug, Clone, Eq, PartialEq, Getters)]
+#[getset(get = "pub")]
+pub struct MerkleProof {
+    steps: Vec<MerkleProofStep>,
+}
+
+impl MerkleProof {
+    /// Create a new `MerkleProof`.
+    ///
+    /// # Arguments
+    ///
+    /// * `steps` - The steps of the proof, from the bottom of the tree to its root.
+    ///
+    /// # Returns
+    ///
+    /// A new `MerkleProof`.
+    pub const fn new(steps: Vec<MerkleProofStep>) -> Self {
+        Self { steps }
+    }
+
+    /// Create the `MerkleProof` of a leaf in the Chainweb Merkle tree
+    /// built over the given leaves.
+    ///
+    /// # Arguments
+    ///
+    /// * `leaves` - The hashes of the leaves of the tree.
+    /// * `index` - The index of the leaf to prove.
+    ///
+    /// # Returns
+    ///
+    /// The `MerkleProof` of the leaf.
+    pub fn from_leaves(leaves: &[HashValue], index: usize) -> Result<Self, CryptoError> {
+        if index >= leaves.len() {
+            return Err(CryptoError::MerkleProof {
+                source: format!(
+                    "Leaf index {index} is out of bounds for a tree of {} leaves",
+                    leaves.len()
+                )
+                .into(),
+            });
+        }
+
+        let mut steps = vec![];
+        let mut leaves = leaves;
+        let mut index = index;
+
+        // Walk down the tree, recording the sibling subtree of each node on the path
+        while leaves.len() > 1 {
+            let split = split_index(leaves.len());
+            if index < split {
+                steps.push(MerkleProofStep::new(
+                    Side::Right,
+                    merkle_root(&leaves[split..])?,
+                ));
+                leaves = &leaves[..split];
+            } else {
+                steps.push(MerkleProofStep::new(
+                    Side::Left,
+                    merkle_root(&leaves[..split])?,
+                ));
+                leaves = &leaves[split..];
+                index -= split;
+            }
+        }
+
+        steps.reverse();
+
+        Ok(Self { steps })
+    }
+
+    /// Compute the root of the tree from the node being proven.
+    ///
+    /// # Arguments
+    ///
+    /// * `node` - The hash of the node being proven.
+    ///
+    /// # Returns
+    ///
+    /// The root of the tree.
+    pub fn root(&self, node: HashValue) -> Result<HashValue, CryptoError> {
+        self.steps
+            .iter()
+            .try_fold(node, |current, step| match step.side {
+                Side::Left => hash_inner(step.hash.as_ref(), current.as_ref()),
+                Side::Right => hash_inner(current.as_ref(), step.hash.as_ref()),
+            })
+    }
+
+    /// Serialize the `MerkleProof` into a vector of bytes.
+    ///
+    /// # Returns
+    ///
+    /// The bytes of the `MerkleProof`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(
+            U16_BYTES_LENGTH + self.steps.len() * MERKLE_PROOF_STEP_BYTES_LENGTH,
+        );
+
+        bytes.extend_from_slice(&(self.steps.len() as u16).to_le_bytes());
+        for step in &self.steps {
+            bytes.push(match step.side {
+                Side::Left => 0,
+                Side::Right => 1,
+            });
+            bytes.extend_from_slice(step.hash.as_ref());
+        }
+
+        bytes
+    }
+
+    /// Deserialize a `MerkleProof` from a slice of bytes.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The slice of bytes to deserialize.
+    ///
+    /// # Returns
+    ///
+    /// The `MerkleProof` deserialized from the bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, TypesError> {
+        let (cursor, steps_len) = extract_fixed_bytes::<U16_BYTES_LENGTH>("MerkleProof", bytes, 0)?;
+        let steps_len = u16::from_le_bytes(steps_len) as usize;
+
+        let expected_len = U16_BYTES_LENGTH + steps_len * MERKLE_PROOF_STEP_BYTES_LENGTH;
+        if bytes.len() != expected_len {
+            return Err(TypesError::InvalidLength {
+                structure: "MerkleProof".into(),
+                expected: expected_len,
+                actual: bytes.len(),
+            });
+        }
+
+        let steps = bytes[cursor..]
+            .chunks_exact(MERKLE_PROOF_STEP_BYTES_LENGTH)
+            .map(|step| {
+                let side = match step[0] {
+                    0 => Side::Left,
+                    1 => Side::Right,
+                    _ => return Err(deserialization_error!("MerkleProof", "Invalid step side")),
+                };
+                let hash = HashValue::from_slice(&step[1..])
+                    .map_err(|err| deserialization_error!("MerkleProof", err))?;
+
+                Ok(MerkleProofStep::new(side, hash))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { steps })
+    }
+}
+
+/// Compute the root of the Chainweb Merkle tree built over the given
+/// leaves.
+///
+/// # Arguments
+///
+/// * `leaves` - The hashes of the leaves of the tree.
+///
+/// # Returns
+///
+/// The root of the tree.
+pub fn merkle_root(leaves: &[HashValue]) -> Result<HashValue, CryptoError> {
+    match leaves.len() {
+        0 => Err(CryptoError::MerkleProof {
+            source: "Can not compute the root of an empty tree".into(),
+        }),
+        1 => Ok(leaves[0]),
+        len => {
+            let split = split_index(len);
+            hash_inner(
+                merkle_root(&leaves[..split])?.as_ref(),
+                merkle_root(&leaves[split..])?.as_ref(),
+            )
+        }
+    }
+}
+
+/// Get the number of leaves in the left subtree of a tree of `len`
+/// leaves, which is the largest power of two strictly below `len`.
+const fn split_index(len: usize) -> usize {
+    len.next_power_of_two() / 2
+}
+
+#[cfg(all(test, feature = "kadena"))]
+mod test {
+    use super::*;
+    use crate::crypto::hash::sha512::hash_tagged_data;
+    use crate::merkle::{
+        BLOCK_CREATION_TIME_TAG, BLOCK_HEIGHT_TAG, BLOCK_NONCE_TAG, BLOCK_WEIGHT_TAG,
+        CHAINWEB_VERSION_TAG, CHAIN_ID_TAG, EPOCH_START_TIME_TAG, FEATURE_FLAGS_TAG,
+        HASH_TARGET_TAG,
+    };
+    use crate::test_utils::RAW_HEADER;
+    use crate::types::header::chain::KadenaHeaderRaw;
+
+    fn header_leaves(header: &KadenaHeaderRaw) -> Vec<HashValue> {
+        let adjacent_hashes = header.adjacent_parents().unwrap().hashes();
+
+        vec![
+            hash_tagged_data(FEATURE_FLAGS_TAG, header.flags()).unwrap(),
+            hash_tagged_data(BLOCK_CREATION_TIME_TAG, header.time()).unwrap(),
+            HashValue::new(*header.parent()),
+            hash_tagged_data(HASH_TARGET_TAG, header.target()).unwrap(),
+            HashValue::new(*header.payload()),
+            hash_tagged_data(CHAIN_ID_TAG, header.chain()).unwrap(),
+            hash_tagged_data(BLOCK_WEIGHT_TAG, header.weight()).unwrap(),
+            hash_tagged_data(BLOCK_HEIGHT_TAG, header.height()).unwrap(),
+            hash_tagged_data(CHAINWEB_VERSION_TAG, header.version()).unwrap(),
+            hash_tagged_data(EPOCH_START_TIME_TAG, header.epoch_start()).unwrap(),
+            hash_tagged_data(BLOCK_NONCE_TAG, header.nonce()).unwrap(),
+            HashValue::new(adjacent_hashes[0]),
+            HashValue::new(adjacent_hashes[1]),
+            HashValue::new(adjacent_hashes[2]),
+        ]
+    }
+
+    #[test]
+    fn test_split_index() {
+        assert_eq!(split_index(2), 1);
+        assert_eq!(split_index(3), 2);
+        assert_eq!(split_index(4), 2);
+        assert_eq!(split_index(5), 4);
+        assert_eq!(split_index(8), 4);
+        assert_eq!(split_index(14), 8);
+    }
+
+    #[test]
+    fn test_merkle_proof_header_tree() {
+        let header = KadenaHeaderRaw::from_base64(RAW_HEADER).unwrap();
+        let leaves = header_leaves(&header);
+
+        // The header tree is a Chainweb Merkle tree of 14 leaves
+        let root = merkle_root(&leaves).unwrap();
+        assert_eq!(root, header.header_root().unwrap());
+        assert_eq!(root.as_ref(), header.hash());
+
+        for (index, leaf) in leaves.iter().enumerate() {
+            let proof = MerkleProof::from_leaves(&leaves, index).unwrap();
+            assert_eq!(proof.root(*leaf).unwrap(), root);
+
+            let bytes = proof.to_bytes();
+            assert_eq!(MerkleProof::from_bytes(&bytes).unwrap(), proof);
+        }
+
+        // A proof for a leaf does not hold for its siblings
+        let proof = MerkleProof::from_leaves(&leaves, 4).unwrap();
+        assert_ne!(proof.root(leaves[5]).unwrap(), root);
+
+        assert!(MerkleProof::from_leaves(&leaves, leaves.len()).is_err());
+        assert!(MerkleProof::from_bytes(&[1, 0]).is_err());
+    }
+}
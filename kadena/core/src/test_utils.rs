@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::types::header::chain::RAW_HEADER_BYTES_LEN;
+use crate::types::header::compact::CompactLayerHeaders;
 use crate::types::header::layer::ChainwebLayerHeader;
 use std::fs;
 use std::path::PathBuf;
@@ -24,12 +25,16 @@ pub const TESTNET_CHAIN_3_HEADERS_URL: &str =
 pub const COMPACT_HEADER_PATH: &str = "../test-assets/compact.json";
 pub const CHAINWEB_LAYER_HEADERS_PATH: &str = "../test-assets/kadena_layer_headers.json";
 
-pub fn get_compact_headers_bytes() -> Vec<u8> {
+// The compact form of the mainnet layers of `CHAINWEB_LAYER_HEADERS_PATH`, from height
+// 5099342 to 5099348, as serialized by `CompactLayerHeaders::to_bytes`.
+pub fn get_compact_layer_headers() -> CompactLayerHeaders {
     let root_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
 
     let test_asset_path = root_path.join(COMPACT_HEADER_PATH);
 
-    fs::read(test_asset_path).unwrap()
+    let bytes: Vec<u8> = serde_json::from_slice(&fs::read(test_asset_path).unwrap()).unwrap();
+
+    CompactLayerHeaders::from_bytes(&bytes).unwrap()
 }
 
 pub fn get_layer_block_headers() -> Vec<ChainwebLayerHeader> {
@@ -2,8 +2,10 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::crypto::hash::DIGEST_BYTES_LENGTH;
+use crate::types::error::TypesError;
 use crate::types::header::chain::CHAIN_BYTES_LENGTH;
 use crate::types::U16_BYTES_LENGTH;
+use getset::{CopyGetters, Getters};
 
 /// Size in bytes of the value for the adjacent parent. Contains the
 /// length of the adjacent parent record and the adjacent parent
@@ -41,15 +43,44 @@ impl AdjacentParentRaw {
 
         Self { chain, hash }
     }
+
+    /// Serialize the `AdjacentParentRaw` into a vector of bytes.
+    ///
+    /// # Returns
+    ///
+    /// The bytes of the `AdjacentParentRaw`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(CHAIN_BYTES_LENGTH + DIGEST_BYTES_LENGTH);
+        bytes.extend_from_slice(&self.chain);
+        bytes.extend_from_slice(&self.hash);
+        bytes
+    }
 }
 
 /// Represent an adjacent parent in a Kadena block in Rust types.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, CopyGetters)]
+#[getset(get_copy = "pub")]
 pub struct AdjacentParent {
     chain: u32,
     hash: [u8; DIGEST_BYTES_LENGTH],
 }
 
+impl AdjacentParent {
+    /// Create a new `AdjacentParent`.
+    ///
+    /// # Arguments
+    ///
+    /// * `chain` - The chain of the adjacent parent.
+    /// * `hash` - The hash of the adjacent parent.
+    ///
+    /// # Returns
+    ///
+    /// A new `AdjacentParent`.
+    pub const fn new(chain: u32, hash: [u8; DIGEST_BYTES_LENGTH]) -> Self {
+        Self { chain, hash }
+    }
+}
+
 impl From<&AdjacentParentRaw> for AdjacentParent {
     fn from(raw: &AdjacentParentRaw) -> Self {
         let chain = u32::from_le_bytes(raw.chain);
@@ -85,6 +116,42 @@ impl AdjacentParentRecordRaw {
 
         Self { length, adjacents }
     }
+
+    /// Serialize the `AdjacentParentRecordRaw` into a vector of bytes.
+    ///
+    /// # Returns
+    ///
+    /// The bytes of the `AdjacentParentRecordRaw`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(ADJACENTS_RAW_BYTES_LENGTH);
+        bytes.extend_from_slice(&self.length);
+        bytes.extend_from_slice(&self.adjacents);
+        bytes
+    }
+}
+
+impl From<&AdjacentParentRecord> for AdjacentParentRecordRaw {
+    fn from(record: &AdjacentParentRecord) -> Self {
+        let length = record.length.to_le_bytes();
+
+        let mut adjacents = [0; ADJACENT_RECORD_RAW_BYTES_LENGTH];
+        for (i, adjacent) in record.adjacents.iter().enumerate() {
+            let start = i * (CHAIN_BYTES_LENGTH + DIGEST_BYTES_LENGTH);
+            adjacents[start..start + CHAIN_BYTES_LENGTH + DIGEST_BYTES_LENGTH]
+                .copy_from_slice(&AdjacentParentRaw::from(adjacent).to_bytes());
+        }
+
+        Self { length, adjacents }
+    }
+}
+
+impl From<&AdjacentParent> for AdjacentParentRaw {
+    fn from(adjacent: &AdjacentParent) -> Self {
+        Self::from(AdjacentParent {
+            chain: adjacent.chain,
+            hash: adjacent.hash,
+        })
+    }
 }
 
 impl From<AdjacentParent> for AdjacentParentRaw {
@@ -99,36 +166,66 @@ impl From<AdjacentParent> for AdjacentParentRaw {
 /// Represents a record of all the adjacent parents of a Kadena block
 /// with its properties as Rust types.
 #[repr(align(1))]
-#[derive(Debug)]
-#[allow(dead_code)]
+#[derive(Debug, Clone, Eq, PartialEq, Getters)]
+#[getset(get = "pub")]
 pub struct AdjacentParentRecord {
     length: u16,
     adjacents: Vec<AdjacentParent>,
 }
 
-impl From<AdjacentParentRecordRaw> for AdjacentParentRecord {
-    fn from(raw: AdjacentParentRecordRaw) -> Self {
+impl TryFrom<AdjacentParentRecordRaw> for AdjacentParentRecord {
+    type Error = TypesError;
+
+    fn try_from(raw: AdjacentParentRecordRaw) -> Result<Self, Self::Error> {
         let length = u16::from_le_bytes(raw.length);
 
-        let mut adjacents = vec![];
-        for i in 0..length as usize {
-            let start = i * CHAIN_BYTES_LENGTH + DIGEST_BYTES_LENGTH;
-            let end = start + CHAIN_BYTES_LENGTH + DIGEST_BYTES_LENGTH;
-            adjacents.push(AdjacentParent::from(&AdjacentParentRaw::from_bytes(
-                raw.adjacents[start..end]
-                    .try_into()
-                    .expect("Should be able to convert raw adjacent parent to fixed slice"),
-            )));
+        // The record has a fixed size, as all Chainweb graphs have the same degree
+        if length as usize != ADJACENT_RECORD_PER_BLOCK {
+            return Err(TypesError::InvalidLength {
+                structure: "AdjacentParentRecord".to_string(),
+                expected: ADJACENT_RECORD_PER_BLOCK,
+                actual: length as usize,
+            });
         }
 
-        // just in case
-        adjacents.sort_unstable_by_key(|v| v.chain);
+        // Adjacent parents are kept in their serialization order, which
+        // is sorted by chain
+        let adjacents = raw
+            .adjacents
+            .chunks_exact(CHAIN_BYTES_LENGTH + DIGEST_BYTES_LENGTH)
+            .map(|bytes| AdjacentParent::from(&AdjacentParentRaw::from_bytes(bytes)))
+            .collect();
 
-        Self { length, adjacents }
+        Ok(Self { length, adjacents })
     }
 }
 
 impl AdjacentParentRecord {
+    /// Create a new `AdjacentParentRecord`.
+    ///
+    /// # Arguments
+    ///
+    /// * `adjacents` - The adjacent parents, sorted by chain.
+    ///
+    /// # Returns
+    ///
+    /// A new `AdjacentParentRecord`.
+    pub fn new(adjacents: Vec<AdjacentParent>) -> Self {
+        Self {
+            length: adjacents.len() as u16,
+            adjacents,
+        }
+    }
+
+    /// Get the chains of the adjacent parents.
+    ///
+    /// # Returns
+    ///
+    /// The chains of the adjacent parents.
+    pub fn chains(&self) -> Vec<u32> {
+        self.adjacents.iter().map(|a| a.chain).collect()
+    }
+
     /// Get the hashes of the adjacent parents.
     ///
     /// # Returns
@@ -138,3 +235,30 @@ impl AdjacentParentRecord {
         self.adjacents.iter().map(|a| a.hash).collect()
     }
 }
+
+#[cfg(all(test, feature = "kadena"))]
+mod test {
+    use super::*;
+    use crate::test_utils::RAW_HEADER;
+    use crate::types::header::chain::KadenaHeaderRaw;
+
+    #[test]
+    fn test_bytes_conversion_adjacent_parent_record() {
+        let header = KadenaHeaderRaw::from_base64(RAW_HEADER).unwrap();
+
+        let record = header.adjacent_parents().unwrap();
+        assert_eq!(record.chains(), vec![5, 10, 15]);
+        assert_eq!(
+            AdjacentParentRecordRaw::from(&record).to_bytes(),
+            header.adjacents().to_vec()
+        );
+
+        // A record must hold one adjacent parent per adjacent chain
+        let mut adjacents = *header.adjacents();
+        adjacents[0] = 2;
+        assert!(
+            AdjacentParentRecord::try_from(AdjacentParentRecordRaw::from_bytes(&adjacents))
+                .is_err()
+        );
+    }
+}
@@ -92,6 +92,58 @@ pub enum ValidationError {
         computed: HashValue,
         stored: HashValue,
     },
+    #[error("Invalid chain block payload hash. Computed: {computed}, stored: {stored}")]
+    InvalidPayloadHash {
+        computed: HashValue,
+        stored: HashValue,
+    },
     #[error("Missing parent hash in the chain block header list at index {index}")]
     MissingParentHeader { index: usize },
+    #[error("Missing adjacent parent header for chain {chain} in the previous layer")]
+    MissingAdjacentParentHeader { chain: u32 },
+    #[error("Unsupported Chainweb version with code {code}")]
+    UnsupportedChainwebVersion { code: u32 },
+    #[error("Unknown Chainweb version {name}")]
+    UnknownChainwebVersion { name: String },
+    #[error("Invalid Chainweb version specification: {reason}")]
+    InvalidChainwebVersionSpec { reason: String },
+    #[error("Unsupported layer at height {height}: its headers can not be verified across the chain graph transition at height {transition_height}")]
+    UnsupportedChainGraphTransition { height: u64, transition_height: u64 },
+    #[error("Invalid Chainweb version for block. Expected {expected}, got {actual}")]
+    InvalidChainwebVersion { expected: u32, actual: u32 },
+    #[error(
+        "Invalid chains for layer at height {height}: expected {expected} chains, got {actual}"
+    )]
+    InvalidLayerChains {
+        height: u64,
+        expected: usize,
+        actual: usize,
+    },
+    #[error("Invalid adjacent parent record for chain {chain}: {source}")]
+    InvalidAdjacentParentRecord {
+        chain: u32,
+        #[source]
+        source: Box<dyn std::error::Error + Sync + Send>,
+    },
+    #[error("Invalid adjacent chains for chain {chain}. Expected {expected}, got {actual}")]
+    InvalidAdjacentChains {
+        chain: u32,
+        expected: String,
+        actual: String,
+    },
+    #[error("Invalid adjacent parent hash for chain {chain} on adjacent chain {adjacent_chain}. Computed: {computed}, stored: {stored}")]
+    InvalidAdjacentParentHash {
+        chain: u32,
+        adjacent_chain: u32,
+        computed: HashValue,
+        stored: HashValue,
+    },
+    #[error("Invalid epoch start for block. Expected {expected}, got {actual}")]
+    InvalidEpochStart { expected: u64, actual: u64 },
+    #[error("Invalid target for block. Expected {expected}, got {actual}")]
+    InvalidTarget { expected: String, actual: String },
+    #[error("Invalid creation time for block. Expected after {parent}, got {actual}")]
+    InvalidCreationTime { parent: u64, actual: u64 },
+    #[error("Invalid weight for block. Expected {expected}, got {actual}")]
+    InvalidWeight { expected: String, actual: String },
 }
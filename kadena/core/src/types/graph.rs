@@ -0,0 +1,72 @@
+// Copyright (c) Argument Computer Corporation
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Chain Graph Module
+//!
+//! This module provides the graphs linking the chains of a Chainweb
+//! network. Each block header references the headers of its adjacent
+//! chains at the previous height, braiding the chains together.
+//!
+//! Chainweb started with ten chains linked as a Petersen graph, and
+//! moved to twenty chains linked by the twenty chain graph. Both graphs
+//! have a degree of three. The graph in use at a given height depends on
+//! the `ChainwebVersion` of the network.
+
+use crate::types::adjacent::ADJACENT_RECORD_PER_BLOCK;
+
+/// Adjacent chains of each chain in the Petersen graph, used by
+/// Chainweb networks of ten chains.
+pub const PETERSEN_GRAPH: [[u32; ADJACENT_RECORD_PER_BLOCK]; 10] = [
+    [2, 3, 5],
+    [3, 4, 6],
+    [0, 4, 7],
+    [0, 1, 8],
+    [1, 2, 9],
+    [0, 6, 9],
+    [1, 5, 7],
+    [2, 6, 8],
+    [3, 7, 9],
+    [4, 5, 8],
+];
+
+/// Adjacent chains of each chain in the twenty chain graph, used by
+/// Chainweb networks of twenty chains.
+pub const TWENTY_CHAIN_GRAPH: [[u32; ADJACENT_RECORD_PER_BLOCK]; 20] = [
+    [5, 10, 15],
+    [6, 11, 16],
+    [7, 12, 17],
+    [8, 13, 18],
+    [9, 14, 19],
+    [0, 7, 8],
+    [1, 8, 9],
+    [2, 5, 9],
+    [3, 5, 6],
+    [4, 6, 7],
+    [0, 11, 19],
+    [1, 10, 12],
+    [2, 11, 13],
+    [3, 12, 14],
+    [4, 13, 15],
+    [0, 14, 16],
+    [1, 15, 17],
+    [2, 16, 18],
+    [3, 17, 19],
+    [4, 10, 18],
+];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_chain_graphs_are_symmetric() {
+        for graph in [&PETERSEN_GRAPH[..], &TWENTY_CHAIN_GRAPH[..]] {
+            for (chain, adjacents) in graph.iter().enumerate() {
+                assert!(adjacents.windows(2).all(|pair| pair[0] < pair[1]));
+                for adjacent in adjacents {
+                    assert!(graph[*adjacent as usize].contains(&(chain as u32)));
+                }
+            }
+        }
+    }
+}
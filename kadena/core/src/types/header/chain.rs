@@ -10,16 +10,21 @@ use crate::merkle::{
     CHAINWEB_VERSION_TAG, CHAIN_ID_TAG, EPOCH_START_TIME_TAG, FEATURE_FLAGS_TAG, HASH_TARGET_TAG,
 };
 use crate::types::adjacent::{
-    AdjacentParentRecord, AdjacentParentRecordRaw, ADJACENTS_RAW_BYTES_LENGTH,
+    AdjacentParent, AdjacentParentRecord, AdjacentParentRecordRaw, ADJACENTS_RAW_BYTES_LENGTH,
+    ADJACENT_RECORD_PER_BLOCK,
 };
 use crate::types::error::{TypesError, ValidationError};
+use crate::types::header::difficulty::{difficulty, expected_epoch_start, expected_target};
 use crate::types::utils::extract_fixed_bytes;
+use crate::types::version::ChainwebVersion;
 use crate::types::{U32_BYTES_LENGTH, U64_BYTES_LENGTH};
 use anyhow::Result;
 use base64::engine::general_purpose::URL_SAFE_NO_PAD;
 use base64::Engine;
 use chrono::{DateTime, Utc};
-use getset::Getters;
+use getset::{CopyGetters, Getters};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 /// Size in bytes of a Kadena header represented as a base64 string
 pub const RAW_HEADER_BYTES_LEN: usize = 424;
@@ -75,6 +80,10 @@ pub const NONCE_BYTES_LENGTH: usize = U64_BYTES_LENGTH;
 /// Size in bytes of the hash property of a Kadena header
 pub const HASH_BYTES_LENGTH: usize = DIGEST_BYTES_LENGTH;
 
+/// Size in bytes of a compact Kadena header represented as a byte array
+pub const COMPACT_HEADER_BYTES_LENGTH: usize =
+    FLAGS_BYTES_LENGTH + TIME_BYTES_LENGTH + PAYLOAD_BYTES_LENGTH + NONCE_BYTES_LENGTH;
+
 /// Representation of a Kadena header with its properties as bytes
 /// arrays.
 ///
@@ -231,6 +240,114 @@ impl KadenaHeaderRaw {
         serialized
     }
 
+    /// Reconstructs a header from its compact representation. Apart from
+    /// the feature flags, creation time, payload and nonce carried by the
+    /// compact header, all the properties of a header follow from its
+    /// parent, the previous layer and the chain graph of the network:
+    ///
+    /// * The adjacent parents are the headers of the previous layer on the
+    ///   chains adjacent to the one of the header in the chain graph.
+    /// * The target, epoch start and weight are derived with the rules
+    ///   checked by [`KadenaHeaderRaw::verify_difficulty`] and
+    ///   [`KadenaHeaderRaw::verify_weight`].
+    ///
+    /// Those derived properties are only trusted once bound to a committed
+    /// hash. The hash of a header commits to the ones of its parent and
+    /// adjacent parents, so a hash is only needed for the headers of the
+    /// last layer of a list, all the previous headers being their ancestors.
+    ///
+    /// # Arguments
+    ///
+    /// * `compact` - The compact representation of the header.
+    /// * `parent` - The parent of the header.
+    /// * `parent_layer` - The chain headers of the layer of the parent,
+    ///   indexed by chain.
+    /// * `chain_graph` - The chain graph of the network at the height of the parent of the
+    ///   header.
+    /// * `hash` - The hash committed for the header, if any.
+    ///
+    /// # Returns
+    ///
+    /// The reconstructed header, or an error if its adjacent parents can
+    /// not be found in the parent layer or if its hash is not the committed
+    /// one.
+    pub fn from_compact(
+        compact: &CompactHeaderRaw,
+        parent: &Self,
+        parent_layer: &[Self],
+        chain_graph: &[[u32; ADJACENT_RECORD_PER_BLOCK]],
+        hash: Option<&[u8; HASH_BYTES_LENGTH]>,
+    ) -> Result<Self, ValidationError> {
+        let chain = u32::from_le_bytes(parent.chain);
+        let adjacent_chains =
+            chain_graph
+                .get(chain as usize)
+                .ok_or(ValidationError::NonValidChain {
+                    size: chain_graph.len(),
+                    chain: chain as usize,
+                })?;
+
+        let adjacent_parents = adjacent_chains
+            .iter()
+            .map(|&adjacent_chain| {
+                parent_layer
+                    .get(adjacent_chain as usize)
+                    .filter(|header| u32::from_le_bytes(header.chain) == adjacent_chain)
+                    .ok_or(ValidationError::MissingAdjacentParentHeader {
+                        chain: adjacent_chain,
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let adjacents = AdjacentParentRecordRaw::from(&AdjacentParentRecord::new(
+            adjacent_parents
+                .iter()
+                .map(|header| AdjacentParent::new(u32::from_le_bytes(header.chain), header.hash))
+                .collect(),
+        ))
+        .to_bytes()
+        .try_into()
+        .expect("Should be able to convert adjacent record to fixed length array");
+
+        let target = expected_target(parent, &adjacent_parents);
+        let mut target_bytes = [0; TARGET_BYTES_LENGTH];
+        target.to_little_endian(&mut target_bytes);
+
+        let weight = U256::from_little_endian(&parent.weight).saturating_add(difficulty(target));
+        let mut weight_bytes = [0; WEIGHT_BYTES_LENGTH];
+        weight.to_little_endian(&mut weight_bytes);
+
+        let mut header = Self {
+            flags: compact.flags,
+            time: compact.time,
+            parent: parent.hash,
+            adjacents,
+            target: target_bytes,
+            payload: compact.payload,
+            chain: parent.chain,
+            weight: weight_bytes,
+            height: (u64::from_le_bytes(parent.height) + 1).to_le_bytes(),
+            version: parent.version,
+            epoch_start: expected_epoch_start(parent).to_le_bytes(),
+            nonce: compact.nonce,
+            hash: [0; HASH_BYTES_LENGTH],
+        };
+        header.hash = *header
+            .header_root()
+            .map_err(|err| ValidationError::HashError { source: err.into() })?
+            .as_ref();
+
+        if let Some(hash) = hash {
+            if header.hash != *hash {
+                return Err(ValidationError::InvalidChainBlockHash {
+                    computed: HashValue::new(header.hash),
+                    stored: HashValue::new(*hash),
+                });
+            }
+        }
+
+        Ok(header)
+    }
+
     /// Computes the root hash of the header.
     ///
     /// # Returns
@@ -311,6 +428,150 @@ impl KadenaHeaderRaw {
         }
     }
 
+    /// Decodes the record of the adjacent parents of the header.
+    ///
+    /// # Returns
+    ///
+    /// The `AdjacentParentRecord` of the header.
+    pub fn adjacent_parents(&self) -> Result<AdjacentParentRecord, TypesError> {
+        AdjacentParentRecord::try_from(AdjacentParentRecordRaw::from_bytes(&self.adjacents))
+    }
+
+    /// Verifies that the header is braided to the previous layer of the
+    /// network: its adjacent parents must be on the chains adjacent to its
+    /// own in the chain graph, and their hashes must be the ones of the
+    /// headers of those chains in the previous layer.
+    ///
+    /// # Arguments
+    ///
+    /// * `parent_layer` - The chain headers of the previous layer, indexed
+    ///   by chain.
+    /// * `chain_graph` - The chain graph of the network at the height of the parent of the
+    ///   header.
+    ///
+    /// # Returns
+    ///
+    /// The adjacent parent headers of the header, taken from the previous
+    /// layer, or an error if the header is not properly braided.
+    pub fn verify_adjacent_parents<'a>(
+        &self,
+        parent_layer: &'a [Self],
+        chain_graph: &[[u32; ADJACENT_RECORD_PER_BLOCK]],
+    ) -> Result<Vec<&'a Self>, ValidationError> {
+        let chain = u32::from_le_bytes(self.chain);
+        let record = self.adjacent_parents().map_err(|err| {
+            ValidationError::InvalidAdjacentParentRecord {
+                chain,
+                source: err.into(),
+            }
+        })?;
+
+        let expected_chains =
+            chain_graph
+                .get(chain as usize)
+                .ok_or(ValidationError::NonValidChain {
+                    size: chain_graph.len(),
+                    chain: chain as usize,
+                })?;
+        let chains = record.chains();
+        if chains != expected_chains {
+            return Err(ValidationError::InvalidAdjacentChains {
+                chain,
+                expected: format!("{expected_chains:?}"),
+                actual: format!("{chains:?}"),
+            });
+        }
+
+        record
+            .adjacents()
+            .iter()
+            .map(|adjacent| {
+                let adjacent_parent = parent_layer
+                    .get(adjacent.chain() as usize)
+                    .filter(|header| u32::from_le_bytes(header.chain) == adjacent.chain())
+                    .ok_or(ValidationError::MissingAdjacentParentHeader {
+                        chain: adjacent.chain(),
+                    })?;
+
+                if adjacent.hash() != adjacent_parent.hash {
+                    return Err(ValidationError::InvalidAdjacentParentHash {
+                        chain,
+                        adjacent_chain: adjacent.chain(),
+                        computed: HashValue::new(adjacent_parent.hash),
+                        stored: HashValue::new(adjacent.hash()),
+                    });
+                }
+
+                Ok(adjacent_parent)
+            })
+            .collect()
+    }
+
+    /// Verifies that the target and epoch start of the header follow the
+    /// difficulty adjustment rules of Chainweb. Without this check, a
+    /// header could set its own low target and be mined with little work.
+    ///
+    /// # Arguments
+    ///
+    /// * `parent` - The parent of the header.
+    /// * `adjacent_parents` - The adjacent parents of the header, as
+    ///   returned by [`KadenaHeaderRaw::verify_adjacent_parents`].
+    ///
+    /// # Returns
+    ///
+    /// An error if the target or the epoch start of the header are not
+    /// the expected ones.
+    pub fn verify_difficulty(
+        &self,
+        parent: &Self,
+        adjacent_parents: &[&Self],
+    ) -> Result<(), ValidationError> {
+        let epoch_start = u64::from_le_bytes(self.epoch_start);
+        let expected_epoch_start = expected_epoch_start(parent);
+        if epoch_start != expected_epoch_start {
+            return Err(ValidationError::InvalidEpochStart {
+                expected: expected_epoch_start,
+                actual: epoch_start,
+            });
+        }
+
+        let target = U256::from_little_endian(&self.target);
+        let expected_target = expected_target(parent, adjacent_parents);
+        if target != expected_target {
+            return Err(ValidationError::InvalidTarget {
+                expected: expected_target.to_string(),
+                actual: target.to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Verifies that the weight of the header is the weight of its parent
+    /// increased by the difficulty of its target. Without this check, a
+    /// header could claim more cumulative work than its chain holds.
+    ///
+    /// # Arguments
+    ///
+    /// * `parent` - The parent of the header.
+    ///
+    /// # Returns
+    ///
+    /// An error if the weight of the header is not the expected one.
+    pub fn verify_weight(&self, parent: &Self) -> Result<(), ValidationError> {
+        let weight = U256::from_little_endian(&self.weight);
+        let expected_weight = U256::from_little_endian(&parent.weight)
+            .saturating_add(difficulty(U256::from_little_endian(&self.target)));
+        if weight != expected_weight {
+            return Err(ValidationError::InvalidWeight {
+                expected: expected_weight.to_string(),
+                actual: weight.to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
     /// Computes the proof of work hash of the header.
     ///
     /// # Returns
@@ -336,43 +597,107 @@ impl KadenaHeaderRaw {
 }
 
 /// Representation of a Kadena header with its properties as Rust types.
-#[derive(Debug, Getters)]
-#[getset(get = "pub")]
-#[allow(dead_code)]
-struct KadenaHeader {
-    flags: [u8; 8], // All 0s, for future usage
+///
+/// It serializes to and from JSON following the object encoding of block
+/// headers in the Chainweb node REST API.
+#[derive(Debug, Clone, Eq, PartialEq, Getters, CopyGetters, Serialize, Deserialize)]
+#[serde(try_from = "KadenaHeaderJson", into = "KadenaHeaderJson")]
+pub struct KadenaHeader {
+    /// The feature flags of the block, all 0s for future usage.
+    #[getset(get_copy = "pub")]
+    flags: u64,
+    /// The creation time of the block.
+    #[getset(get_copy = "pub")]
     time: DateTime<Utc>,
-    parent: U256,
+    /// The hash of the parent of the block.
+    #[getset(get_copy = "pub")]
+    parent: HashValue,
+    /// The adjacent parents of the block.
+    #[getset(get = "pub")]
     adjacents: AdjacentParentRecord,
+    /// The target that the proof of work hash of the block must not exceed.
+    #[getset(get_copy = "pub")]
     target: U256,
+    /// The hash of the payload of the block.
+    #[getset(get_copy = "pub")]
     payload: HashValue,
-    chain: [u8; 4],
+    /// The chain of the block.
+    #[getset(get_copy = "pub")]
+    chain: u32,
+    /// The cumulative difficulty of the chain up to the block.
+    #[getset(get_copy = "pub")]
     weight: U256,
+    /// The height of the block.
+    #[getset(get_copy = "pub")]
     height: u64,
+    /// The code of the Chainweb version of the block.
+    #[getset(get_copy = "pub")]
     version: u32,
+    /// The start time of the difficulty adjustment epoch of the block.
+    #[getset(get_copy = "pub")]
     epoch_start: DateTime<Utc>,
-    nonce: [u8; 8],
+    /// The nonce of the block.
+    #[getset(get_copy = "pub")]
+    nonce: u64,
+    /// The hash of the block.
+    #[getset(get_copy = "pub")]
     hash: HashValue,
 }
 
+impl KadenaHeader {
+    /// Verifies that the header was created after its parent.
+    ///
+    /// # Arguments
+    ///
+    /// * `parent` - The parent of the header.
+    ///
+    /// # Returns
+    ///
+    /// An error if the header was not created after its parent.
+    pub fn verify_creation_time(&self, parent: &Self) -> Result<(), ValidationError> {
+        if self.time <= parent.time {
+            return Err(ValidationError::InvalidCreationTime {
+                parent: parent.time.timestamp_micros() as u64,
+                actual: self.time.timestamp_micros() as u64,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Verifies that the weight of the header is the weight of its parent
+    /// increased by the difficulty of its target. See
+    /// [`KadenaHeaderRaw::verify_weight`].
+    ///
+    /// # Arguments
+    ///
+    /// * `parent` - The parent of the header.
+    ///
+    /// # Returns
+    ///
+    /// An error if the weight of the header is not the expected one.
+    pub fn verify_weight(&self, parent: &Self) -> Result<(), ValidationError> {
+        KadenaHeaderRaw::from(self).verify_weight(&KadenaHeaderRaw::from(parent))
+    }
+}
+
 impl TryFrom<KadenaHeaderRaw> for KadenaHeader {
     type Error = TypesError;
 
     fn try_from(raw: KadenaHeaderRaw) -> Result<Self, Self::Error> {
-        let flags = raw.flags;
+        let flags = u64::from_le_bytes(raw.flags);
         let creation_time = DateTime::from_timestamp_micros(u64::from_le_bytes(raw.time) as i64)
             .ok_or_else(|| TypesError::ConversionError {
                 source: "Could not convert time bytes to DateTime".into(),
                 from: "KadenaHeaderRaw".into(),
                 to: "KadenaHeader".into(),
             })?;
-        let parent = U256::from_little_endian(&raw.parent);
-        let adjacents =
-            AdjacentParentRecord::from(AdjacentParentRecordRaw::from_bytes(&raw.adjacents));
+        let parent = HashValue::new(raw.parent);
+        let adjacents = raw.adjacent_parents()?;
 
         let target = U256::from_little_endian(&raw.target);
         let payload = HashValue::new(raw.payload);
-        let chain = raw.chain;
+        let chain = u32::from_le_bytes(raw.chain);
         let weight = U256::from_little_endian(&raw.weight);
         let height = u64::from_le_bytes(raw.height);
         let version = u32::from_le_bytes(raw.version);
@@ -383,7 +708,7 @@ impl TryFrom<KadenaHeaderRaw> for KadenaHeader {
                     from: "KadenaHeaderRaw".into(),
                     to: "KadenaHeader".into(),
                 })?;
-        let nonce = raw.nonce;
+        let nonce = u64::from_le_bytes(raw.nonce);
         let hash = HashValue::new(raw.hash);
 
         Ok(Self {
@@ -404,13 +729,190 @@ impl TryFrom<KadenaHeaderRaw> for KadenaHeader {
     }
 }
 
-/// A compact representation of a Kadena header.
-#[derive(Debug, Clone, Getters)]
+impl From<&KadenaHeader> for KadenaHeaderRaw {
+    fn from(header: &KadenaHeader) -> Self {
+        let mut target = [0; TARGET_BYTES_LENGTH];
+        header.target.to_little_endian(&mut target);
+        let mut weight = [0; WEIGHT_BYTES_LENGTH];
+        header.weight.to_little_endian(&mut weight);
+
+        Self {
+            flags: header.flags.to_le_bytes(),
+            time: (header.time.timestamp_micros() as u64).to_le_bytes(),
+            parent: *header.parent.as_ref(),
+            adjacents: AdjacentParentRecordRaw::from(&header.adjacents)
+                .to_bytes()
+                .try_into()
+                .expect("Should be able to convert adjacent record to fixed length array"),
+            target,
+            payload: *header.payload.as_ref(),
+            chain: header.chain.to_le_bytes(),
+            weight,
+            height: header.height.to_le_bytes(),
+            version: header.version.to_le_bytes(),
+            epoch_start: (header.epoch_start.timestamp_micros() as u64).to_le_bytes(),
+            nonce: header.nonce.to_le_bytes(),
+            hash: *header.hash.as_ref(),
+        }
+    }
+}
+
+/// Object encoding of a block header in the Chainweb node REST API.
+///
+/// From [the `chainweb-node` API documentation](https://api.chainweb.com/openapi/#tag/model-block-header).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct KadenaHeaderJson {
+    nonce: String,
+    creation_time: i64,
+    parent: String,
+    adjacents: BTreeMap<String, String>,
+    target: String,
+    payload_hash: String,
+    chain_id: u32,
+    weight: String,
+    height: u64,
+    chainweb_version: String,
+    epoch_start: i64,
+    feature_flags: u64,
+    hash: String,
+}
+
+/// Decodes a 32 bytes value encoded in base64 in the Chainweb node REST API.
+fn decode_base64_json(field: &str, value: &str) -> Result<[u8; DIGEST_BYTES_LENGTH], TypesError> {
+    let decoded =
+        URL_SAFE_NO_PAD
+            .decode(value)
+            .map_err(|err| TypesError::DeserializationError {
+                structure: format!("KadenaHeader.{field}"),
+                source: err.into(),
+            })?;
+
+    <[u8; DIGEST_BYTES_LENGTH]>::try_from(decoded.as_slice()).map_err(|_| {
+        TypesError::InvalidLength {
+            structure: format!("KadenaHeader.{field}"),
+            expected: DIGEST_BYTES_LENGTH,
+            actual: decoded.len(),
+        }
+    })
+}
+
+impl TryFrom<KadenaHeaderJson> for KadenaHeader {
+    type Error = TypesError;
+
+    fn try_from(json: KadenaHeaderJson) -> Result<Self, Self::Error> {
+        let conversion_error =
+            |source: Box<dyn std::error::Error + Sync + Send>| TypesError::ConversionError {
+                from: "KadenaHeaderJson".into(),
+                to: "KadenaHeader".into(),
+                source,
+            };
+
+        // Adjacent parents are sorted by chain, as in the binary encoding
+        let mut adjacents = json
+            .adjacents
+            .iter()
+            .map(|(chain, hash)| {
+                Ok(AdjacentParent::new(
+                    chain
+                        .parse()
+                        .map_err(|err| conversion_error(Box::new(err)))?,
+                    decode_base64_json("adjacents", hash)?,
+                ))
+            })
+            .collect::<Result<Vec<_>, TypesError>>()?;
+        adjacents.sort_by_key(|adjacent| adjacent.chain());
+
+        // Headers of custom versions are named after their code
+        let version = ChainwebVersion::try_from(json.chainweb_version.as_str())
+            .map(|version| version.code())
+            .or_else(|err| {
+                json.chainweb_version
+                    .parse()
+                    .map_err(|_| conversion_error(err.into()))
+            })?;
+
+        Ok(Self {
+            flags: json.feature_flags,
+            time: DateTime::from_timestamp_micros(json.creation_time)
+                .ok_or_else(|| conversion_error("Invalid creation time".into()))?,
+            parent: HashValue::new(decode_base64_json("parent", &json.parent)?),
+            adjacents: AdjacentParentRecord::new(adjacents),
+            target: U256::from_little_endian(&decode_base64_json("target", &json.target)?),
+            payload: HashValue::new(decode_base64_json("payloadHash", &json.payload_hash)?),
+            chain: json.chain_id,
+            weight: U256::from_little_endian(&decode_base64_json("weight", &json.weight)?),
+            height: json.height,
+            version,
+            epoch_start: DateTime::from_timestamp_micros(json.epoch_start)
+                .ok_or_else(|| conversion_error("Invalid epoch start".into()))?,
+            nonce: json
+                .nonce
+                .parse()
+                .map_err(|err| conversion_error(Box::new(err)))?,
+            hash: HashValue::new(decode_base64_json("hash", &json.hash)?),
+        })
+    }
+}
+
+impl From<KadenaHeader> for KadenaHeaderJson {
+    fn from(header: KadenaHeader) -> Self {
+        let raw = KadenaHeaderRaw::from(&header);
+
+        // Headers of custom versions are named after their code
+        let chainweb_version = ChainwebVersion::from_code(header.version).map_or_else(
+            |_| header.version.to_string(),
+            |version| version.name().to_string(),
+        );
+
+        Self {
+            nonce: header.nonce.to_string(),
+            creation_time: header.time.timestamp_micros(),
+            parent: URL_SAFE_NO_PAD.encode(raw.parent),
+            adjacents: header
+                .adjacents
+                .adjacents()
+                .iter()
+                .map(|adjacent| {
+                    (
+                        adjacent.chain().to_string(),
+                        URL_SAFE_NO_PAD.encode(adjacent.hash()),
+                    )
+                })
+                .collect(),
+            target: URL_SAFE_NO_PAD.encode(raw.target),
+            payload_hash: URL_SAFE_NO_PAD.encode(raw.payload),
+            chain_id: header.chain,
+            weight: URL_SAFE_NO_PAD.encode(raw.weight),
+            height: header.height,
+            chainweb_version,
+            epoch_start: header.epoch_start.timestamp_micros(),
+            feature_flags: header.flags,
+            hash: URL_SAFE_NO_PAD.encode(raw.hash),
+        }
+    }
+}
+
+/// A compact representation of a Kadena header, made of the properties
+/// that can not be derived from its parent and adjacent parents.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Getters)]
 #[getset(get = "pub")]
 pub struct CompactHeaderRaw {
-    time: [u8; 8],
-    payload: [u8; 32],
-    nonce: [u8; 8],
+    flags: [u8; FLAGS_BYTES_LENGTH],
+    time: [u8; TIME_BYTES_LENGTH],
+    payload: [u8; PAYLOAD_BYTES_LENGTH],
+    nonce: [u8; NONCE_BYTES_LENGTH],
+}
+
+impl From<&KadenaHeaderRaw> for CompactHeaderRaw {
+    fn from(header: &KadenaHeaderRaw) -> Self {
+        Self {
+            flags: header.flags,
+            time: header.time,
+            payload: header.payload,
+            nonce: header.nonce,
+        }
+    }
 }
 
 impl CompactHeaderRaw {
@@ -432,37 +934,71 @@ impl CompactHeaderRaw {
                     source: err.into(),
                 })?;
 
-        if decoded.len() != TIME_BYTES_LENGTH + PAYLOAD_BYTES_LENGTH + NONCE_BYTES_LENGTH {
+        Self::from_bytes(&decoded)
+    }
+
+    /// Creates a new `CompactHeaderRaw` from a slice of bytes.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - A slice of bytes representing the compact header.
+    ///
+    /// # Returns
+    ///
+    /// A new `CompactHeaderRaw` instance.
+    pub fn from_bytes(input: &[u8]) -> Result<Self, TypesError> {
+        if input.len() != COMPACT_HEADER_BYTES_LENGTH {
             return Err(TypesError::InvalidLength {
                 structure: "CompactHeaderRaw".to_string(),
-                expected: TIME_BYTES_LENGTH + PAYLOAD_BYTES_LENGTH + NONCE_BYTES_LENGTH,
-                actual: decoded.len(),
+                expected: COMPACT_HEADER_BYTES_LENGTH,
+                actual: input.len(),
             });
         }
 
         let cursor = 0;
 
+        let (cursor, flags) =
+            extract_fixed_bytes::<FLAGS_BYTES_LENGTH>("CompactHeaderRaw", input, cursor)?;
         let (cursor, time) =
-            extract_fixed_bytes::<TIME_BYTES_LENGTH>("CompactHeaderRaw", &decoded, cursor)?;
+            extract_fixed_bytes::<TIME_BYTES_LENGTH>("CompactHeaderRaw", input, cursor)?;
         let (cursor, payload) =
-            extract_fixed_bytes::<PAYLOAD_BYTES_LENGTH>("CompactHeaderRaw", &decoded, cursor)?;
+            extract_fixed_bytes::<PAYLOAD_BYTES_LENGTH>("CompactHeaderRaw", input, cursor)?;
         let (_, nonce) =
-            extract_fixed_bytes::<NONCE_BYTES_LENGTH>("CompactHeaderRaw", &decoded, cursor)?;
+            extract_fixed_bytes::<NONCE_BYTES_LENGTH>("CompactHeaderRaw", input, cursor)?;
 
         Ok(Self {
+            flags,
             time,
             payload,
             nonce,
         })
     }
+
+    /// Serialize the `CompactHeaderRaw` into a vector of bytes.
+    ///
+    /// # Returns
+    ///
+    /// The bytes of the `CompactHeaderRaw`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(COMPACT_HEADER_BYTES_LENGTH);
+        bytes.extend_from_slice(&self.flags);
+        bytes.extend_from_slice(&self.time);
+        bytes.extend_from_slice(&self.payload);
+        bytes.extend_from_slice(&self.nonce);
+        bytes
+    }
 }
 
 #[cfg(all(test, feature = "kadena"))]
 mod test {
     use crate::crypto::hash::HashValue;
     use crate::crypto::U256;
-    use crate::test_utils::{RAW_HEADER, RAW_HEADER_POW_HASH_HEX};
-    use crate::types::header::chain::{KadenaHeader, KadenaHeaderRaw, RAW_HEADER_BYTES_LEN};
+    use crate::test_utils::{get_layer_block_headers, RAW_HEADER, RAW_HEADER_POW_HASH_HEX};
+    use crate::types::error::ValidationError;
+    use crate::types::graph::TWENTY_CHAIN_GRAPH;
+    use crate::types::header::chain::{
+        CompactHeaderRaw, KadenaHeader, KadenaHeaderRaw, RAW_HEADER_BYTES_LEN,
+    };
     use std::process::Stdio;
     use uint::hex;
 
@@ -477,6 +1013,63 @@ mod test {
         let _ = KadenaHeader::try_from(header_raw).unwrap();
     }
 
+    #[test]
+    fn test_kadena_header_conversion() {
+        let header_raw = KadenaHeaderRaw::from_base64(RAW_HEADER).unwrap();
+        let header = KadenaHeader::try_from(header_raw).unwrap();
+
+        assert_eq!(header.height(), u64::from_le_bytes(header_raw.height));
+        assert_eq!(KadenaHeaderRaw::from(&header), header_raw);
+    }
+
+    #[test]
+    fn test_kadena_header_json() {
+        let header =
+            KadenaHeader::try_from(KadenaHeaderRaw::from_base64(RAW_HEADER).unwrap()).unwrap();
+
+        let json = serde_json::to_value(&header).unwrap();
+        assert_eq!(json["chainwebVersion"], "mainnet01");
+        assert_eq!(json["nonce"], header.nonce().to_string());
+        assert_eq!(json["height"], header.height());
+        assert_eq!(
+            json["adjacents"].as_object().unwrap().len(),
+            header.adjacents().adjacents().len()
+        );
+
+        let deserialized: KadenaHeader = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(deserialized, header);
+
+        let mut invalid = json;
+        invalid["parent"] = "AAAA".into();
+        assert!(serde_json::from_value::<KadenaHeader>(invalid).is_err());
+    }
+
+    #[test]
+    fn test_kadena_header_validation() {
+        let list = get_layer_block_headers();
+        let parent = KadenaHeader::try_from(list[0].chain_headers()[0]).unwrap();
+        let header = KadenaHeader::try_from(list[1].chain_headers()[0]).unwrap();
+
+        header.verify_creation_time(&parent).unwrap();
+        header.verify_weight(&parent).unwrap();
+        list[1].chain_headers()[0]
+            .verify_weight(&list[0].chain_headers()[0])
+            .unwrap();
+
+        assert!(matches!(
+            parent.verify_creation_time(&header),
+            Err(ValidationError::InvalidCreationTime { .. })
+        ));
+        assert!(matches!(
+            parent.verify_weight(&header),
+            Err(ValidationError::InvalidWeight { .. })
+        ));
+        assert!(matches!(
+            list[0].chain_headers()[0].verify_weight(&list[1].chain_headers()[0]),
+            Err(ValidationError::InvalidWeight { .. })
+        ));
+    }
+
     #[test]
     fn test_bytes_conversion_header_raw() {
         let header_raw = KadenaHeaderRaw::from_base64(RAW_HEADER).unwrap();
@@ -494,6 +1087,264 @@ mod test {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn test_verify_difficulty_within_epoch() {
+        use crate::test_utils::get_layer_block_headers;
+        use crate::types::error::ValidationError;
+
+        let layers = get_layer_block_headers();
+        let parent = &layers[0].chain_headers()[0];
+        let header = layers[1].chain_headers()[0];
+        let adjacent_parents = header
+            .verify_adjacent_parents(layers[0].chain_headers(), &TWENTY_CHAIN_GRAPH)
+            .unwrap();
+
+        header.verify_difficulty(parent, &adjacent_parents).unwrap();
+
+        // Within an epoch, a header can not lower its target
+        let mut easy_header = header;
+        easy_header.target = [0xff; 32];
+        assert!(matches!(
+            easy_header.verify_difficulty(parent, &adjacent_parents),
+            Err(ValidationError::InvalidTarget { .. })
+        ));
+
+        // Nor start a new epoch
+        let mut new_epoch_header = header;
+        new_epoch_header.epoch_start = header.time;
+        assert!(matches!(
+            new_epoch_header.verify_difficulty(parent, &adjacent_parents),
+            Err(ValidationError::InvalidEpochStart { .. })
+        ));
+    }
+
+    #[test]
+    fn test_verify_adjacent_parents() {
+        use crate::test_utils::get_layer_block_headers;
+        use crate::types::error::ValidationError;
+        use crate::types::graph::PETERSEN_GRAPH;
+
+        let layers = get_layer_block_headers();
+        let parent_layer = layers[0].chain_headers();
+
+        for header in layers[1].chain_headers() {
+            let adjacent_parents = header
+                .verify_adjacent_parents(parent_layer, &TWENTY_CHAIN_GRAPH)
+                .unwrap();
+            assert_eq!(adjacent_parents.len(), 3);
+        }
+
+        let header = layers[1].chain_headers()[0];
+
+        // Adjacent parent hashes must be the ones of the previous layer
+        let mut tampered_hash = header;
+        tampered_hash.adjacents[10] ^= 1;
+        assert!(matches!(
+            tampered_hash.verify_adjacent_parents(parent_layer, &TWENTY_CHAIN_GRAPH),
+            Err(ValidationError::InvalidAdjacentParentHash {
+                chain: 0,
+                adjacent_chain: 5,
+                ..
+            })
+        ));
+
+        // Adjacent parents must be on the adjacent chains in the chain graph
+        let mut tampered_chain = header;
+        tampered_chain.adjacents[2] = 6;
+        assert!(matches!(
+            tampered_chain.verify_adjacent_parents(parent_layer, &TWENTY_CHAIN_GRAPH),
+            Err(ValidationError::InvalidAdjacentChains { chain: 0, .. })
+        ));
+
+        // Adjacent parents must follow the chain graph in use at the height of the header
+        assert!(matches!(
+            header.verify_adjacent_parents(parent_layer, &PETERSEN_GRAPH),
+            Err(ValidationError::InvalidAdjacentChains { chain: 0, .. })
+        ));
+
+        // Adjacent parents must be in the previous layer
+        assert!(matches!(
+            header.verify_adjacent_parents(&parent_layer[..3], &TWENTY_CHAIN_GRAPH),
+            Err(ValidationError::MissingAdjacentParentHeader { chain: 5 })
+        ));
+    }
+
+    #[test]
+    fn test_from_compact() {
+        let layers = get_layer_block_headers();
+        let parent_layer = layers[0].chain_headers();
+
+        // Mainnet headers are reconstructed with their committed hash
+        for (header, parent) in layers[1].chain_headers().iter().zip(parent_layer) {
+            let reconstructed = KadenaHeaderRaw::from_compact(
+                &CompactHeaderRaw::from(header),
+                parent,
+                parent_layer,
+                &TWENTY_CHAIN_GRAPH,
+                Some(&header.hash),
+            )
+            .unwrap();
+            assert_eq!(reconstructed.hash, header.hash);
+            assert_eq!(&reconstructed, header);
+        }
+
+        let header = layers[1].chain_headers()[0];
+        let compact = CompactHeaderRaw::from(&header);
+
+        // The reconstructed hash must be the committed one
+        let mut tampered_hash = header.hash;
+        tampered_hash[0] ^= 1;
+        assert!(matches!(
+            KadenaHeaderRaw::from_compact(
+                &compact,
+                &parent_layer[0],
+                parent_layer,
+                &TWENTY_CHAIN_GRAPH,
+                Some(&tampered_hash),
+            ),
+            Err(ValidationError::InvalidChainBlockHash { .. })
+        ));
+
+        // Feature flags are carried by the compact header
+        let mut flagged = header;
+        flagged.flags[0] = 1;
+        flagged.hash = *flagged.header_root().unwrap().as_ref();
+        assert_eq!(
+            KadenaHeaderRaw::from_compact(
+                &CompactHeaderRaw::from(&flagged),
+                &parent_layer[0],
+                parent_layer,
+                &TWENTY_CHAIN_GRAPH,
+                Some(&flagged.hash),
+            )
+            .unwrap(),
+            flagged
+        );
+
+        // Adjacent parents must be in the previous layer
+        assert!(matches!(
+            KadenaHeaderRaw::from_compact(
+                &compact,
+                &parent_layer[0],
+                &parent_layer[..3],
+                &TWENTY_CHAIN_GRAPH,
+                Some(&header.hash),
+            ),
+            Err(ValidationError::MissingAdjacentParentHeader { chain: 5 })
+        ));
+    }
+
+    #[test]
+    fn test_verify_difficulty_new_epoch() {
+        use crate::types::header::difficulty::{BLOCK_DELAY_MICROS, WINDOW_WIDTH};
+        use crate::types::header::layer::ChainwebLayerHeader;
+        use crate::types::version::{ChainwebVersion, DEVELOPMENT_CODE};
+
+        let epoch_duration = WINDOW_WIDTH * BLOCK_DELAY_MICROS;
+        let last_time = 1_725_448_982_198_534_u64;
+
+        // Mine a header, the targets being high enough for a few attempts to succeed
+        let mine = |mut header: KadenaHeaderRaw| {
+            while header.produced_work().is_err() {
+                header.nonce = (u64::from_le_bytes(header.nonce) + 1).to_le_bytes();
+            }
+            header.hash = *header.header_root().unwrap().as_ref();
+            header
+        };
+
+        // Chain 0 took the expected time since the start of its epoch, while the
+        // other chains started their epoch one more epoch duration earlier
+        let base = (0..TWENTY_CHAIN_GRAPH.len() as u32)
+            .map(|chain| {
+                let mut header = KadenaHeaderRaw {
+                    chain: chain.to_le_bytes(),
+                    height: (WINDOW_WIDTH - 2).to_le_bytes(),
+                    version: DEVELOPMENT_CODE.to_le_bytes(),
+                    time: (last_time - BLOCK_DELAY_MICROS).to_le_bytes(),
+                    epoch_start: (last_time - epoch_duration * (1 + u64::from(chain != 0)))
+                        .to_le_bytes(),
+                    ..Default::default()
+                };
+                (U256::from(chain + 1) << 244).to_little_endian(&mut header.target);
+                mine(header)
+            })
+            .collect::<Vec<_>>();
+
+        // The following layers derive from their parent layer as reconstructed from
+        // their compact form
+        let next_layer = |parent_layer: &[KadenaHeaderRaw], time: u64| {
+            parent_layer
+                .iter()
+                .map(|parent| {
+                    let mut compact = CompactHeaderRaw::from(parent);
+                    compact.time = time.to_le_bytes();
+                    mine(
+                        KadenaHeaderRaw::from_compact(
+                            &compact,
+                            parent,
+                            parent_layer,
+                            &TWENTY_CHAIN_GRAPH,
+                            None,
+                        )
+                        .unwrap(),
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+        let last = next_layer(&base, last_time);
+        let first = next_layer(&last, last_time + BLOCK_DELAY_MICROS);
+
+        let parent = &last[0];
+        let header = first[0];
+        let adjacent_parents = header
+            .verify_adjacent_parents(&last, &TWENTY_CHAIN_GRAPH)
+            .unwrap();
+        header.verify_difficulty(parent, &adjacent_parents).unwrap();
+
+        // The new epoch starts at the creation time of the parent
+        assert_eq!(u64::from_le_bytes(header.epoch_start), last_time);
+
+        // The target of chain 0 is kept, and the ones of its adjacent chains 5, 10 and 15
+        // are doubled, so the new target is (1 + 2 * 6 + 2 * 11 + 2 * 16) / 4 * 2^244
+        assert_eq!(
+            U256::from_little_endian(&header.target),
+            U256::from(67) << 242
+        );
+
+        // The target of the parent alone is not the one of the new epoch
+        let mut parent_target_header = header;
+        parent_target_header.target = parent.target;
+        assert!(matches!(
+            parent_target_header.verify_difficulty(parent, &adjacent_parents),
+            Err(ValidationError::InvalidTarget { .. })
+        ));
+        assert!(matches!(
+            header.verify_difficulty(parent, &[]),
+            Err(ValidationError::InvalidTarget { .. })
+        ));
+
+        // The new epoch does not start at the creation time of its first block
+        let mut child_time_header = header;
+        child_time_header.epoch_start = header.time;
+        assert!(matches!(
+            child_time_header.verify_difficulty(parent, &adjacent_parents),
+            Err(ValidationError::InvalidEpochStart {
+                expected: 1_725_448_982_198_534,
+                ..
+            })
+        ));
+
+        // The whole window across the epoch boundary is valid
+        let list = [
+            ChainwebLayerHeader::new(WINDOW_WIDTH - 2, base).unwrap(),
+            ChainwebLayerHeader::new(WINDOW_WIDTH - 1, last).unwrap(),
+            ChainwebLayerHeader::new(WINDOW_WIDTH, first).unwrap(),
+        ];
+        assert!(
+            ChainwebLayerHeader::verify_with_version(&list, &ChainwebVersion::Development).is_ok()
+        );
+    }
+
     #[test]
     fn test_multiple_header_root_computing() {
         use crate::test_utils::TESTNET_CHAIN_3_HEADERS_URL;
@@ -0,0 +1,348 @@
+// Copyright (c) Argument Computer Corporation
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Compact Layer Headers Module
+//!
+//! This module provides a compact representation of consecutive layers of
+//! the Chainweb network. Only the first layer is kept in full, as a base.
+//! The headers of the following layers are reduced to their creation time,
+//! payload and nonce, their other properties following from the layer
+//! before them. The hashes of the headers of the last layer are kept to
+//! check the reconstructed headers against.
+
+use crate::crypto::hash::DIGEST_BYTES_LENGTH;
+use crate::types::error::{TypesError, ValidationError};
+use crate::types::header::chain::{CompactHeaderRaw, KadenaHeaderRaw, COMPACT_HEADER_BYTES_LENGTH};
+use crate::types::header::layer::ChainwebLayerHeader;
+use crate::types::utils::extract_fixed_bytes;
+use crate::types::version::ChainwebVersion;
+use crate::types::{U16_BYTES_LENGTH, U64_BYTES_LENGTH};
+use getset::Getters;
+
+/// Consecutive layers of the Chainweb network, with all layers but the
+/// first one made of compact headers.
+#[derive(Debug, Clone, Eq, PartialEq, Getters)]
+#[getset(get = "pub")]
+pub struct CompactLayerHeaders {
+    /// The first layer, in full.
+    base: ChainwebLayerHeader,
+    /// The compact headers of the following layers, indexed by chain.
+    layers: Vec<Vec<CompactHeaderRaw>>,
+    /// The hashes of the headers of the last layer, indexed by chain.
+    hashes: Vec<[u8; DIGEST_BYTES_LENGTH]>,
+}
+
+impl CompactLayerHeaders {
+    /// Create a new `CompactLayerHeaders`.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The first layer, in full.
+    /// * `layers` - The compact headers of the following layers, indexed by chain.
+    /// * `hashes` - The hashes of the headers of the last layer, indexed by chain.
+    ///
+    /// # Returns
+    ///
+    /// A new `CompactLayerHeaders`.
+    pub const fn new(
+        base: ChainwebLayerHeader,
+        layers: Vec<Vec<CompactHeaderRaw>>,
+        hashes: Vec<[u8; DIGEST_BYTES_LENGTH]>,
+    ) -> Self {
+        Self {
+            base,
+            layers,
+            hashes,
+        }
+    }
+
+    /// Create the `CompactLayerHeaders` of a list of consecutive layers.
+    ///
+    /// # Arguments
+    ///
+    /// * `list` - The list of layer headers.
+    ///
+    /// # Returns
+    ///
+    /// The `CompactLayerHeaders` of the list, or an error if it is empty.
+    pub fn from_layer_headers(list: &[ChainwebLayerHeader]) -> Result<Self, ValidationError> {
+        let (base, layers) = list
+            .split_first()
+            .ok_or(ValidationError::InvalidLayerBlockHeadersList { size: list.len() })?;
+
+        Ok(Self {
+            base: base.clone(),
+            layers: layers
+                .iter()
+                .map(|layer_header| {
+                    layer_header
+                        .chain_headers()
+                        .iter()
+                        .map(CompactHeaderRaw::from)
+                        .collect()
+                })
+                .collect(),
+            hashes: list
+                .last()
+                .expect("Should have at least the base layer")
+                .chain_headers()
+                .iter()
+                .map(|chain_header| *chain_header.hash())
+                .collect(),
+        })
+    }
+
+    /// Reconstruct the full layers from the base layer and the compact headers.
+    /// The headers of the last layer must have the committed hashes, which
+    /// binds all the reconstructed headers, as they are their ancestors.
+    ///
+    /// # Returns
+    ///
+    /// The list of layer headers, starting with the base layer, or an error
+    /// if a layer does not contain the chains of the Chainweb version at its
+    /// height or if a reconstructed header does not have the committed hash.
+    pub fn to_layer_headers(&self) -> Result<Vec<ChainwebLayerHeader>, ValidationError> {
+        let code = self
+            .base
+            .chain_headers()
+            .first()
+            .map(|chain_header| u32::from_le_bytes(*chain_header.version()))
+            .ok_or(ValidationError::InvalidChainBlockHeadersList)?;
+        let version = ChainwebVersion::from_code(code)?;
+
+        let mut list = Vec::with_capacity(1 + self.layers.len());
+        list.push(self.base.clone());
+
+        for (position, compact_layer) in self.layers.iter().enumerate() {
+            let parent_layer = list.last().expect("Should have at least the base layer");
+            let height = parent_layer.height() + 1;
+
+            // Each layer contains the same chains as its parent layer
+            if compact_layer.len() != parent_layer.chain_headers().len() {
+                return Err(ValidationError::InvalidLayerChains {
+                    height,
+                    expected: parent_layer.chain_headers().len(),
+                    actual: compact_layer.len(),
+                });
+            }
+
+            // Only the last layer has committed hashes
+            let hashes = if position == self.layers.len() - 1 {
+                if self.hashes.len() != compact_layer.len() {
+                    return Err(ValidationError::InvalidLayerChains {
+                        height,
+                        expected: compact_layer.len(),
+                        actual: self.hashes.len(),
+                    });
+                }
+                self.hashes.iter().map(Some).collect()
+            } else {
+                vec![None; compact_layer.len()]
+            };
+
+            let chain_graph = version.chain_graph(*parent_layer.height());
+            let chain_headers = compact_layer
+                .iter()
+                .zip(parent_layer.chain_headers())
+                .zip(hashes)
+                .map(|((compact, parent), hash)| {
+                    KadenaHeaderRaw::from_compact(
+                        compact,
+                        parent,
+                        parent_layer.chain_headers(),
+                        chain_graph,
+                        hash,
+                    )
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            list.push(ChainwebLayerHeader::new(height, chain_headers)?);
+        }
+
+        Ok(list)
+    }
+
+    /// Serialize the `CompactLayerHeaders` into a vector of bytes.
+    ///
+    /// # Returns
+    ///
+    /// The bytes of the `CompactLayerHeaders`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+
+        let base_bytes = self.base.to_bytes();
+        bytes.extend_from_slice(&(base_bytes.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(&base_bytes);
+
+        bytes.extend_from_slice(&(self.layers.len() as u16).to_le_bytes());
+        for compact_layer in &self.layers {
+            bytes.extend_from_slice(&(compact_layer.len() as u16).to_le_bytes());
+            for compact in compact_layer {
+                bytes.extend_from_slice(&compact.to_bytes());
+            }
+        }
+
+        bytes.extend_from_slice(&(self.hashes.len() as u16).to_le_bytes());
+        for hash in &self.hashes {
+            bytes.extend_from_slice(hash);
+        }
+
+        bytes
+    }
+
+    /// Deserialize a `CompactLayerHeaders` from a slice of bytes.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The slice of bytes to deserialize.
+    ///
+    /// # Returns
+    ///
+    /// The `CompactLayerHeaders` deserialized from the bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, TypesError> {
+        let (cursor, base_len) =
+            extract_fixed_bytes::<U16_BYTES_LENGTH>("CompactLayerHeaders", bytes, 0)?;
+        let base_len = u16::from_le_bytes(base_len) as usize;
+
+        // The base layer contains at least its height and number of chain headers
+        if base_len < U64_BYTES_LENGTH + U16_BYTES_LENGTH || bytes.len() < cursor + base_len {
+            return Err(TypesError::UnderLength {
+                structure: "CompactLayerHeaders".into(),
+                minimum: cursor + base_len.max(U64_BYTES_LENGTH + U16_BYTES_LENGTH),
+                actual: bytes.len(),
+            });
+        }
+        let base = ChainwebLayerHeader::from_bytes(&bytes[cursor..cursor + base_len])?;
+
+        let (mut cursor, layers_len) = extract_fixed_bytes::<U16_BYTES_LENGTH>(
+            "CompactLayerHeaders",
+            bytes,
+            cursor + base_len,
+        )?;
+        let layers_len = u16::from_le_bytes(layers_len) as usize;
+
+        let mut layers = Vec::with_capacity(layers_len);
+        for _ in 0..layers_len {
+            let (next, chains_len) =
+                extract_fixed_bytes::<U16_BYTES_LENGTH>("CompactLayerHeaders", bytes, cursor)?;
+            let chains_len = u16::from_le_bytes(chains_len) as usize;
+
+            let end = next + chains_len * COMPACT_HEADER_BYTES_LENGTH;
+            if bytes.len() < end {
+                return Err(TypesError::UnderLength {
+                    structure: "CompactLayerHeaders".into(),
+                    minimum: end,
+                    actual: bytes.len(),
+                });
+            }
+
+            layers.push(
+                bytes[next..end]
+                    .chunks_exact(COMPACT_HEADER_BYTES_LENGTH)
+                    .map(CompactHeaderRaw::from_bytes)
+                    .collect::<Result<Vec<_>, _>>()?,
+            );
+            cursor = end;
+        }
+
+        let (mut cursor, hashes_len) =
+            extract_fixed_bytes::<U16_BYTES_LENGTH>("CompactLayerHeaders", bytes, cursor)?;
+        let hashes_len = u16::from_le_bytes(hashes_len) as usize;
+
+        let mut hashes = Vec::with_capacity(hashes_len);
+        for _ in 0..hashes_len {
+            let (next, hash) =
+                extract_fixed_bytes::<DIGEST_BYTES_LENGTH>("CompactLayerHeaders", bytes, cursor)?;
+            hashes.push(hash);
+            cursor = next;
+        }
+
+        if bytes.len() != cursor {
+            return Err(TypesError::OverLength {
+                structure: "CompactLayerHeaders".into(),
+                maximum: cursor,
+                actual: bytes.len(),
+            });
+        }
+
+        Ok(Self {
+            base,
+            layers,
+            hashes,
+        })
+    }
+}
+
+#[cfg(all(test, feature = "kadena"))]
+mod test {
+    use super::*;
+    use crate::test_utils::{get_compact_layer_headers, get_layer_block_headers};
+
+    #[test]
+    fn test_compact_layer_headers() {
+        let list = get_layer_block_headers();
+
+        let compact = get_compact_layer_headers();
+        assert_eq!(
+            CompactLayerHeaders::from_layer_headers(&list).unwrap(),
+            compact
+        );
+        assert_eq!(compact.layers().len(), list.len() - 1);
+
+        // Mainnet headers are reconstructed from their compact form to the exact same
+        // bytes and hashes
+        let reconstructed = compact.to_layer_headers().unwrap();
+        for (layer, expected_layer) in reconstructed.iter().zip(&list) {
+            for (header, expected) in layer
+                .chain_headers()
+                .iter()
+                .zip(expected_layer.chain_headers())
+            {
+                assert_eq!(header.hash(), expected.hash());
+                assert_eq!(header.header_root().unwrap().as_ref(), expected.hash());
+            }
+        }
+        assert_eq!(reconstructed, list);
+
+        let bytes = compact.to_bytes();
+        assert!(bytes.len() < ChainwebLayerHeader::serialize_list(&list).len() / 3);
+        assert_eq!(CompactLayerHeaders::from_bytes(&bytes).unwrap(), compact);
+        assert!(CompactLayerHeaders::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+
+        // A layer must contain all the chains of its parent layer
+        let mut layers = compact.layers().clone();
+        layers[0].pop();
+        assert!(matches!(
+            CompactLayerHeaders::new(compact.base().clone(), layers, compact.hashes().clone())
+                .to_layer_headers(),
+            Err(ValidationError::InvalidLayerChains { .. })
+        ));
+
+        // Altered compact headers are rejected, even in an intermediate layer
+        let mut layers = compact.layers().clone();
+        layers[0][0] = layers[1][0];
+        assert!(matches!(
+            CompactLayerHeaders::new(compact.base().clone(), layers, compact.hashes().clone())
+                .to_layer_headers(),
+            Err(ValidationError::InvalidChainBlockHash { .. })
+        ));
+
+        // The headers of the last layer must have the committed hashes
+        let mut hashes = compact.hashes().clone();
+        hashes[0][0] ^= 1;
+        assert!(matches!(
+            CompactLayerHeaders::new(compact.base().clone(), compact.layers().clone(), hashes)
+                .to_layer_headers(),
+            Err(ValidationError::InvalidChainBlockHash { .. })
+        ));
+        let mut hashes = compact.hashes().clone();
+        hashes.pop();
+        assert!(matches!(
+            CompactLayerHeaders::new(compact.base().clone(), compact.layers().clone(), hashes)
+                .to_layer_headers(),
+            Err(ValidationError::InvalidLayerChains { .. })
+        ));
+
+        assert!(CompactLayerHeaders::from_layer_headers(&[]).is_err());
+    }
+}
@@ -0,0 +1,179 @@
+// Copyright (c) Argument Computer Corporation
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Difficulty Adjustment Module
+//!
+//! This module implements the difficulty adjustment (DA) rules of
+//! Chainweb, which define the `target` and `epoch_start` a block header
+//! must have given its parent.
+//!
+//! Blocks are grouped in epochs of `WINDOW_WIDTH` blocks. Within an
+//! epoch, a block inherits the target and epoch start of its parent. The
+//! first block of a new epoch starts a new epoch at the creation time of
+//! its parent, the last block of the previous epoch. Its target is the
+//! average of the targets of its parent and of its adjacent parents, each
+//! adjusted by the ratio between the time elapsed from the start of its
+//! epoch to the creation of the parent and the expected duration of an
+//! epoch.
+//!
+//! These are the rules of the fixed DA of `chainweb-node`, which replaced
+//! the legacy DA that started a new epoch at the creation time of its
+//! first block and only adjusted the target of the parent.
+
+use crate::crypto::{U256, U256_BYTES_LENGTH, U512};
+use crate::types::header::chain::KadenaHeaderRaw;
+
+/// Number of blocks in a difficulty adjustment epoch.
+pub const WINDOW_WIDTH: u64 = 120;
+
+/// Expected delay between two blocks on a chain, in microseconds.
+pub const BLOCK_DELAY_MICROS: u64 = 30_000_000;
+
+/// Checks if a block at the given height is the last one of its epoch,
+/// meaning that its child starts a new epoch with an adjusted target.
+///
+/// # Arguments
+///
+/// * `height` - The height of the block.
+///
+/// # Returns
+///
+/// `true` if the block is the last of its epoch, `false` otherwise.
+pub const fn is_last_in_epoch(height: u64) -> bool {
+    (height + 1) % WINDOW_WIDTH == 0
+}
+
+/// Adjusts a target by the ratio between the time an epoch took and its
+/// expected duration, rounding up. The result is capped to the maximum
+/// target.
+///
+/// # Arguments
+///
+/// * `target` - The target of the epoch.
+/// * `elapsed_micros` - The time the epoch took, in microseconds.
+///
+/// # Returns
+///
+/// The adjusted target.
+pub fn adjust(target: U256, elapsed_micros: u64) -> U256 {
+    let mut target_bytes = [0; U256_BYTES_LENGTH];
+    target.to_little_endian(&mut target_bytes);
+
+    let numerator = U512::from_little_endian(&target_bytes) * U512::from(elapsed_micros);
+    let denominator = U512::from(WINDOW_WIDTH * BLOCK_DELAY_MICROS);
+    let adjusted = (numerator + denominator - 1) / denominator;
+
+    if adjusted > U512::from_little_endian(&[u8::MAX; U256_BYTES_LENGTH]) {
+        return U256::MAX;
+    }
+
+    let mut adjusted_bytes = [0; 2 * U256_BYTES_LENGTH];
+    adjusted.to_little_endian(&mut adjusted_bytes);
+    U256::from_little_endian(&adjusted_bytes[..U256_BYTES_LENGTH])
+}
+
+/// Computes the difficulty of a target, which is the weight that a block
+/// mined with this target adds to its chain.
+///
+/// # Arguments
+///
+/// * `target` - The target of the block.
+///
+/// # Returns
+///
+/// The difficulty of the target.
+pub fn difficulty(target: U256) -> U256 {
+    U256::MAX / target.max(U256::one())
+}
+
+/// Computes the epoch start that a block header must have. A new epoch
+/// starts at the creation time of the last block of the previous epoch.
+///
+/// # Arguments
+///
+/// * `parent` - The parent of the block header.
+///
+/// # Returns
+///
+/// The expected epoch start of the block header, in microseconds.
+pub fn expected_epoch_start(parent: &KadenaHeaderRaw) -> u64 {
+    if is_last_in_epoch(u64::from_le_bytes(*parent.height())) {
+        u64::from_le_bytes(*parent.time())
+    } else {
+        u64::from_le_bytes(*parent.epoch_start())
+    }
+}
+
+/// Computes the target that a block header must have.
+///
+/// The target of a new epoch is the average of the targets of the parent
+/// and of the adjacent parents of the block header, each adjusted by the
+/// time elapsed between the start of its epoch and the creation of the
+/// parent.
+///
+/// # Arguments
+///
+/// * `parent` - The parent of the block header.
+/// * `adjacent_parents` - The adjacent parents of the block header.
+///
+/// # Returns
+///
+/// The expected target of the block header.
+pub fn expected_target(parent: &KadenaHeaderRaw, adjacent_parents: &[&KadenaHeaderRaw]) -> U256 {
+    if !is_last_in_epoch(u64::from_le_bytes(*parent.height())) {
+        return U256::from_little_endian(parent.target());
+    }
+
+    let parent_time = u64::from_le_bytes(*parent.time());
+    let headers = std::iter::once(parent).chain(adjacent_parents.iter().copied());
+
+    // The sum of the adjusted targets can exceed 256 bits
+    let mut sum = U512::zero();
+    for header in headers.clone() {
+        let elapsed_micros = parent_time.saturating_sub(u64::from_le_bytes(*header.epoch_start()));
+        let mut adjusted_bytes = [0; U256_BYTES_LENGTH];
+        adjust(U256::from_little_endian(header.target()), elapsed_micros)
+            .to_little_endian(&mut adjusted_bytes);
+        sum += U512::from_little_endian(&adjusted_bytes);
+    }
+    let average = sum / U512::from(headers.count());
+
+    let mut average_bytes = [0; 2 * U256_BYTES_LENGTH];
+    average.to_little_endian(&mut average_bytes);
+    U256::from_little_endian(&average_bytes[..U256_BYTES_LENGTH])
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_adjust() {
+        let target = U256::from(1_000_000_u64);
+        let epoch_duration = WINDOW_WIDTH * BLOCK_DELAY_MICROS;
+
+        // An epoch that took the expected time keeps its target
+        assert_eq!(adjust(target, epoch_duration), target);
+        // A slower epoch raises the target, making blocks easier to mine
+        assert_eq!(adjust(target, 2 * epoch_duration), target * 2);
+        // A faster epoch lowers the target, rounding up
+        assert_eq!(adjust(target, epoch_duration / 3), U256::from(333_334_u64));
+        // The target can not exceed the maximum target
+        assert_eq!(adjust(U256::MAX, 2 * epoch_duration), U256::MAX);
+    }
+
+    #[test]
+    fn test_difficulty() {
+        assert_eq!(difficulty(U256::MAX), U256::one());
+        assert_eq!(difficulty(U256::MAX / 4), U256::from(4));
+        assert_eq!(difficulty(U256::zero()), U256::MAX);
+    }
+
+    #[test]
+    fn test_is_last_in_epoch() {
+        assert!(is_last_in_epoch(WINDOW_WIDTH - 1));
+        assert!(is_last_in_epoch(5_099_399));
+        assert!(!is_last_in_epoch(WINDOW_WIDTH));
+        assert!(!is_last_in_epoch(5_099_342));
+    }
+}
@@ -7,6 +7,7 @@ use crate::crypto::hash::HashValue;
 use crate::crypto::U256;
 use crate::types::error::{TypesError, ValidationError};
 use crate::types::header::chain::{KadenaHeaderRaw, RAW_HEADER_DECODED_BYTES_LENGTH};
+use crate::types::version::ChainwebVersion;
 use crate::types::{U16_BYTES_LENGTH, U64_BYTES_LENGTH};
 use anyhow::Result;
 use getset::Getters;
@@ -56,6 +57,18 @@ impl ChainwebLayerHeader {
             })
     }
 
+    /// Get the total weight of the layer, that is the sum of the weights of
+    /// all the chains in the layer.
+    ///
+    /// # Returns
+    ///
+    /// The total weight of the layer.
+    pub fn weight(&self) -> U256 {
+        self.chain_headers.iter().fold(U256::zero(), |acc, header| {
+            acc.saturating_add(U256::from_little_endian(header.weight()))
+        })
+    }
+
     /// Serialized a `ChainwebLayerHeader` into a byte vector.
     ///
     /// # Returns
@@ -244,7 +257,58 @@ impl ChainwebLayerHeader {
         Ok(hashes[0])
     }
 
-    pub fn verify(list: &[Self]) -> Result<(HashValue, HashValue, U256), ValidationError> {
+    /// Verify a list of layer headers of one of the known Chainweb versions, identified
+    /// by the version code stored in the headers. See [`ChainwebLayerHeader::verify_with_version`].
+    ///
+    /// This is the verification run by the programs, so headers of a custom version are
+    /// rejected with [`ValidationError::UnsupportedChainwebVersion`]: their specification
+    /// is not part of the headers, see [`ChainwebVersion::is_provable`].
+    ///
+    /// # Arguments
+    ///
+    /// * `list` - The list of layer headers, with the target layer at its center.
+    ///
+    /// # Returns
+    ///
+    /// The root of the first layer, the root of the target layer, the work produced
+    /// to confirm the target layer and the total weight of the target layer.
+    pub fn verify(list: &[Self]) -> Result<(HashValue, HashValue, U256, U256), ValidationError> {
+        let code = list
+            .first()
+            .and_then(|layer_header| layer_header.chain_headers().first())
+            .map(|chain_header| u32::from_le_bytes(*chain_header.version()))
+            .ok_or(ValidationError::InvalidLayerBlockHeadersList { size: list.len() })?;
+
+        Self::verify_with_version(list, &ChainwebVersion::from_code(code)?)
+    }
+
+    /// Verify a list of layer headers, checking that each layer contains exactly the chains
+    /// of the Chainweb version at its height and for each chain header its version, height,
+    /// hash, proof of work and parent. Headers with a parent in the list are also checked to
+    /// be braided with their adjacent parents, to follow the difficulty adjustment rules,
+    /// so that their target can not be lowered, and to extend the weight of their parent
+    /// by the difficulty of their target.
+    ///
+    /// Lists that would verify headers across a chain graph transition are rejected: at the
+    /// transition height the new chains start with genesis blocks that have no parent in
+    /// the list, and the children of the first blocks after their genesis start a new
+    /// difficulty epoch regardless of their height. The first layer of a list is not
+    /// verified against a parent, so it is the only layer of a list that can be at the
+    /// transition height or two blocks after it.
+    ///
+    /// # Arguments
+    ///
+    /// * `list` - The list of layer headers, with the target layer at its center.
+    /// * `version` - The Chainweb version of the network.
+    ///
+    /// # Returns
+    ///
+    /// The root of the first layer, the root of the target layer, the work produced
+    /// to confirm the target layer and the total weight of the target layer.
+    pub fn verify_with_version(
+        list: &[Self],
+        version: &ChainwebVersion,
+    ) -> Result<(HashValue, HashValue, U256, U256), ValidationError> {
         // Ensure input list is valid
         if list.len() < 3 || list.len() % 2 != 1 {
             return Err(ValidationError::InvalidLayerBlockHeadersList { size: list.len() });
@@ -253,9 +317,48 @@ impl ChainwebLayerHeader {
         // Target block has central position in the list of headers
         let target_block_idx = list.len() / 2;
 
+        // Headers at a chain graph transition, or two blocks after it, can not be verified
+        // against their parent layer, as the new chains have no parent or restart their epoch
+        for layer_header in &list[1..] {
+            let height = *layer_header.height();
+            if let Some(transition_height) = [height, height.saturating_sub(2)]
+                .into_iter()
+                .find(|&transition_height| version.is_graph_transition(transition_height))
+            {
+                return Err(ValidationError::UnsupportedChainGraphTransition {
+                    height,
+                    transition_height,
+                });
+            }
+        }
+
         let mut confirmation_work = U256::zero();
         for (i, layer_header) in list.iter().enumerate() {
+            // Ensure the layer contains exactly the chains of the network at its height
+            let chain_graph = version.chain_graph(*layer_header.height());
+            if layer_header.chain_headers().len() != chain_graph.len() {
+                return Err(ValidationError::InvalidLayerChains {
+                    height: *layer_header.height(),
+                    expected: chain_graph.len(),
+                    actual: layer_header.chain_headers().len(),
+                });
+            }
+
             for (j, chain_header) in layer_header.chain_headers().iter().enumerate() {
+                if u32::from_le_bytes(*chain_header.chain()) as usize != j {
+                    return Err(ValidationError::NonValidChain {
+                        size: chain_graph.len(),
+                        chain: u32::from_le_bytes(*chain_header.chain()) as usize,
+                    });
+                }
+
+                if u32::from_le_bytes(*chain_header.version()) != version.code() {
+                    return Err(ValidationError::InvalidChainwebVersion {
+                        expected: version.code(),
+                        actual: u32::from_le_bytes(*chain_header.version()),
+                    });
+                }
+
                 if u64::from_le_bytes(*chain_header.height()) != *layer_header.height() {
                     return Err(ValidationError::InvalidChainBlockHeight {
                         chain_height: u64::from_le_bytes(*chain_header.height()),
@@ -288,6 +391,19 @@ impl ChainwebLayerHeader {
                             stored: HashValue::new(*parent_chain_header.hash()),
                         });
                     }
+
+                    // Check that the header is braided with its adjacent parents, following
+                    // the chain graph at the height of its parent
+                    let adjacent_parents = chain_header.verify_adjacent_parents(
+                        previous_layer.chain_headers(),
+                        version.chain_graph(*previous_layer.height()),
+                    )?;
+
+                    // Check that the target follows the difficulty adjustment rules
+                    chain_header.verify_difficulty(parent_chain_header, &adjacent_parents)?;
+
+                    // Check that the weight accumulates the difficulty of the chain
+                    chain_header.verify_weight(parent_chain_header)?;
                 }
             }
 
@@ -308,7 +424,12 @@ impl ChainwebLayerHeader {
             .header_root()
             .map_err(|err| ValidationError::HashError { source: err.into() })?;
 
-        Ok((first_header_root, target_header_root, confirmation_work))
+        Ok((
+            first_header_root,
+            target_header_root,
+            confirmation_work,
+            list[target_block_idx].weight(),
+        ))
     }
 }
 
@@ -347,7 +468,7 @@ mod test {
     fn test_verify_layer_block_header_list_no_panic() {
         let headers = get_layer_block_headers();
 
-        let (first_hash, target_hash, confirmation_work) =
+        let (first_hash, target_hash, confirmation_work, target_weight) =
             ChainwebLayerHeader::verify(&headers).unwrap();
 
         assert_eq!(first_hash, headers[0].header_root().unwrap());
@@ -362,6 +483,103 @@ mod test {
             )
             .unwrap()
         );
+        assert_eq!(target_weight, headers[headers.len() / 2].weight());
+        assert!(target_weight > headers[0].weight());
+    }
+
+    #[test]
+    fn test_verify_layer_block_header_list_version() {
+        let headers = get_layer_block_headers();
+
+        assert!(
+            ChainwebLayerHeader::verify_with_version(&headers, &ChainwebVersion::Mainnet01).is_ok()
+        );
+
+        // The headers must be of the expected version
+        assert!(matches!(
+            ChainwebLayerHeader::verify_with_version(&headers, &ChainwebVersion::Testnet04),
+            Err(ValidationError::InvalidChainwebVersion { .. })
+        ));
+
+        // Each layer must contain all the chains of the version at its height
+        let mut missing_chain = headers.clone();
+        let middle = missing_chain.len() / 2;
+        let mut chain_headers = missing_chain[middle].chain_headers().clone();
+        chain_headers.pop();
+        missing_chain[middle] =
+            ChainwebLayerHeader::new(*missing_chain[middle].height(), chain_headers).unwrap();
+        assert!(matches!(
+            ChainwebLayerHeader::verify(&missing_chain),
+            Err(ValidationError::InvalidLayerChains {
+                expected: 20,
+                actual: 19,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_verify_layer_block_header_list_graph_transition() {
+        use crate::types::graph::{PETERSEN_GRAPH, TWENTY_CHAIN_GRAPH};
+        use crate::types::version::{
+            ChainwebVersionSpec, MAINNET01_CODE, MAINNET01_TWENTY_CHAIN_HEIGHT,
+        };
+        use std::borrow::Cow;
+
+        // The new chains of mainnet have no parent at the transition height
+        let transition = (MAINNET01_TWENTY_CHAIN_HEIGHT - 1..=MAINNET01_TWENTY_CHAIN_HEIGHT + 1)
+            .map(|height| {
+                let chain_count = ChainwebVersion::Mainnet01.chain_count(height);
+                ChainwebLayerHeader::new(height, vec![KadenaHeaderRaw::default(); chain_count])
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+        assert!(matches!(
+            ChainwebLayerHeader::verify_with_version(&transition, &ChainwebVersion::Mainnet01),
+            Err(ValidationError::UnsupportedChainGraphTransition {
+                height: MAINNET01_TWENTY_CHAIN_HEIGHT,
+                transition_height: MAINNET01_TWENTY_CHAIN_HEIGHT,
+            })
+        ));
+
+        let headers = get_layer_block_headers();
+        let first_height = *headers[0].height();
+        let version_with_transition_at = |transition_height| {
+            ChainwebVersion::Custom(
+                ChainwebVersionSpec::new(
+                    "mainnet01",
+                    MAINNET01_CODE,
+                    vec![
+                        (0, Cow::Borrowed(&PETERSEN_GRAPH[..])),
+                        (transition_height, Cow::Borrowed(&TWENTY_CHAIN_GRAPH[..])),
+                    ],
+                )
+                .unwrap(),
+            )
+        };
+
+        // Only the first layer can be two blocks after the transition height
+        assert!(ChainwebLayerHeader::verify_with_version(
+            &headers,
+            &version_with_transition_at(first_height - 2)
+        )
+        .is_ok());
+        assert!(matches!(
+            ChainwebLayerHeader::verify_with_version(
+                &headers,
+                &version_with_transition_at(first_height - 1)
+            ),
+            Err(ValidationError::UnsupportedChainGraphTransition { height, .. })
+                if height == first_height + 1
+        ));
+        assert!(matches!(
+            ChainwebLayerHeader::verify_with_version(
+                &headers,
+                &version_with_transition_at(first_height)
+            ),
+            Err(ValidationError::UnsupportedChainGraphTransition { height, .. })
+                if height == first_height + 2
+        ));
     }
 
     fn hash_list(mut list: Vec<HashValue>) -> HashValue {
@@ -2,4 +2,6 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod chain;
+pub mod compact;
+pub mod difficulty;
 pub mod layer;
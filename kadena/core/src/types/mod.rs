@@ -3,8 +3,11 @@
 
 pub mod adjacent;
 pub mod error;
+pub mod graph;
 pub mod header;
+pub mod spv;
 pub mod utils;
+pub mod version;
 
 /// Size in bytes of a u16.
 pub const U16_BYTES_LENGTH: usize = 2;
@@ -0,0 +1,371 @@
+// Copyright (c) Argument Computer Corporation
+// SPDX-License-Identifier: Apache-2.0
+
+//! # SPV Module
+//!
+//! This module provides the Simple Payment Verification (SPV) proof of a
+//! transaction output in the Chainweb network.
+//!
+//! The outputs of the transactions of a block are the leaves of the
+//! outputs tree, after the coinbase output. The payload hash stored in
+//! the block header is the root of the tree over the transactions tree
+//! root and the outputs tree root. The SPV proof proves an output up to
+//! the outputs tree root, which is combined with the transactions tree
+//! root into the payload hash of a header, then tied to the header root
+//! and to a layer of the Chainweb network.
+
+use crate::crypto::error::CryptoError;
+use crate::crypto::hash::sha512::{hash_inner, hash_tagged_data};
+use crate::crypto::hash::{HashValue, DIGEST_BYTES_LENGTH};
+use crate::merkle::proof::MerkleProof;
+use crate::merkle::{COINBASE_OUTPUT_TAG, TRANSACTION_OUTPUT_TAG};
+use crate::types::error::{TypesError, ValidationError};
+use crate::types::header::layer::ChainwebLayerHeader;
+use crate::types::utils::extract_fixed_bytes;
+use crate::types::U32_BYTES_LENGTH;
+use getset::{CopyGetters, Getters};
+
+/// The SPV proof of the inclusion of a transaction output in the
+/// payload of a block on a chain of the Chainweb network.
+#[derive(Debug, Clone, Eq, PartialEq, Getters, CopyGetters)]
+pub struct Spv {
+    /// The chain of the block containing the transaction.
+    #[getset(get_copy = "pub")]
+    chain: u32,
+    /// The serialized transaction output.
+    #[getset(get = "pub")]
+    subject: Vec<u8>,
+    /// The root of the transactions tree of the block, left sibling of
+    /// the outputs tree root in the payload hash.
+    #[getset(get_copy = "pub")]
+    transactions_hash: HashValue,
+    /// The proof of the transaction output up to the outputs tree root of the block.
+    #[getset(get = "pub")]
+    outputs_proof: MerkleProof,
+}
+
+impl Spv {
+    /// Create a new `Spv`.
+    ///
+    /// # Arguments
+    ///
+    /// * `chain` - The chain of the block containing the transaction.
+    /// * `subject` - The serialized transaction output.
+    /// * `transactions_hash` - The root of the transactions tree of the block.
+    /// * `outputs_proof` - The proof of the transaction output up to the outputs tree root of the block.
+    ///
+    /// # Returns
+    ///
+    /// A new `Spv`.
+    pub const fn new(
+        chain: u32,
+        subject: Vec<u8>,
+        transactions_hash: HashValue,
+        outputs_proof: MerkleProof,
+    ) -> Self {
+        Self {
+            chain,
+            subject,
+            transactions_hash,
+            outputs_proof,
+        }
+    }
+
+    /// Create the `Spv` of a transaction output from the outputs of the
+    /// block containing it.
+    ///
+    /// # Arguments
+    ///
+    /// * `chain` - The chain of the block.
+    /// * `transactions_hash` - The root of the transactions tree of the block.
+    /// * `coinbase` - The serialized coinbase output of the block.
+    /// * `outputs` - The serialized transaction outputs of the block.
+    /// * `index` - The index of the transaction output to prove.
+    ///
+    /// # Returns
+    ///
+    /// The `Spv` of the transaction output.
+    pub fn from_block_outputs(
+        chain: u32,
+        transactions_hash: HashValue,
+        coinbase: &[u8],
+        outputs: &[Vec<u8>],
+        index: usize,
+    ) -> Result<Self, CryptoError> {
+        let subject = outputs
+            .get(index)
+            .ok_or_else(|| CryptoError::MerkleProof {
+                source: format!(
+                    "Output index {index} is out of bounds for a block of {} outputs",
+                    outputs.len()
+                )
+                .into(),
+            })?
+            .clone();
+
+        // The coinbase output is the first leaf of the outputs tree
+        let leaves = std::iter::once(hash_tagged_data(COINBASE_OUTPUT_TAG, coinbase))
+            .chain(
+                outputs
+                    .iter()
+                    .map(|output| hash_tagged_data(TRANSACTION_OUTPUT_TAG, output)),
+            )
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            chain,
+            subject,
+            transactions_hash,
+            outputs_proof: MerkleProof::from_leaves(&leaves, index + 1)?,
+        })
+    }
+
+    /// Compute the hash of the transaction output, as a leaf of the
+    /// outputs tree.
+    ///
+    /// # Returns
+    ///
+    /// The hash of the transaction output.
+    pub fn subject_hash(&self) -> Result<HashValue, CryptoError> {
+        hash_tagged_data(TRANSACTION_OUTPUT_TAG, &self.subject)
+    }
+
+    /// Verify that the transaction output is included in the block of
+    /// the proven chain in the given layer. The layer itself is expected
+    /// to be verified, e.g. by [`ChainwebLayerHeader::verify`].
+    ///
+    /// # Arguments
+    ///
+    /// * `layer_header` - The layer containing the block of the transaction.
+    ///
+    /// # Returns
+    ///
+    /// The hash of the transaction output, or an error if it is not
+    /// included in the block.
+    pub fn verify(&self, layer_header: &ChainwebLayerHeader) -> Result<HashValue, ValidationError> {
+        let chain_header = layer_header
+            .chain_headers()
+            .get(self.chain as usize)
+            .filter(|header| u32::from_le_bytes(*header.chain()) == self.chain)
+            .ok_or(ValidationError::NonValidChain {
+                size: layer_header.chain_headers().len(),
+                chain: self.chain as usize,
+            })?;
+
+        // Check the proof of the transaction output up to the payload hash,
+        // the outputs tree root being the right child of the payload hash
+        let subject_hash = self
+            .subject_hash()
+            .map_err(|err| ValidationError::HashError { source: err.into() })?;
+        let outputs_hash = self
+            .outputs_proof
+            .root(subject_hash)
+            .map_err(|err| ValidationError::HashError { source: err.into() })?;
+        let payload_hash = hash_inner(self.transactions_hash.as_ref(), outputs_hash.as_ref())
+            .map_err(|err| ValidationError::HashError { source: err.into() })?;
+
+        if payload_hash.as_ref() != chain_header.payload() {
+            return Err(ValidationError::InvalidPayloadHash {
+                computed: payload_hash,
+                stored: HashValue::new(*chain_header.payload()),
+            });
+        }
+
+        // Check that the payload hash is committed in the header root
+        let chain_header_hash = chain_header
+            .header_root()
+            .map_err(|err| ValidationError::HashError { source: err.into() })?;
+
+        if chain_header_hash.as_ref() != chain_header.hash() {
+            return Err(ValidationError::InvalidChainBlockHash {
+                computed: chain_header_hash,
+                stored: HashValue::new(*chain_header.hash()),
+            });
+        }
+
+        Ok(subject_hash)
+    }
+
+    /// Serialize the `Spv` into a vector of bytes.
+    ///
+    /// # Returns
+    ///
+    /// The bytes of the `Spv`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&self.chain.to_le_bytes());
+        bytes.extend_from_slice(&(self.subject.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(&self.subject);
+        bytes.extend_from_slice(self.transactions_hash.as_ref());
+        bytes.extend_from_slice(&self.outputs_proof.to_bytes());
+        bytes
+    }
+
+    /// Deserialize a `Spv` from a slice of bytes.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The slice of bytes to deserialize.
+    ///
+    /// # Returns
+    ///
+    /// The `Spv` deserialized from the bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, TypesError> {
+        let (cursor, chain) = extract_fixed_bytes::<U32_BYTES_LENGTH>("Spv", bytes, 0)?;
+        let (cursor, subject_len) = extract_fixed_bytes::<U32_BYTES_LENGTH>("Spv", bytes, cursor)?;
+        let subject_len = u32::from_le_bytes(subject_len) as usize;
+
+        if bytes.len() < cursor + subject_len {
+            return Err(TypesError::UnderLength {
+                structure: "Spv".into(),
+                minimum: cursor + subject_len,
+                actual: bytes.len(),
+            });
+        }
+
+        let subject = bytes[cursor..cursor + subject_len].to_vec();
+        let (cursor, transactions_hash) =
+            extract_fixed_bytes::<DIGEST_BYTES_LENGTH>("Spv", bytes, cursor + subject_len)?;
+        let outputs_proof = MerkleProof::from_bytes(&bytes[cursor..])?;
+
+        Ok(Self {
+            chain: u32::from_le_bytes(chain),
+            subject,
+            transactions_hash: HashValue::new(transactions_hash),
+            outputs_proof,
+        })
+    }
+}
+
+#[cfg(all(test, feature = "kadena"))]
+mod test {
+    use super::*;
+    use crate::merkle::proof::{merkle_root, MerkleProofStep, Side};
+    use crate::test_utils::get_layer_block_headers;
+    use crate::types::adjacent::ADJACENTS_RAW_BYTES_LENGTH;
+    use crate::types::header::chain::{
+        KadenaHeaderRaw, FLAGS_BYTES_LENGTH, PARENT_BYTES_LENGTH, RAW_HEADER_DECODED_BYTES_LENGTH,
+        TARGET_BYTES_LENGTH, TIME_BYTES_LENGTH,
+    };
+
+    /// Offset of the payload hash in a serialized header.
+    const PAYLOAD_OFFSET: usize = FLAGS_BYTES_LENGTH
+        + TIME_BYTES_LENGTH
+        + PARENT_BYTES_LENGTH
+        + ADJACENTS_RAW_BYTES_LENGTH
+        + TARGET_BYTES_LENGTH;
+
+    /// Offset of the hash in a serialized header.
+    const HASH_OFFSET: usize = RAW_HEADER_DECODED_BYTES_LENGTH - DIGEST_BYTES_LENGTH;
+
+    /// Set the payload hash of the chain 0 header of a layer, updating
+    /// its hash accordingly.
+    fn set_payload(layer_header: &ChainwebLayerHeader, payload: HashValue) -> ChainwebLayerHeader {
+        let mut chain_headers = layer_header.chain_headers().clone();
+
+        let mut bytes = chain_headers[0].to_bytes();
+        bytes[PAYLOAD_OFFSET..PAYLOAD_OFFSET + DIGEST_BYTES_LENGTH]
+            .copy_from_slice(payload.as_ref());
+        let root = KadenaHeaderRaw::from_bytes(&bytes)
+            .unwrap()
+            .header_root()
+            .unwrap();
+        bytes[HASH_OFFSET..].copy_from_slice(root.as_ref());
+        chain_headers[0] = KadenaHeaderRaw::from_bytes(&bytes).unwrap();
+
+        ChainwebLayerHeader::new(*layer_header.height(), chain_headers).unwrap()
+    }
+
+    #[test]
+    fn test_verify_spv() {
+        let coinbase = b"coinbase".to_vec();
+        let outputs = (0..5)
+            .map(|i| format!("{{\"reqKey\":\"{i}\"}}").into_bytes())
+            .collect::<Vec<_>>();
+        let transactions_hash = HashValue::new([1; DIGEST_BYTES_LENGTH]);
+
+        let leaves = std::iter::once(hash_tagged_data(COINBASE_OUTPUT_TAG, &coinbase))
+            .chain(
+                outputs
+                    .iter()
+                    .map(|output| hash_tagged_data(TRANSACTION_OUTPUT_TAG, output)),
+            )
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        let outputs_hash = merkle_root(&leaves).unwrap();
+        let payload_hash = hash_inner(transactions_hash.as_ref(), outputs_hash.as_ref()).unwrap();
+
+        let layers = get_layer_block_headers();
+        let layer_header = set_payload(&layers[layers.len() / 2], payload_hash);
+
+        for index in 0..outputs.len() {
+            let spv =
+                Spv::from_block_outputs(0, transactions_hash, &coinbase, &outputs, index).unwrap();
+            assert_eq!(Spv::from_bytes(&spv.to_bytes()).unwrap(), spv);
+
+            assert_eq!(
+                spv.verify(&layer_header).unwrap(),
+                hash_tagged_data(TRANSACTION_OUTPUT_TAG, &outputs[index]).unwrap()
+            );
+        }
+
+        // The output must be part of the payload of the block
+        let spv = Spv::from_block_outputs(0, transactions_hash, &coinbase, &outputs, 0).unwrap();
+        let tampered = Spv::new(
+            0,
+            outputs[1].clone(),
+            transactions_hash,
+            spv.outputs_proof().clone(),
+        );
+        assert!(matches!(
+            tampered.verify(&layer_header),
+            Err(ValidationError::InvalidPayloadHash { .. })
+        ));
+
+        // The output must be proven in the outputs tree, the transactions tree root being
+        // always combined on the left: a proof that already includes the payload step can
+        // not be used to reach the payload hash with another transactions tree root
+        let mut steps = spv.outputs_proof().steps().clone();
+        steps.push(MerkleProofStep::new(Side::Left, transactions_hash));
+        let full_payload_proof = Spv::new(
+            0,
+            spv.subject().clone(),
+            HashValue::new([2; DIGEST_BYTES_LENGTH]),
+            MerkleProof::new(steps),
+        );
+        assert!(matches!(
+            full_payload_proof.verify(&layer_header),
+            Err(ValidationError::InvalidPayloadHash { .. })
+        ));
+        let other_transactions = Spv::new(
+            0,
+            spv.subject().clone(),
+            HashValue::new([2; DIGEST_BYTES_LENGTH]),
+            spv.outputs_proof().clone(),
+        );
+        assert!(matches!(
+            other_transactions.verify(&layer_header),
+            Err(ValidationError::InvalidPayloadHash { .. })
+        ));
+
+        // The payload must be the one of the block on the proven chain
+        let other_chain = Spv::new(
+            1,
+            spv.subject().clone(),
+            transactions_hash,
+            spv.outputs_proof().clone(),
+        );
+        assert!(matches!(
+            other_chain.verify(&layer_header),
+            Err(ValidationError::InvalidPayloadHash { .. })
+        ));
+
+        // The header must commit to the payload
+        assert!(matches!(
+            spv.verify(&layers[layers.len() / 2]),
+            Err(ValidationError::InvalidPayloadHash { .. })
+        ));
+
+        assert!(Spv::from_block_outputs(0, transactions_hash, &coinbase, &outputs, 5).is_err());
+    }
+}
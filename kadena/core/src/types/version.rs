@@ -0,0 +1,349 @@
+// Copyright (c) Argument Computer Corporation
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Chainweb Version Module
+//!
+//! This module provides the versions of the Chainweb network. A version
+//! identifies a network, such as `mainnet01`, and defines its chain
+//! graph at each block height. Its code is stored in every block header,
+//! which makes the version of a header part of what is proven.
+//!
+//! See [the `chainweb-node` version definitions](https://github.com/kadena-io/chainweb-node/tree/master/src/Chainweb/Version).
+
+use crate::types::adjacent::ADJACENT_RECORD_PER_BLOCK;
+use crate::types::error::ValidationError;
+use crate::types::graph::{PETERSEN_GRAPH, TWENTY_CHAIN_GRAPH};
+use getset::{CopyGetters, Getters};
+use std::borrow::Cow;
+
+/// Adjacent chains of each chain of a chain graph, indexed by chain.
+pub type ChainGraph = Cow<'static, [[u32; ADJACENT_RECORD_PER_BLOCK]]>;
+
+/// Code of the `mainnet01` version.
+pub const MAINNET01_CODE: u32 = 0x0000_0005;
+
+/// Code of the `testnet04` version.
+pub const TESTNET04_CODE: u32 = 0x0000_0007;
+
+/// Code of the `development` version.
+pub const DEVELOPMENT_CODE: u32 = 0x0000_0001;
+
+/// Height from which `mainnet01` runs on twenty chains.
+pub const MAINNET01_TWENTY_CHAIN_HEIGHT: u64 = 852_054;
+
+/// Height from which `testnet04` runs on twenty chains.
+pub const TESTNET04_TWENTY_CHAIN_HEIGHT: u64 = 332_604;
+
+/// Height from which `development` runs on twenty chains.
+pub const DEVELOPMENT_TWENTY_CHAIN_HEIGHT: u64 = 60;
+
+/// A version of the Chainweb network.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ChainwebVersion {
+    /// The Kadena mainnet.
+    Mainnet01,
+    /// The Kadena testnet.
+    Testnet04,
+    /// The network used for local development.
+    Development,
+    /// A network with a custom specification. Its headers can be verified
+    /// natively with [`ChainwebLayerHeader::verify_with_version`], but not
+    /// proven, as the programs only know the versions with a built-in code.
+    ///
+    /// [`ChainwebLayerHeader::verify_with_version`]: crate::types::header::layer::ChainwebLayerHeader::verify_with_version
+    Custom(ChainwebVersionSpec),
+}
+
+impl ChainwebVersion {
+    /// Get the version with the given code among the known versions. Custom
+    /// versions are never returned, as their specification is not part of
+    /// the headers.
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - The code of the version.
+    ///
+    /// # Returns
+    ///
+    /// The version with the given code.
+    pub fn from_code(code: u32) -> Result<Self, ValidationError> {
+        match code {
+            MAINNET01_CODE => Ok(Self::Mainnet01),
+            TESTNET04_CODE => Ok(Self::Testnet04),
+            DEVELOPMENT_CODE => Ok(Self::Development),
+            _ => Err(ValidationError::UnsupportedChainwebVersion { code }),
+        }
+    }
+
+    /// Whether the headers of the version can be proven, that is whether the
+    /// version can be found from the code stored in its headers with
+    /// [`ChainwebVersion::from_code`].
+    ///
+    /// # Returns
+    ///
+    /// `true` for the known versions, `false` for custom versions.
+    pub const fn is_provable(&self) -> bool {
+        !matches!(self, Self::Custom(_))
+    }
+
+    /// Get the name of the version, as used in the Chainweb node API.
+    ///
+    /// # Returns
+    ///
+    /// The name of the version.
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Mainnet01 => "mainnet01",
+            Self::Testnet04 => "testnet04",
+            Self::Development => "development",
+            Self::Custom(spec) => spec.name(),
+        }
+    }
+
+    /// Get the code of the version, as stored in the block headers.
+    ///
+    /// # Returns
+    ///
+    /// The code of the version.
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::Mainnet01 => MAINNET01_CODE,
+            Self::Testnet04 => TESTNET04_CODE,
+            Self::Development => DEVELOPMENT_CODE,
+            Self::Custom(spec) => spec.code(),
+        }
+    }
+
+    /// Get the chain graph of the network at a given height.
+    ///
+    /// # Arguments
+    ///
+    /// * `height` - The block height.
+    ///
+    /// # Returns
+    ///
+    /// The adjacent chains of each chain, indexed by chain.
+    pub fn chain_graph(&self, height: u64) -> &[[u32; ADJACENT_RECORD_PER_BLOCK]] {
+        let twenty_chain_height = match self {
+            Self::Mainnet01 => MAINNET01_TWENTY_CHAIN_HEIGHT,
+            Self::Testnet04 => TESTNET04_TWENTY_CHAIN_HEIGHT,
+            Self::Development => DEVELOPMENT_TWENTY_CHAIN_HEIGHT,
+            Self::Custom(spec) => return spec.chain_graph(height),
+        };
+
+        if height < twenty_chain_height {
+            &PETERSEN_GRAPH
+        } else {
+            &TWENTY_CHAIN_GRAPH
+        }
+    }
+
+    /// Get the number of chains of the network at a given height.
+    ///
+    /// # Arguments
+    ///
+    /// * `height` - The block height.
+    ///
+    /// # Returns
+    ///
+    /// The number of chains.
+    pub fn chain_count(&self, height: u64) -> usize {
+        self.chain_graph(height).len()
+    }
+
+    /// Checks if the chain graph of the network changes at a given
+    /// height. New chains start at this height with a genesis block, and
+    /// the headers of the existing chains at this height are braided
+    /// following the previous graph.
+    ///
+    /// # Arguments
+    ///
+    /// * `height` - The block height.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the chain graph at the height differs from the one at the
+    /// previous height, `false` otherwise.
+    pub fn is_graph_transition(&self, height: u64) -> bool {
+        height > 0 && self.chain_graph(height - 1) != self.chain_graph(height)
+    }
+}
+
+/// Parse the name of one of the known versions. Custom versions are built
+/// from their [`ChainwebVersionSpec`] instead.
+impl TryFrom<&str> for ChainwebVersion {
+    type Error = ValidationError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "mainnet01" => Ok(Self::Mainnet01),
+            "testnet04" => Ok(Self::Testnet04),
+            "development" => Ok(Self::Development),
+            _ => Err(ValidationError::UnknownChainwebVersion {
+                name: value.to_string(),
+            }),
+        }
+    }
+}
+
+/// The specification of a custom version of the Chainweb network.
+#[derive(Debug, Clone, Eq, PartialEq, Getters, CopyGetters)]
+pub struct ChainwebVersionSpec {
+    /// The name of the version, as used in the Chainweb node API.
+    #[getset(get = "pub")]
+    name: String,
+    /// The code of the version, as stored in the block headers.
+    #[getset(get_copy = "pub")]
+    code: u32,
+    /// The chain graphs of the network, with the height from which
+    /// they apply, sorted by height.
+    #[getset(get = "pub")]
+    graphs: Vec<(u64, ChainGraph)>,
+}
+
+impl ChainwebVersionSpec {
+    /// Create a new `ChainwebVersionSpec`.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the version.
+    /// * `code` - The code of the version.
+    /// * `graphs` - The chain graphs of the network, with the height from which they
+    ///   apply. The first graph must apply from the genesis height.
+    ///
+    /// # Returns
+    ///
+    /// A new `ChainwebVersionSpec`, or an error if the graphs are not valid.
+    pub fn new(
+        name: &str,
+        code: u32,
+        graphs: Vec<(u64, ChainGraph)>,
+    ) -> Result<Self, ValidationError> {
+        if graphs.first().map(|(height, _)| *height) != Some(0) {
+            return Err(ValidationError::InvalidChainwebVersionSpec {
+                reason: "the first chain graph must apply from height 0".into(),
+            });
+        }
+
+        if graphs.windows(2).any(|pair| pair[0].0 >= pair[1].0) {
+            return Err(ValidationError::InvalidChainwebVersionSpec {
+                reason: "chain graphs must be sorted by increasing height".into(),
+            });
+        }
+
+        // Each chain must be adjacent to existing chains, and they to it
+        for (height, graph) in &graphs {
+            for (chain, adjacents) in graph.iter().enumerate() {
+                if !adjacents.iter().all(|adjacent| {
+                    graph
+                        .get(*adjacent as usize)
+                        .is_some_and(|other| other.contains(&(chain as u32)))
+                }) {
+                    return Err(ValidationError::InvalidChainwebVersionSpec {
+                        reason: format!(
+                            "chain {chain} has invalid adjacent chains in the graph at height {height}"
+                        ),
+                    });
+                }
+            }
+        }
+
+        Ok(Self {
+            name: name.to_string(),
+            code,
+            graphs,
+        })
+    }
+
+    /// Get the chain graph of the network at a given height.
+    ///
+    /// # Arguments
+    ///
+    /// * `height` - The block height.
+    ///
+    /// # Returns
+    ///
+    /// The adjacent chains of each chain, indexed by chain.
+    pub fn chain_graph(&self, height: u64) -> &[[u32; ADJACENT_RECORD_PER_BLOCK]] {
+        // The first graph applies from height 0, so there is always a match
+        self.graphs
+            .iter()
+            .rev()
+            .find(|(start, _)| *start <= height)
+            .map(|(_, graph)| graph.as_ref())
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_chain_graph_at_height() {
+        let mainnet = ChainwebVersion::Mainnet01;
+        assert_eq!(mainnet.chain_count(MAINNET01_TWENTY_CHAIN_HEIGHT - 1), 10);
+        assert_eq!(mainnet.chain_count(MAINNET01_TWENTY_CHAIN_HEIGHT), 20);
+        assert_eq!(mainnet.chain_graph(0), &PETERSEN_GRAPH[..]);
+        assert!(mainnet.is_graph_transition(MAINNET01_TWENTY_CHAIN_HEIGHT));
+        assert!(!mainnet.is_graph_transition(MAINNET01_TWENTY_CHAIN_HEIGHT + 1));
+        assert!(!mainnet.is_graph_transition(0));
+
+        assert_eq!(ChainwebVersion::from_code(MAINNET01_CODE).unwrap(), mainnet);
+        assert_eq!(ChainwebVersion::try_from("mainnet01").unwrap(), mainnet);
+        assert_eq!(
+            ChainwebVersion::try_from(ChainwebVersion::Testnet04.name()).unwrap(),
+            ChainwebVersion::Testnet04
+        );
+        assert!(ChainwebVersion::from_code(0xff).is_err());
+        assert!(ChainwebVersion::try_from("mainnet").is_err());
+
+        let spec = ChainwebVersionSpec::new(
+            "fast-development",
+            0x0000_0002,
+            vec![
+                (0, Cow::Borrowed(&PETERSEN_GRAPH[..])),
+                (100, Cow::Owned(TWENTY_CHAIN_GRAPH.to_vec())),
+            ],
+        )
+        .unwrap();
+        let custom = ChainwebVersion::Custom(spec);
+        assert_eq!(custom.name(), "fast-development");
+        assert_eq!(custom.chain_count(99), 10);
+        assert_eq!(custom.chain_count(100), 20);
+        assert!(custom.is_graph_transition(100));
+        assert!(!custom.is_graph_transition(99));
+
+        // Custom versions can not be found from the code of their headers
+        assert!(mainnet.is_provable());
+        assert!(!custom.is_provable());
+        assert!(ChainwebVersion::from_code(0x0000_0002).is_err());
+    }
+
+    #[test]
+    fn test_invalid_chainweb_version_spec() {
+        // Graphs must start at genesis
+        assert!(ChainwebVersionSpec::new(
+            "custom",
+            0,
+            vec![(1, Cow::Borrowed(&PETERSEN_GRAPH[..]))]
+        )
+        .is_err());
+
+        // Graphs must be sorted by height
+        assert!(ChainwebVersionSpec::new(
+            "custom",
+            0,
+            vec![
+                (0, Cow::Borrowed(&PETERSEN_GRAPH[..])),
+                (0, Cow::Borrowed(&TWENTY_CHAIN_GRAPH[..])),
+            ]
+        )
+        .is_err());
+
+        // Graphs must be symmetric
+        let mut graph = PETERSEN_GRAPH.to_vec();
+        graph[0] = [1, 3, 5];
+        assert!(ChainwebVersionSpec::new("custom", 0, vec![(0, Cow::Owned(graph))]).is_err());
+    }
+}
@@ -1,16 +1,42 @@
 // Copyright (c) Argument Computer Corporation
 // SPDX-License-Identifier: Apache-2.0
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
+use axum::extract::State;
+use axum::routing::get;
+use axum::{Json, Router};
+use backoff::backoff::Backoff;
+use backoff::ExponentialBackoff;
 use clap::Parser;
+use kadena_lc::client::error::ClientError;
 use kadena_lc::client::Client;
+use kadena_lc::proofs::longest_chain::CommitteeChangeOut;
 use kadena_lc::proofs::ProvingMode;
+use kadena_lc::types::tip::VerifiedTip;
+use kadena_lc_core::crypto::hash::HashValue;
+use kadena_lc_core::types::version::ChainwebVersion;
+use log::{debug, error, info};
+use serde_json::{json, Value};
 use std::env;
-use std::sync::Arc;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+use tokio::net::TcpListener;
 
-pub const TARGET_BLOCK: usize = 5099345;
+/// The number of layers proven before and after each target layer.
 pub const BLOCK_WINDOW: usize = 3;
 
+/// The interval at which the light client looks for new layers, roughly
+/// the block time of the Chainweb network.
+pub const POLLING_INTERVAL: Duration = Duration::from_secs(30);
+
+/// The maximum delay before retrying a layer that failed to be proven
+/// because of the Chainweb node or the proof server.
+pub const MAX_RETRY_INTERVAL: Duration = Duration::from_secs(600);
+
+/// The latest layer verified by the light client, shared with the server
+/// exposing it.
+type SharedVerifiedTip = Arc<RwLock<VerifiedTip>>;
+
 /// The CLI for the light client.
 #[derive(Parser)]
 struct Cli {
@@ -21,6 +47,14 @@ struct Cli {
     /// The address of the proof server
     #[arg(short, long)]
     proof_server_address: String,
+
+    /// The version of the Chainweb network, such as mainnet01 or testnet04.
+    #[arg(long, default_value = "mainnet01")]
+    chainweb_version: String,
+
+    /// Address on which the latest verified layer is served, at `/tip`. E.g. 127.0.0.1:1234
+    #[arg(long)]
+    tip_address: Option<String>,
 }
 
 #[tokio::main]
@@ -33,8 +67,11 @@ async fn main() -> Result<()> {
     let Cli {
         chainweb_node_address,
         proof_server_address,
-        ..
+        chainweb_version,
+        tip_address,
     } = Cli::parse();
+    let chainweb_version = ChainwebVersion::try_from(chainweb_version.as_str())
+        .expect("Chainweb version should be mainnet01, testnet04 or development");
 
     // Initialize the logger.
     env_logger::init();
@@ -45,17 +82,185 @@ async fn main() -> Result<()> {
     let client = Client::new(
         chainweb_node_address.as_str(),
         proof_server_address.as_str(),
+        chainweb_version,
     );
 
-    let kadena_headers = client
-        .get_layer_block_headers(TARGET_BLOCK, BLOCK_WINDOW)
+    info!("Testing connection to endpoints...");
+    client.test_endpoints().await?;
+
+    // Initialize the verified tip with the latest layer for which a full window is available.
+    let latest_layer_height = client.get_latest_layer_height().await?;
+    let target_block = latest_layer_height
+        .checked_sub(BLOCK_WINDOW as u64)
+        .ok_or_else(|| anyhow!("Not enough layers in the network to start the light client"))?;
+
+    info!("Proving initial layer at height {target_block}...");
+    let (_, tip) = prove_layer(&client, mode, target_block).await?;
+
+    info!(
+        "Light client initialized at layer {} with hash {}",
+        tip.height(),
+        tip.layer_hash()
+    );
+
+    let tip: SharedVerifiedTip = Arc::new(RwLock::new(tip));
+
+    if let Some(tip_address) = tip_address {
+        let listener = TcpListener::bind(&tip_address).await?;
+        let app = Router::new()
+            .route("/tip", get(get_tip))
+            .with_state(tip.clone());
+
+        info!("Serving the verified tip on {tip_address}");
+        tokio::spawn(async move {
+            if let Err(err) = axum::serve(listener, app).await {
+                error!("Verified tip server stopped: {err}");
+            }
+        });
+    }
+
+    let mut interval = tokio::time::interval(POLLING_INTERVAL);
+    let mut retry_backoff = ExponentialBackoff {
+        max_interval: MAX_RETRY_INTERVAL,
+        max_elapsed_time: None,
+        ..Default::default()
+    };
+
+    // Layers that failed because of the Chainweb node or the proof server are retried after
+    // a growing delay. Layers that can not be proven or do not extend the tip would fail the
+    // same way on every retry, so the light client stops on them.
+    loop {
+        interval.tick().await;
+
+        let current_tip = *tip
+            .read()
+            .expect("verified tip lock should not be poisoned");
+        match extend_tip(&client, mode, &current_tip).await {
+            Ok(Some(next)) => {
+                retry_backoff.reset();
+                *tip.write()
+                    .expect("verified tip lock should not be poisoned") = next;
+
+                info!(
+                    "Verified tip moved to layer {} with hash {}, confirmed by {} work ({} since start)",
+                    next.height(),
+                    next.layer_hash(),
+                    next.confirmation_work(),
+                    next.total_work()
+                );
+            }
+            Ok(None) => retry_backoff.reset(),
+            Err(err) if err.is_permanent() => {
+                return Err(anyhow!(
+                    "Could not extend the verified tip at layer {}: {err}",
+                    current_tip.height()
+                ));
+            }
+            Err(err) => {
+                let delay = retry_backoff.next_backoff().unwrap_or(MAX_RETRY_INTERVAL);
+                error!(
+                    "Failed to extend the verified tip at layer {}, retrying in {delay:?}: {err}",
+                    current_tip.height()
+                );
+                tokio::time::sleep(delay).await;
+            }
+        }
+    }
+}
+
+/// Serve the latest layer verified by the light client.
+async fn get_tip(State(tip): State<SharedVerifiedTip>) -> Json<Value> {
+    let tip = *tip
+        .read()
+        .expect("verified tip lock should not be poisoned");
+
+    Json(json!({
+        "height": tip.height(),
+        "layer_hash": tip.layer_hash().to_string(),
+        "confirmation_work": tip.confirmation_work().to_string(),
+        "total_work": tip.total_work().to_string(),
+    }))
+}
+
+/// Prove the window of layers starting at the verified tip, and move the
+/// tip to its target layer.
+///
+/// # Arguments
+///
+/// * `client` - The client.
+/// * `mode` - The proving mode to use.
+/// * `tip` - The current verified tip.
+///
+/// # Returns
+///
+/// The new verified tip, or `None` if the window is not available yet.
+async fn extend_tip(
+    client: &Client,
+    mode: ProvingMode,
+    tip: &VerifiedTip,
+) -> Result<Option<VerifiedTip>, ClientError> {
+    // The next window starts at the tip, so that its proof links to it
+    let target_block = tip.height() + BLOCK_WINDOW as u64;
+    let latest_layer_height = client.get_latest_layer_height().await?;
+
+    if target_block + BLOCK_WINDOW as u64 > latest_layer_height {
+        debug!(
+            "Waiting for layer {} to be available, latest layer is {latest_layer_height}",
+            target_block + BLOCK_WINDOW as u64
+        );
+        return Ok(None);
+    }
+
+    info!("Proving layer at height {target_block}...");
+    let (first_layer_hash, next) = prove_layer(client, mode, target_block).await?;
+
+    tip.extend(first_layer_hash, next).map(Some)
+}
+
+/// Prove and verify the window of layers around a target layer.
+///
+/// # Arguments
+///
+/// * `client` - The client.
+/// * `mode` - The proving mode to use.
+/// * `target_block` - The height of the target layer.
+///
+/// # Returns
+///
+/// The hash of the first layer of the window and the verified target layer.
+async fn prove_layer(
+    client: &Client,
+    mode: ProvingMode,
+    target_block: u64,
+) -> Result<(HashValue, VerifiedTip), ClientError> {
+    let compact_layer_headers = client
+        .get_compact_layer_headers(target_block as usize, BLOCK_WINDOW)
         .await?;
 
-    let proof = client.prove_longest_chain(mode, kadena_headers).await?;
+    let proof = client
+        .prove_longest_chain(mode, compact_layer_headers)
+        .await?;
+
+    if !client.verify_longest_chain(proof.clone()).await? {
+        return Err(ClientError::Response {
+            endpoint: "verify_longest_chain".into(),
+            source: format!("Proof of the layer at height {target_block} is invalid").into(),
+        });
+    }
 
-    let valid = client.verify_longest_chain(proof).await?;
+    let outputs = CommitteeChangeOut::from(&mut proof.public_values());
 
-    assert!(valid);
+    debug!(
+        "Layer at height {target_block} has a total weight of {}",
+        outputs.target_layer_weight()
+    );
 
-    Ok(())
+    Ok((
+        outputs.first_layer_block_header_hash(),
+        VerifiedTip::new(
+            target_block,
+            outputs.target_layer_block_header_hash(),
+            outputs.confirmation_work(),
+        ),
+    ))
 }
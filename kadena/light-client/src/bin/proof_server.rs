@@ -10,6 +10,7 @@ use axum::routing::post;
 use axum::{extract::State, http::StatusCode, response::IntoResponse, routing::get, Router};
 use clap::{Parser, ValueEnum};
 use kadena_lc::proofs::longest_chain::LongestChainProver;
+use kadena_lc::proofs::spv::SpvProver;
 use kadena_lc::proofs::Prover;
 use kadena_lc::types::network::Request;
 use log::{error, info};
@@ -46,6 +47,7 @@ struct ServerState {
     mode: Mode,
     active_requests: Arc<AtomicUsize>,
     longest_chain_prover: Arc<LongestChainProver>,
+    spv_prover: Arc<SpvProver>,
 }
 
 #[tokio::main]
@@ -69,11 +71,14 @@ async fn main() -> Result<()> {
         mode,
         active_requests: Arc::new(AtomicUsize::new(0)),
         longest_chain_prover: Arc::new(LongestChainProver::new()),
+        spv_prover: Arc::new(SpvProver::new()),
     };
 
     let app = Router::new()
         .route("/longest-chain/proof", post(committee_proof))
         .route("/longest-chain/verify", post(committee_verify))
+        .route("/spv/proof", post(spv_proof))
+        .route("/spv/verify", post(spv_verify))
         .route("/health", get(health_check))
         .route("/ready", get(ready_check))
         .layer(axum::middleware::from_fn_with_state(
@@ -185,6 +190,87 @@ async fn committee_verify(
     Ok(response)
 }
 
+async fn spv_proof(
+    State(state): State<ServerState>,
+    request: axum::extract::Request,
+) -> Result<impl IntoResponse, StatusCode> {
+    let bytes = axum::body::to_bytes(request.into_body(), usize::MAX)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    let res = Request::from_bytes(&bytes);
+
+    if let Err(err) = res {
+        error!("Failed to deserialize request object: {err}");
+        return Err(StatusCode::BAD_REQUEST);
+    }
+
+    let request = res.unwrap();
+    let Request::ProveSpv(boxed) = request else {
+        error!("Invalid request type");
+        return Err(StatusCode::BAD_REQUEST);
+    };
+
+    let (proving_mode, inputs) = *boxed;
+    let proof_handle = spawn_blocking(move || state.spv_prover.prove(&inputs, proving_mode));
+    let proof = proof_handle
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    let res = proof
+        .to_bytes()
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    let response = Response::builder()
+        .status(StatusCode::OK)
+        .header(CONTENT_TYPE, "application/octet-stream")
+        .body(Body::from(res))
+        .map_err(|err| {
+            error!("Could not construct response for client: {err}");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+
+    Ok(response)
+}
+
+async fn spv_verify(
+    State(state): State<ServerState>,
+    request: axum::extract::Request,
+) -> Result<impl IntoResponse, StatusCode> {
+    let bytes = axum::body::to_bytes(request.into_body(), usize::MAX)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    let res = Request::from_bytes(&bytes);
+
+    if let Err(err) = res {
+        error!("Failed to deserialize request object: {err}");
+        return Err(StatusCode::BAD_REQUEST);
+    }
+
+    let request = res.unwrap();
+    let Request::VerifySpv(boxed) = request else {
+        error!("Invalid request type");
+        return Err(StatusCode::BAD_REQUEST);
+    };
+    let res = {
+        let is_valid = state.spv_prover.verify(boxed.as_ref()).is_ok();
+        vec![u8::from(is_valid)]
+    };
+
+    let response = Response::builder()
+        .status(StatusCode::OK)
+        .header(CONTENT_TYPE, "application/octet-stream")
+        .body(Body::from(res))
+        .map_err(|err| {
+            error!("Could not construct response for client: {err}");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+
+    Ok(response)
+}
+
 #[allow(dead_code)]
 async fn forward_request(request_bytes: &[u8], snd_addr: &str) -> Result<Vec<u8>, StatusCode> {
     info!("Connecting to the secondary server");
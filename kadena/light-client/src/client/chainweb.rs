@@ -11,19 +11,24 @@
 
 use crate::client::error::ClientError;
 use crate::client::utils::test_connection;
-use crate::types::chainweb::BlockHeaderResponse;
+use crate::types::chainweb::{
+    BlockHeaderResponse, CutResponse, PayloadWithOutputsResponse, PollMetaData, PollResponse,
+};
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
 use getset::Getters;
 use kadena_lc_core::types::header::chain::KadenaHeaderRaw;
 use kadena_lc_core::types::header::layer::ChainwebLayerHeader;
+use kadena_lc_core::types::spv::Spv;
+use kadena_lc_core::types::version::ChainwebVersion;
 use reqwest::header::ACCEPT;
 use reqwest::Client;
+use serde_json::json;
 use std::sync::Arc;
 use tokio::task::JoinSet;
 
 /// The version of the Chainweb API.
 const CHAINWEB_API_VERSION: &str = "0.0";
-/// The number of chains in the Chainweb network.
-const CHAINWEB_CHAIN_COUNT: usize = 20;
 
 /// An internal client to handle communication with a Chainweb Node.
 #[derive(Debug, Clone, Getters)]
@@ -31,6 +36,8 @@ const CHAINWEB_CHAIN_COUNT: usize = 20;
 pub(crate) struct ChainwebClient {
     /// The address of the Chainweb Node API.
     chainweb_node_address: Arc<String>,
+    /// The version of the Chainweb network served by the node.
+    chainweb_version: Arc<ChainwebVersion>,
     /// The inner HTTP client.
     inner: Arc<Client>,
 }
@@ -41,13 +48,15 @@ impl ChainwebClient {
     /// # Arguments
     ///
     /// * `chainweb_node_address` - The address of the Chainweb Node API.
+    /// * `chainweb_version` - The version of the Chainweb network served by the node.
     ///
     /// # Returns
     ///
     /// A new `ChainwebClient`.
-    pub(crate) fn new(chainweb_node_address: &str) -> Self {
+    pub(crate) fn new(chainweb_node_address: &str, chainweb_version: ChainwebVersion) -> Self {
         Self {
             chainweb_node_address: Arc::new(chainweb_node_address.to_string()),
+            chainweb_version: Arc::new(chainweb_version),
             inner: Arc::new(Client::new()),
         }
     }
@@ -62,8 +71,63 @@ impl ChainwebClient {
         test_connection(&self.chainweb_node_address).await
     }
 
+    /// `get_latest_layer_height` fetches the current cut of the Chainweb
+    /// node to get the height of the latest layer available on every chain.
+    ///
+    /// # Returns
+    ///
+    /// The height of the latest complete layer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response is not successful or properly formatted.
+    pub(crate) async fn get_latest_layer_height(&self) -> Result<u64, ClientError> {
+        // Format the endpoint for the call
+        let url = format!(
+            "{}/chainweb/{CHAINWEB_API_VERSION}/{}/cut",
+            self.chainweb_node_address,
+            self.chainweb_version.name()
+        );
+
+        // Send the HTTP request
+        let response = self
+            .inner
+            .get(&url)
+            .header(ACCEPT, "application/json")
+            .send()
+            .await
+            .map_err(|err| ClientError::Request {
+                endpoint: url.clone(),
+                source: Box::new(err),
+            })?;
+
+        if !response.status().is_success() {
+            return Err(ClientError::Request {
+                endpoint: url,
+                source: format!(
+                    "Request not successful, got HTTP code {}",
+                    response.status().as_str()
+                )
+                .into(),
+            });
+        }
+
+        // Deserialize the response
+        let response: CutResponse = response.json().await.map_err(|err| ClientError::Request {
+            endpoint: url.clone(),
+            source: Box::new(err),
+        })?;
+
+        response
+            .layer_height()
+            .ok_or_else(|| ClientError::Response {
+                endpoint: url,
+                source: "Received an empty cut".into(),
+            })
+    }
+
     /// `get_layer_block_headers` leverages `get_block_headers` to
-    /// get the block headers for each chain of the Chainweb network (0 to 19).
+    /// get the block headers for each chain of the Chainweb network.
     ///
     /// # Arguments
     ///
@@ -76,30 +140,51 @@ impl ChainwebClient {
     ///
     /// # Errors
     ///
-    /// Returns an error if the request fails or the response is not successful or properly formatted.
+    /// Returns an error if the request fails or the response is not successful or properly formatted,
+    /// or if the chain graph of the network changes within the window.
     pub(crate) async fn get_layer_block_headers(
         &self,
         target_block: usize,
         block_window: usize,
     ) -> Result<Vec<ChainwebLayerHeader>, ClientError> {
+        // All layers of the window must contain the same chains
+        let chain_count = self
+            .chainweb_version
+            .chain_count((target_block - block_window) as u64);
+        if self
+            .chainweb_version
+            .chain_count((target_block + block_window) as u64)
+            != chain_count
+        {
+            return Err(ClientError::InvalidLayerHeaders {
+                target_block: target_block as u64,
+                source: format!(
+                    "The chain graph of {} changes between heights {} and {}",
+                    self.chainweb_version.name(),
+                    target_block - block_window,
+                    target_block + block_window
+                )
+                .into(),
+            });
+        }
+
         let mut set = JoinSet::new();
 
-        // Spawn tasks for fetching block headers for each chain (0 to 19).
-        for chain in 0..CHAINWEB_CHAIN_COUNT {
+        // Spawn tasks for fetching block headers for each chain.
+        for chain in 0..chain_count {
             let client = self.inner.clone();
-            let address = self.chainweb_node_address.clone();
+            let chain_url = self.chain_url(chain);
             set.spawn(get_block_headers(
                 client,
-                address,
+                chain_url,
                 target_block,
                 block_window,
-                chain,
             ));
         }
 
-        // Initialize a vector with 20 elements (for chains 0 to 19), each holding the result (Vec<KadenaHeaderRaw>).
+        // Initialize a vector with an element per chain, each holding the result (Vec<KadenaHeaderRaw>).
         let mut response: Vec<Vec<KadenaHeaderRaw>> =
-            vec![vec![KadenaHeaderRaw::default(); CHAINWEB_CHAIN_COUNT]; 1 + block_window * 2];
+            vec![vec![KadenaHeaderRaw::default(); chain_count]; 1 + block_window * 2];
 
         // Collect results as they complete.
         while let Some(res) = set.join_next().await {
@@ -124,7 +209,7 @@ impl ChainwebClient {
                             .chain(),
                     ) as usize;
 
-                    if chain >= CHAINWEB_CHAIN_COUNT {
+                    if chain >= chain_count {
                         return Err(ClientError::Response {
                             endpoint: "get_layer_block_headers".to_string(),
                             source: format!("Invalid chain number: {}", chain).into(),
@@ -172,6 +257,155 @@ impl ChainwebClient {
 
         Ok(layer_headers)
     }
+
+    /// `get_transaction_block` polls the result of a transaction to get
+    /// the metadata of the block containing it.
+    ///
+    /// # Arguments
+    ///
+    /// * `chain` - The chain of the transaction.
+    /// * `request_key` - The request key of the transaction.
+    ///
+    /// # Returns
+    ///
+    /// The metadata of the block containing the transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails, the response is not successful or properly
+    /// formatted, or if the transaction is not yet included in a block.
+    pub(crate) async fn get_transaction_block(
+        &self,
+        chain: u32,
+        request_key: &str,
+    ) -> Result<PollMetaData, ClientError> {
+        // Format the endpoint for the call
+        let url = format!("{}/pact/api/v1/poll", self.chain_url(chain as usize));
+
+        // Send the HTTP request
+        let response = self
+            .inner
+            .post(&url)
+            .header(ACCEPT, "application/json")
+            .json(&json!({ "requestKeys": [request_key] }))
+            .send()
+            .await
+            .map_err(|err| ClientError::Request {
+                endpoint: url.clone(),
+                source: Box::new(err),
+            })?;
+
+        if !response.status().is_success() {
+            return Err(ClientError::Request {
+                endpoint: url,
+                source: format!(
+                    "Request not successful, got HTTP code {}",
+                    response.status().as_str()
+                )
+                .into(),
+            });
+        }
+
+        // Deserialize the response
+        let mut response: PollResponse =
+            response.json().await.map_err(|err| ClientError::Request {
+                endpoint: url.clone(),
+                source: Box::new(err),
+            })?;
+
+        response
+            .remove(request_key)
+            .and_then(|result| result.meta_data().clone())
+            .ok_or_else(|| ClientError::Response {
+                endpoint: url,
+                source: format!("No block found for request key {request_key}").into(),
+            })
+    }
+
+    /// `get_spv` fetches the payload of a block with the outputs of its
+    /// transactions to build the SPV proof of the output of a transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `header` - The header of the block containing the transaction.
+    /// * `request_key` - The request key of the transaction.
+    ///
+    /// # Returns
+    ///
+    /// The SPV proof of the output of the transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails, the response is not successful or properly
+    /// formatted, or if the transaction is not in the block.
+    pub(crate) async fn get_spv(
+        &self,
+        header: &KadenaHeaderRaw,
+        request_key: &str,
+    ) -> Result<Spv, ClientError> {
+        let chain = u32::from_le_bytes(*header.chain());
+
+        // Format the endpoint for the call
+        let url = format!(
+            "{}/payload/{}/outputs",
+            self.chain_url(chain as usize),
+            URL_SAFE_NO_PAD.encode(header.payload())
+        );
+
+        // Send the HTTP request
+        let response = self
+            .inner
+            .get(&url)
+            .header(ACCEPT, "application/json")
+            .send()
+            .await
+            .map_err(|err| ClientError::Request {
+                endpoint: url.clone(),
+                source: Box::new(err),
+            })?;
+
+        if !response.status().is_success() {
+            return Err(ClientError::Request {
+                endpoint: url,
+                source: format!(
+                    "Request not successful, got HTTP code {}",
+                    response.status().as_str()
+                )
+                .into(),
+            });
+        }
+
+        // Deserialize the response
+        let response: PayloadWithOutputsResponse =
+            response.json().await.map_err(|err| ClientError::Request {
+                endpoint: url.clone(),
+                source: Box::new(err),
+            })?;
+
+        response
+            .into_spv(chain, request_key)
+            .map_err(|err| ClientError::Response {
+                endpoint: url,
+                source: Box::new(err),
+            })
+    }
+
+    /// Get the base URL of the endpoints of a chain on the Chainweb node.
+    ///
+    /// # Arguments
+    ///
+    /// * `chain` - The chain.
+    ///
+    /// # Returns
+    ///
+    /// The base URL of the endpoints of the chain.
+    fn chain_url(&self, chain: usize) -> String {
+        format!(
+            "{}/chainweb/{CHAINWEB_API_VERSION}/{}/chain/{chain}",
+            self.chainweb_node_address,
+            self.chainweb_version.name()
+        )
+    }
 }
 
 /// `get_block_headers` makes an HTTP request to the Chainweb Node API
@@ -179,9 +413,10 @@ impl ChainwebClient {
 ///
 ///  # Arguments
 ///
+/// * `client` - The inner HTTP client.
+/// * `chain_url` - The base URL of the endpoints of the chain to get the headers for.
 /// * `target_block` - The target block to get the headers for.
 /// * `block_window` - The number of blocks to get before and after the target block.
-/// * `chain` - The chain to get the headers for.
 ///
 /// # Returns
 ///
@@ -192,16 +427,12 @@ impl ChainwebClient {
 /// Returns an error if the request fails or the response is not successful or properly formatted.
 pub(crate) async fn get_block_headers(
     client: Arc<Client>,
-    chainweb_node_address: Arc<String>,
+    chain_url: String,
     target_block: usize,
     block_window: usize,
-    chain: usize,
 ) -> Result<Vec<KadenaHeaderRaw>, ClientError> {
     // Format the endpoint for the call
-    let url = format!(
-        "{}/chainweb/{CHAINWEB_API_VERSION}/mainnet01/chain/{chain}/header",
-        chainweb_node_address
-    );
+    let url = format!("{chain_url}/header");
 
     // Send the HTTP request
     let response = client
@@ -1,6 +1,7 @@
 // Copyright (c) Argument Computer Corporation
 // SPDX-License-Identifier: APACHE-2.0
 
+use kadena_lc_core::crypto::hash::HashValue;
 use thiserror::Error;
 
 /// The error type for the `client` module.
@@ -20,4 +21,37 @@ pub enum ClientError {
     },
     #[error("Could not connect to the given address, {address}")]
     Connection { address: String },
+    #[error(
+        "First layer {actual} of the proven layers does not extend the verified tip {expected}"
+    )]
+    InvalidFirstLayer {
+        expected: HashValue,
+        actual: HashValue,
+    },
+    #[error("Proven layer at height {actual} is not above the verified tip at height {tip}")]
+    OutdatedLayer { tip: u64, actual: u64 },
+    #[error("Layer block headers around height {target_block} are invalid: {source}")]
+    InvalidLayerHeaders {
+        target_block: u64,
+        #[source]
+        source: Box<dyn std::error::Error + Sync + Send>,
+    },
+    #[error("Headers of the custom Chainweb version {name} can not be proven")]
+    UnprovableChainwebVersion { name: String },
+}
+
+impl ClientError {
+    /// Whether the error comes from data that will not change when requested again,
+    /// such as layer headers that do not follow the rules of the Chainweb network or
+    /// do not extend the verified tip, or from a Chainweb version that can not be proven. Other errors come from the communication with
+    /// the Chainweb node or the proof server, and can be retried.
+    pub const fn is_permanent(&self) -> bool {
+        matches!(
+            self,
+            Self::InvalidFirstLayer { .. }
+                | Self::OutdatedLayer { .. }
+                | Self::InvalidLayerHeaders { .. }
+                | Self::UnprovableChainwebVersion { .. }
+        )
+    }
 }
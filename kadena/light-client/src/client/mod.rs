@@ -15,7 +15,12 @@ use crate::client::chainweb::ChainwebClient;
 use crate::client::error::ClientError;
 use crate::client::proof_server::ProofServerClient;
 use crate::proofs::{ProofType, ProvingMode};
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use kadena_lc_core::types::header::compact::CompactLayerHeaders;
 use kadena_lc_core::types::header::layer::ChainwebLayerHeader;
+use kadena_lc_core::types::spv::Spv;
+use kadena_lc_core::types::version::ChainwebVersion;
 
 pub(crate) mod chainweb;
 pub mod error;
@@ -36,17 +41,40 @@ impl Client {
     ///
     /// * `chainweb_node_address: ` - The address of the Chainweb Node API.
     /// * `proof_server_address: ` - The address of the Proof Server.
+    /// * `chainweb_version: ` - The version of the Chainweb network served by the node.
+    ///   Custom versions can be fetched and verified, but not proven.
     ///
     /// # Returns
     ///
     /// A new `Client`.
-    pub fn new(chainweb_node_address: &str, proof_server_address: &str) -> Self {
+    pub fn new(
+        chainweb_node_address: &str,
+        proof_server_address: &str,
+        chainweb_version: ChainwebVersion,
+    ) -> Self {
         Self {
-            chainweb_client: ChainwebClient::new(chainweb_node_address),
+            chainweb_client: ChainwebClient::new(chainweb_node_address, chainweb_version),
             proof_server_client: ProofServerClient::new(proof_server_address),
         }
     }
 
+    /// Check that the headers of the Chainweb version of the client can be proven, as the
+    /// programs only know the versions with a built-in code.
+    ///
+    /// # Returns
+    ///
+    /// An error if the version is a custom one.
+    fn check_provable_version(&self) -> Result<(), ClientError> {
+        let chainweb_version = self.chainweb_client.chainweb_version();
+        if !chainweb_version.is_provable() {
+            return Err(ClientError::UnprovableChainwebVersion {
+                name: chainweb_version.name().to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
     /// Test the connection to all the endpoints.
     ///
     /// # Returns
@@ -61,6 +89,16 @@ impl Client {
         Ok(())
     }
 
+    /// Get the height of the latest layer available on every chain of
+    /// the Chainweb network.
+    ///
+    /// # Returns
+    ///
+    /// The height of the latest complete layer.
+    pub async fn get_latest_layer_height(&self) -> Result<u64, ClientError> {
+        self.chainweb_client.get_latest_layer_height().await
+    }
+
     /// Get the layer block headers according to the given block height
     /// and window.
     ///
@@ -82,12 +120,108 @@ impl Client {
             .await
     }
 
+    /// Get the layer block headers according to the given block height
+    /// and window, in their compact form. The headers are verified before
+    /// being compacted, so that they are not sent to the proof server if
+    /// they can not be proven.
+    ///
+    /// # Arguments
+    ///
+    /// * `target_block` - The target block height.
+    /// * `block_window` - The window of blocks to fetch.
+    ///
+    /// # Returns
+    ///
+    /// The compact layer block headers.
+    pub async fn get_compact_layer_headers(
+        &self,
+        target_block: usize,
+        block_window: usize,
+    ) -> Result<CompactLayerHeaders, ClientError> {
+        self.check_provable_version()?;
+
+        let layer_block_headers = self
+            .get_layer_block_headers(target_block, block_window)
+            .await?;
+
+        // Headers that can not be verified would make the proof fail, whenever they are fetched
+        ChainwebLayerHeader::verify_with_version(
+            &layer_block_headers,
+            self.chainweb_client.chainweb_version(),
+        )
+        .map_err(|err| ClientError::InvalidLayerHeaders {
+            target_block: target_block as u64,
+            source: Box::new(err),
+        })?;
+
+        CompactLayerHeaders::from_layer_headers(&layer_block_headers).map_err(|err| {
+            ClientError::Response {
+                endpoint: "get_compact_layer_headers".into(),
+                source: Box::new(err),
+            }
+        })
+    }
+
+    /// Get the SPV proof of the output of a transaction, along with the
+    /// layer block headers around the block containing it.
+    ///
+    /// # Arguments
+    ///
+    /// * `chain` - The chain of the transaction.
+    /// * `request_key` - The request key of the transaction.
+    /// * `block_window` - The window of blocks to fetch around the block of the transaction.
+    ///
+    /// # Returns
+    ///
+    /// The layer block headers, with the block of the transaction in the
+    /// target layer, and the SPV proof of the transaction output.
+    pub async fn get_spv(
+        &self,
+        chain: u32,
+        request_key: &str,
+        block_window: usize,
+    ) -> Result<(Vec<ChainwebLayerHeader>, Spv), ClientError> {
+        let block = self
+            .chainweb_client
+            .get_transaction_block(chain, request_key)
+            .await?;
+
+        let layer_block_headers = self
+            .get_layer_block_headers(*block.block_height() as usize, block_window)
+            .await?;
+
+        let header = layer_block_headers[block_window]
+            .chain_headers()
+            .get(chain as usize)
+            .ok_or_else(|| ClientError::Response {
+                endpoint: "get_spv".into(),
+                source: format!("No header found for chain {chain}").into(),
+            })?;
+
+        // The block of the transaction could have been orphaned since it was polled
+        if URL_SAFE_NO_PAD.encode(header.hash()) != *block.block_hash() {
+            return Err(ClientError::Response {
+                endpoint: "get_spv".into(),
+                source: format!(
+                    "Block {} of the transaction is not on the longest chain",
+                    block.block_hash()
+                )
+                .into(),
+            });
+        }
+
+        let spv = self.chainweb_client.get_spv(header, request_key).await?;
+
+        Ok((layer_block_headers, spv))
+    }
+
     /// Forwards a request to the proof server to prove the longest chain.
     ///
     /// # Arguments
     ///
     /// * `proving_mode` - The proving mode to use, either STARK or SNARK.
-    /// * `layer_block_headers` - The list of Chainweb layer block headers to prove.
+    /// * `compact_layer_headers` - The list of Chainweb layer block headers to prove, in
+    ///   their compact form.
     ///
     /// # Returns
     ///
@@ -95,10 +229,12 @@ impl Client {
     pub async fn prove_longest_chain(
         &self,
         proving_mode: ProvingMode,
-        layer_block_headers: Vec<ChainwebLayerHeader>,
+        compact_layer_headers: CompactLayerHeaders,
     ) -> Result<ProofType, ClientError> {
+        self.check_provable_version()?;
+
         self.proof_server_client
-            .prove_longest_chain(proving_mode, layer_block_headers)
+            .prove_longest_chain(proving_mode, compact_layer_headers)
             .await
     }
 
@@ -114,4 +250,44 @@ impl Client {
     pub async fn verify_longest_chain(&self, proof: ProofType) -> Result<bool, ClientError> {
         self.proof_server_client.verify_longest_chain(proof).await
     }
+
+    /// Forwards a request to the proof server to prove the inclusion of
+    /// a transaction output.
+    ///
+    /// # Arguments
+    ///
+    /// * `proving_mode` - The proving mode to use, either STARK or SNARK.
+    /// * `layer_block_headers` - The list of Chainweb layer block headers, with the block
+    ///   containing the transaction in the target layer.
+    /// * `spv` - The SPV proof of the transaction output.
+    ///
+    /// # Returns
+    ///
+    /// A proof of the inclusion of the transaction output.
+    pub async fn prove_spv(
+        &self,
+        proving_mode: ProvingMode,
+        layer_block_headers: Vec<ChainwebLayerHeader>,
+        spv: Spv,
+    ) -> Result<ProofType, ClientError> {
+        self.check_provable_version()?;
+
+        self.proof_server_client
+            .prove_spv(proving_mode, layer_block_headers, spv)
+            .await
+    }
+
+    /// Forwards a request to the proof server to verify the inclusion of
+    /// a transaction output.
+    ///
+    /// # Arguments
+    ///
+    /// * `proof` - The proof to verify.
+    ///
+    /// # Returns
+    ///
+    /// A boolean indicating whether the proof is valid.
+    pub async fn verify_spv(&self, proof: ProofType) -> Result<bool, ClientError> {
+        self.proof_server_client.verify_spv(proof).await
+    }
 }
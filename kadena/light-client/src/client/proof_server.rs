@@ -9,9 +9,12 @@
 use crate::client::error::ClientError;
 use crate::client::utils::test_connection;
 use crate::proofs::longest_chain::LongestChainIn;
+use crate::proofs::spv::SpvIn;
 use crate::proofs::{ProofType, ProvingMode};
 use crate::types::network::Request;
+use kadena_lc_core::types::header::compact::CompactLayerHeaders;
 use kadena_lc_core::types::header::layer::ChainwebLayerHeader;
+use kadena_lc_core::types::spv::Spv;
 use reqwest::header::CONTENT_TYPE;
 use reqwest::Client;
 
@@ -57,7 +60,8 @@ impl ProofServerClient {
     /// # Arguments
     ///
     /// * `proving_mode` - The proving mode to use, either STARK or SNARK.
-    /// * `layer_block_headers` - The list of Chainweb layer block headers to prove.
+    /// * `compact_layer_headers` - The list of Chainweb layer block headers to prove, in
+    ///   their compact form.
     ///
     /// # Returns
     ///
@@ -65,11 +69,11 @@ impl ProofServerClient {
     pub(crate) async fn prove_longest_chain(
         &self,
         proving_mode: ProvingMode,
-        layer_block_headers: Vec<ChainwebLayerHeader>,
+        compact_layer_headers: CompactLayerHeaders,
     ) -> Result<ProofType, ClientError> {
         let url = format!("http://{}/longest-chain/proof", self.address);
 
-        let inputs = LongestChainIn::new(layer_block_headers);
+        let inputs = LongestChainIn::new(compact_layer_headers);
         let request = Request::ProveLongestChain(Box::new((proving_mode, inputs)));
 
         let response = self
@@ -98,7 +102,7 @@ impl ProofServerClient {
     ///
     /// A boolean indicating whether the proof is valid.
     pub(crate) async fn verify_longest_chain(&self, proof: ProofType) -> Result<bool, ClientError> {
-        let url = format!("http://{}/committee/verify", self.address);
+        let url = format!("http://{}/longest-chain/verify", self.address);
 
         let request = Request::VerifyLongestChain(Box::new(proof));
 
@@ -115,6 +119,74 @@ impl ProofServerClient {
         Ok(response.first().unwrap_or(&0) == &1)
     }
 
+    /// Prove that a transaction output is included in a block of the
+    /// longest chain by invoking [`ChainwebLayerHeader::verify`] and
+    /// [`Spv::verify`].
+    ///
+    /// # Arguments
+    ///
+    /// * `proving_mode` - The proving mode to use, either STARK or SNARK.
+    /// * `layer_block_headers` - The list of Chainweb layer block headers, with the block
+    ///   containing the transaction in the target layer.
+    /// * `spv` - The SPV proof of the transaction output.
+    ///
+    /// # Returns
+    ///
+    /// A proof of the inclusion of the transaction output.
+    pub(crate) async fn prove_spv(
+        &self,
+        proving_mode: ProvingMode,
+        layer_block_headers: Vec<ChainwebLayerHeader>,
+        spv: Spv,
+    ) -> Result<ProofType, ClientError> {
+        let url = format!("http://{}/spv/proof", self.address);
+
+        let inputs = SpvIn::new(layer_block_headers, spv);
+        let request = Request::ProveSpv(Box::new((proving_mode, inputs)));
+
+        let response = self
+            .post_request(
+                &url,
+                request.to_bytes().map_err(|err| ClientError::Request {
+                    endpoint: "ProofServer::ProveSpv".into(),
+                    source: err.into(),
+                })?,
+            )
+            .await?;
+
+        ProofType::from_bytes(&response).map_err(|err| ClientError::Response {
+            endpoint: "ProofServer::ProveSpv".into(),
+            source: err.into(),
+        })
+    }
+
+    /// Verify a proof for the inclusion of a transaction output.
+    ///
+    /// # Arguments
+    ///
+    /// * `proof` - The proof to verify.
+    ///
+    /// # Returns
+    ///
+    /// A boolean indicating whether the proof is valid.
+    pub(crate) async fn verify_spv(&self, proof: ProofType) -> Result<bool, ClientError> {
+        let url = format!("http://{}/spv/verify", self.address);
+
+        let request = Request::VerifySpv(Box::new(proof));
+
+        let response = self
+            .post_request(
+                &url,
+                request.to_bytes().map_err(|err| ClientError::Request {
+                    endpoint: "ProofServer::VerifySpv".into(),
+                    source: err.into(),
+                })?,
+            )
+            .await?;
+
+        Ok(response.first().unwrap_or(&0) == &1)
+    }
+
     /// Send a POST request to the given URL with the given request body.
     ///
     /// # Arguments
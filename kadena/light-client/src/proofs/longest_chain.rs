@@ -13,7 +13,7 @@ use getset::CopyGetters;
 use kadena_lc_core::crypto::hash::HashValue;
 use kadena_lc_core::crypto::U256;
 use kadena_lc_core::types::error::TypesError;
-use kadena_lc_core::types::header::layer::ChainwebLayerHeader;
+use kadena_lc_core::types::header::compact::CompactLayerHeaders;
 use kadena_programs::LONGEST_CHAIN_PROGRAM;
 use sphinx_sdk::{
     ProverClient, SphinxProvingKey, SphinxPublicValues, SphinxStdin, SphinxVerifyingKey,
@@ -54,10 +54,11 @@ impl LongestChainProver {
     }
 }
 
-/// The input for the sync committee change proof.
+/// The input for the sync committee change proof. The layer block headers
+/// are kept in their compact form.
 #[derive(Debug, Eq, PartialEq)]
 pub struct LongestChainIn {
-    layer_block_headers: Vec<ChainwebLayerHeader>,
+    compact_layer_headers: CompactLayerHeaders,
 }
 
 impl LongestChainIn {
@@ -65,14 +66,14 @@ impl LongestChainIn {
     ///
     /// # Arguments
     ///
-    /// * `layer_block_headers` - The layer block headers.
+    /// * `compact_layer_headers` - The layer block headers, in their compact form.
     ///
     /// # Returns
     ///
     /// A new `CommitteeChangeIn`.
-    pub const fn new(layer_block_headers: Vec<ChainwebLayerHeader>) -> Self {
+    pub const fn new(compact_layer_headers: CompactLayerHeaders) -> Self {
         Self {
-            layer_block_headers,
+            compact_layer_headers,
         }
     }
 
@@ -82,13 +83,7 @@ impl LongestChainIn {
     ///
     /// A `Vec<u8>` containing the serialized `LongestChainIn` struct.
     pub fn to_bytes(&self) -> Vec<u8> {
-        let mut bytes = vec![];
-
-        bytes.extend_from_slice(&ChainwebLayerHeader::serialize_list(
-            &self.layer_block_headers,
-        ));
-
-        bytes
+        self.compact_layer_headers.to_bytes()
     }
 
     /// Deserialize a `LongestChainIn` struct from bytes.
@@ -102,7 +97,7 @@ impl LongestChainIn {
     /// A `Result` containing either the deserialized `LongestChainIn` struct or a `TypesError`.
     pub fn from_bytes(bytes: &[u8]) -> Result<Self, TypesError> {
         Ok(Self {
-            layer_block_headers: ChainwebLayerHeader::deserialize_list(bytes)?,
+            compact_layer_headers: CompactLayerHeaders::from_bytes(bytes)?,
         })
     }
 }
@@ -114,6 +109,7 @@ pub struct CommitteeChangeOut {
     first_layer_block_header_hash: HashValue,
     target_layer_block_header_hash: HashValue,
     confirmation_work: U256,
+    target_layer_weight: U256,
 }
 
 impl From<&mut SphinxPublicValues> for CommitteeChangeOut {
@@ -121,11 +117,13 @@ impl From<&mut SphinxPublicValues> for CommitteeChangeOut {
         let confirmation_work = U256::from_little_endian(&public_values.read::<[u8; 32]>());
         let first_layer_block_header_hash = HashValue::new(public_values.read::<[u8; 32]>());
         let target_layer_block_header_hash = HashValue::new(public_values.read::<[u8; 32]>());
+        let target_layer_weight = U256::from_little_endian(&public_values.read::<[u8; 32]>());
 
         Self {
             confirmation_work,
             first_layer_block_header_hash,
             target_layer_block_header_hash,
+            target_layer_weight,
         }
     }
 }
@@ -137,10 +135,9 @@ impl Prover for LongestChainProver {
     type StdOut = CommitteeChangeOut;
 
     fn generate_sphinx_stdin(&self, inputs: &Self::StdIn) -> Result<SphinxStdin, Self::Error> {
+        // The program reconstructs the headers following the base layer from their compact form
         let mut stdin = SphinxStdin::new();
-        stdin.write(&ChainwebLayerHeader::serialize_list(
-            &inputs.layer_block_headers,
-        ));
+        stdin.write(&inputs.compact_layer_headers.to_bytes());
         Ok(stdin)
     }
 
@@ -199,7 +196,8 @@ impl Prover for LongestChainProver {
 #[cfg(all(test, feature = "kadena"))]
 mod test {
     use super::*;
-    use kadena_lc_core::test_utils::get_layer_block_headers;
+    use kadena_lc_core::test_utils::{get_compact_layer_headers, get_layer_block_headers};
+    use kadena_lc_core::types::header::layer::ChainwebLayerHeader;
 
     #[test]
     fn test_execute_committee_change() {
@@ -208,7 +206,7 @@ mod test {
         let prover = LongestChainProver::new();
 
         let new_period_inputs = LongestChainIn {
-            layer_block_headers: headers.clone(),
+            compact_layer_headers: CompactLayerHeaders::from_layer_headers(&headers).unwrap(),
         };
 
         let new_period_output = prover.execute(&new_period_inputs).unwrap();
@@ -233,6 +231,10 @@ mod test {
                 .header_root()
                 .expect("Should have a header root"),
         );
+        assert_eq!(
+            new_period_output.target_layer_weight,
+            headers[headers.len() / 2].weight()
+        );
     }
 
     #[test]
@@ -240,12 +242,12 @@ mod test {
     fn test_prove_stark_committee_change() {
         use std::time::Instant;
 
-        let layer_block_headers = get_layer_block_headers();
+        let compact_layer_headers = get_compact_layer_headers();
 
         let prover = LongestChainProver::new();
 
         let new_period_inputs = LongestChainIn {
-            layer_block_headers,
+            compact_layer_headers,
         };
 
         println!("Starting STARK proving for sync committee change...");
@@ -262,12 +264,12 @@ mod test {
     fn test_prove_snark_committee_change() {
         use std::time::Instant;
 
-        let layer_block_headers = get_layer_block_headers();
+        let compact_layer_headers = get_compact_layer_headers();
 
         let prover = LongestChainProver::new();
 
         let new_period_inputs = LongestChainIn {
-            layer_block_headers,
+            compact_layer_headers,
         };
 
         println!("Starting SNARK proving for sync committee change...");
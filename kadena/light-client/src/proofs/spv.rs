@@ -0,0 +1,250 @@
+// Copyright (c) Argument Computer Corporation
+// SPDX-License-Identifier: Apache-2.0
+
+//! # SPV Prover module
+//!
+//! This module provides the prover implementation for the SPV proof. The prover is responsible
+//! for generating, executing, proving, and verifying proofs that a transaction output is
+//! included in a block of the Chainweb network confirmed by the longest chain.
+
+use crate::proofs::error::ProverError;
+use crate::proofs::{ProofType, Prover, ProvingMode};
+use anyhow::Result;
+use getset::{CopyGetters, Getters};
+use kadena_lc_core::crypto::hash::HashValue;
+use kadena_lc_core::crypto::U256;
+use kadena_lc_core::types::error::TypesError;
+use kadena_lc_core::types::header::layer::ChainwebLayerHeader;
+use kadena_lc_core::types::spv::Spv;
+use kadena_lc_core::types::utils::extract_fixed_bytes;
+use kadena_lc_core::types::U64_BYTES_LENGTH;
+use kadena_programs::SPV_PROGRAM;
+use sphinx_sdk::{
+    ProverClient, SphinxProvingKey, SphinxPublicValues, SphinxStdin, SphinxVerifyingKey,
+};
+
+/// The prover for the SPV proof.
+pub struct SpvProver {
+    client: ProverClient,
+    keys: (SphinxProvingKey, SphinxVerifyingKey),
+}
+
+impl Default for SpvProver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SpvProver {
+    /// Create a new `SpvProver`.
+    ///
+    /// # Returns
+    ///
+    /// A new `SpvProver`.
+    pub fn new() -> Self {
+        let client = ProverClient::new();
+        let keys = client.setup(SPV_PROGRAM);
+
+        Self { client, keys }
+    }
+
+    /// Gets a `SphinxVerifyingKey`.
+    ///
+    /// # Returns
+    ///
+    /// A `SphinxVerifyingKey` that can be used for verifying the SPV proof.
+    pub const fn get_vk(&self) -> &SphinxVerifyingKey {
+        &self.keys.1
+    }
+}
+
+/// The input for the SPV proof.
+#[derive(Debug, Clone, Eq, PartialEq, Getters)]
+#[getset(get = "pub")]
+pub struct SpvIn {
+    layer_block_headers: Vec<ChainwebLayerHeader>,
+    spv: Spv,
+}
+
+impl SpvIn {
+    /// Create a new `SpvIn`.
+    ///
+    /// # Arguments
+    ///
+    /// * `layer_block_headers` - The layer block headers, with the block containing the
+    ///   transaction in the target layer.
+    /// * `spv` - The SPV proof of the transaction output.
+    ///
+    /// # Returns
+    ///
+    /// A new `SpvIn`.
+    pub const fn new(layer_block_headers: Vec<ChainwebLayerHeader>, spv: Spv) -> Self {
+        Self {
+            layer_block_headers,
+            spv,
+        }
+    }
+
+    /// Serialize the `SpvIn` struct to bytes.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<u8>` containing the serialized `SpvIn` struct.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![];
+
+        let layer_block_headers = ChainwebLayerHeader::serialize_list(&self.layer_block_headers);
+        bytes.extend_from_slice(&(layer_block_headers.len() as u64).to_le_bytes());
+        bytes.extend_from_slice(&layer_block_headers);
+        bytes.extend_from_slice(&self.spv.to_bytes());
+
+        bytes
+    }
+
+    /// Deserialize a `SpvIn` struct from bytes.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The serialized bytes.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either the deserialized `SpvIn` struct or a `TypesError`.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, TypesError> {
+        let (cursor, layer_block_headers_len) =
+            extract_fixed_bytes::<U64_BYTES_LENGTH>("SpvIn", bytes, 0)?;
+        let layer_block_headers_len = u64::from_le_bytes(layer_block_headers_len) as usize;
+
+        if bytes.len() < cursor + layer_block_headers_len {
+            return Err(TypesError::UnderLength {
+                structure: "SpvIn".into(),
+                minimum: cursor + layer_block_headers_len,
+                actual: bytes.len(),
+            });
+        }
+
+        Ok(Self {
+            layer_block_headers: ChainwebLayerHeader::deserialize_list(
+                &bytes[cursor..cursor + layer_block_headers_len],
+            )?,
+            spv: Spv::from_bytes(&bytes[cursor + layer_block_headers_len..])?,
+        })
+    }
+}
+
+/// The output for the SPV proof.
+#[derive(Debug, Clone, Copy, CopyGetters)]
+#[getset(get_copy = "pub")]
+pub struct SpvOut {
+    first_layer_block_header_hash: HashValue,
+    target_layer_block_header_hash: HashValue,
+    confirmation_work: U256,
+    chain: u32,
+    subject_hash: HashValue,
+}
+
+impl From<&mut SphinxPublicValues> for SpvOut {
+    fn from(public_values: &mut SphinxPublicValues) -> Self {
+        let confirmation_work = U256::from_little_endian(&public_values.read::<[u8; 32]>());
+        let first_layer_block_header_hash = HashValue::new(public_values.read::<[u8; 32]>());
+        let target_layer_block_header_hash = HashValue::new(public_values.read::<[u8; 32]>());
+        let chain = public_values.read::<u32>();
+        let subject_hash = HashValue::new(public_values.read::<[u8; 32]>());
+
+        Self {
+            confirmation_work,
+            first_layer_block_header_hash,
+            target_layer_block_header_hash,
+            chain,
+            subject_hash,
+        }
+    }
+}
+
+impl Prover for SpvProver {
+    const PROGRAM: &'static [u8] = SPV_PROGRAM;
+    type Error = ProverError;
+    type StdIn = SpvIn;
+    type StdOut = SpvOut;
+
+    fn generate_sphinx_stdin(&self, inputs: &Self::StdIn) -> Result<SphinxStdin, Self::Error> {
+        let mut stdin = SphinxStdin::new();
+        stdin.write(&ChainwebLayerHeader::serialize_list(
+            &inputs.layer_block_headers,
+        ));
+        stdin.write(&inputs.spv.to_bytes());
+        Ok(stdin)
+    }
+
+    fn execute(&self, inputs: &Self::StdIn) -> Result<Self::StdOut, Self::Error> {
+        sphinx_sdk::utils::setup_logger();
+
+        let stdin = self.generate_sphinx_stdin(inputs)?;
+
+        let (mut public_values, _) = self
+            .client
+            .execute(Self::PROGRAM, stdin)
+            .run()
+            .map_err(|err| ProverError::Execution { source: err.into() })?;
+
+        Ok(SpvOut::from(&mut public_values))
+    }
+
+    fn prove(&self, inputs: &Self::StdIn, mode: ProvingMode) -> Result<ProofType, Self::Error> {
+        let stdin = self.generate_sphinx_stdin(inputs)?;
+
+        match mode {
+            ProvingMode::STARK => self
+                .client
+                .prove(&self.keys.0, stdin)
+                .run()
+                .map_err(|err| ProverError::Proving {
+                    proof_type: mode.into(),
+                    source: err.into(),
+                })
+                .map(ProofType::STARK),
+            ProvingMode::SNARK => self
+                .client
+                .prove(&self.keys.0, stdin)
+                .plonk()
+                .run()
+                .map_err(|err| ProverError::Proving {
+                    proof_type: mode.into(),
+                    source: err.into(),
+                })
+                .map(ProofType::SNARK),
+        }
+    }
+
+    fn verify(&self, proof: &ProofType) -> Result<(), Self::Error> {
+        let vk = &self.keys.1;
+
+        match proof {
+            ProofType::STARK(proof) | ProofType::SNARK(proof) => self
+                .client
+                .verify(proof, vk)
+                .map_err(|err| ProverError::Verification { source: err.into() }),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "kadena"))]
+mod test {
+    use super::*;
+    use kadena_lc_core::test_utils::get_layer_block_headers;
+
+    #[test]
+    fn test_bytes_conversion_spv_in() {
+        let outputs = vec![
+            b"{\"reqKey\":\"0\"}".to_vec(),
+            b"{\"reqKey\":\"1\"}".to_vec(),
+        ];
+        let spv =
+            Spv::from_block_outputs(0, HashValue::new([1; 32]), b"coinbase", &outputs, 1).unwrap();
+
+        let spv_in = SpvIn::new(get_layer_block_headers(), spv);
+
+        assert_eq!(SpvIn::from_bytes(&spv_in.to_bytes()).unwrap(), spv_in);
+        assert!(SpvIn::from_bytes(&spv_in.to_bytes()[..100]).is_err());
+    }
+}
@@ -1,9 +1,16 @@
 // Copyright (c) Argument Computer Corporation
 // SPDX-License-Identifier: Apache-2.0
 
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use getset::Getters;
+use kadena_lc_core::crypto::hash::HashValue;
+use kadena_lc_core::deserialization_error;
 use kadena_lc_core::types::error::TypesError;
 use kadena_lc_core::types::header::chain::KadenaHeaderRaw;
+use kadena_lc_core::types::spv::Spv;
 use serde::Deserialize;
+use std::collections::HashMap;
 
 /// Response received while querying block headers from a Chainweb
 /// node.
@@ -25,3 +32,131 @@ impl TryInto<Vec<KadenaHeaderRaw>> for BlockHeaderResponse {
             .collect()
     }
 }
+
+/// Height and hash of the latest block of a chain in a cut.
+#[derive(Clone, Debug, Deserialize, Getters)]
+#[getset(get = "pub")]
+pub struct CutHashResponse {
+    height: u64,
+    hash: String,
+}
+
+/// Response received while querying the current cut from a Chainweb
+/// node, with the latest block of each chain indexed by chain.
+#[derive(Clone, Debug, Deserialize, Getters)]
+#[getset(get = "pub")]
+pub struct CutResponse {
+    hashes: HashMap<String, CutHashResponse>,
+}
+
+impl CutResponse {
+    /// Get the height of the latest layer for which every chain of the
+    /// cut has a block.
+    ///
+    /// # Returns
+    ///
+    /// The height of the latest complete layer, or `None` if the cut is empty.
+    pub fn layer_height(&self) -> Option<u64> {
+        self.hashes.values().map(|hash| hash.height).min()
+    }
+}
+
+/// Metadata of the block containing a transaction, received while
+/// polling the result of a transaction from a Chainweb node.
+#[derive(Clone, Debug, Deserialize, Getters)]
+#[serde(rename_all = "camelCase")]
+#[getset(get = "pub")]
+pub struct PollMetaData {
+    block_height: u64,
+    block_hash: String,
+}
+
+/// Result of a transaction, received while polling from a Chainweb
+/// node.
+#[derive(Clone, Debug, Deserialize, Getters)]
+#[serde(rename_all = "camelCase")]
+#[getset(get = "pub")]
+pub struct PollResult {
+    meta_data: Option<PollMetaData>,
+}
+
+/// Response received while polling the results of transactions from a
+/// Chainweb node, indexed by request key.
+pub type PollResponse = HashMap<String, PollResult>;
+
+/// Transaction output, only decoded as far as needed to identify its
+/// transaction.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TransactionOutputResponse {
+    req_key: String,
+}
+
+/// Response received while querying the payload of a block with the
+/// outputs of its transactions from a Chainweb node.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct PayloadWithOutputsResponse {
+    transactions: Vec<(String, String)>,
+    transactions_hash: String,
+    outputs_hash: String,
+    payload_hash: String,
+    coinbase: String,
+}
+
+impl PayloadWithOutputsResponse {
+    /// Build the SPV proof of the output of a transaction in the payload.
+    ///
+    /// # Arguments
+    ///
+    /// * `chain` - The chain of the block of the payload.
+    /// * `request_key` - The request key of the transaction.
+    ///
+    /// # Returns
+    ///
+    /// The SPV proof of the output of the transaction.
+    pub fn into_spv(self, chain: u32, request_key: &str) -> Result<Spv, TypesError> {
+        let decode = |value: &str| {
+            URL_SAFE_NO_PAD
+                .decode(value)
+                .map_err(|err| deserialization_error!("PayloadWithOutputsResponse", err))
+        };
+
+        let outputs = self
+            .transactions
+            .iter()
+            .map(|(_, output)| decode(output))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let index = outputs
+            .iter()
+            .map(|output| serde_json::from_slice::<TransactionOutputResponse>(output))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|err| deserialization_error!("PayloadWithOutputsResponse", err))?
+            .iter()
+            .position(|output| output.req_key == request_key)
+            .ok_or_else(|| {
+                deserialization_error!(
+                    "PayloadWithOutputsResponse",
+                    format!("No output for request key {request_key}")
+                )
+            })?;
+
+        let transactions_hash = HashValue::from_slice(decode(&self.transactions_hash)?)
+            .map_err(|err| deserialization_error!("PayloadWithOutputsResponse", err))?;
+
+        Spv::from_block_outputs(
+            chain,
+            transactions_hash,
+            &decode(&self.coinbase)?,
+            &outputs,
+            index,
+        )
+        .map_err(|err| TypesError::ConversionError {
+            from: "PayloadWithOutputsResponse".into(),
+            to: "Spv".into(),
+            source: err.into(),
+        })
+    }
+}
@@ -2,5 +2,5 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod chainweb;
-pub mod compact;
 pub mod network;
+pub mod tip;
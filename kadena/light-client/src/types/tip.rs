@@ -0,0 +1,116 @@
+// Copyright (c) Argument Computer Corporation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::client::error::ClientError;
+use getset::CopyGetters;
+use kadena_lc_core::crypto::hash::HashValue;
+use kadena_lc_core::crypto::U256;
+
+/// The latest layer of the Chainweb network verified by the light client,
+/// along with the work confirming it.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, CopyGetters)]
+#[getset(get_copy = "pub")]
+pub struct VerifiedTip {
+    /// The height of the layer.
+    height: u64,
+    /// The hash of the layer.
+    layer_hash: HashValue,
+    /// The work produced on top of the layer in its proven window.
+    confirmation_work: U256,
+    /// The sum of the confirmation work of all the layers verified since
+    /// the light client started. With a constant block window, it is the
+    /// work produced from the first verified layer up to the confirmations
+    /// of the tip.
+    total_work: U256,
+}
+
+impl VerifiedTip {
+    /// Create a new `VerifiedTip`, as the first layer verified by the light client.
+    ///
+    /// # Arguments
+    ///
+    /// * `height` - The height of the layer.
+    /// * `layer_hash` - The hash of the layer.
+    /// * `confirmation_work` - The work produced on top of the layer in its proven window.
+    ///
+    /// # Returns
+    ///
+    /// A new `VerifiedTip`.
+    pub const fn new(height: u64, layer_hash: HashValue, confirmation_work: U256) -> Self {
+        Self {
+            height,
+            layer_hash,
+            confirmation_work,
+            total_work: confirmation_work,
+        }
+    }
+
+    /// Move the tip to a newly proven layer, if the window proving it
+    /// starts at the current tip.
+    ///
+    /// # Arguments
+    ///
+    /// * `first_layer_hash` - The hash of the first layer of the proven window.
+    /// * `next` - The target layer of the proven window.
+    ///
+    /// # Returns
+    ///
+    /// The new tip, or an error if the proven window does not extend the current tip.
+    pub fn extend(&self, first_layer_hash: HashValue, next: Self) -> Result<Self, ClientError> {
+        if first_layer_hash != self.layer_hash {
+            return Err(ClientError::InvalidFirstLayer {
+                expected: self.layer_hash,
+                actual: first_layer_hash,
+            });
+        }
+
+        if next.height <= self.height {
+            return Err(ClientError::OutdatedLayer {
+                tip: self.height,
+                actual: next.height,
+            });
+        }
+
+        Ok(Self {
+            height: next.height,
+            layer_hash: next.layer_hash,
+            confirmation_work: next.confirmation_work,
+            total_work: self.total_work.saturating_add(next.confirmation_work),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_extend_verified_tip() {
+        let tip = VerifiedTip::new(100, HashValue::new([1; 32]), U256::from(10));
+        let next = VerifiedTip::new(103, HashValue::new([2; 32]), U256::from(12));
+
+        let extended = tip.extend(HashValue::new([1; 32]), next).unwrap();
+        assert_eq!(extended.height(), 103);
+        assert_eq!(extended.layer_hash(), HashValue::new([2; 32]));
+        assert_eq!(extended.confirmation_work(), U256::from(12));
+        assert_eq!(extended.total_work(), U256::from(22));
+
+        // The proven window must start at the tip
+        assert!(matches!(
+            tip.extend(HashValue::new([3; 32]), next),
+            Err(ClientError::InvalidFirstLayer { .. })
+        ));
+
+        // The tip can only move forward
+        assert!(matches!(
+            extended.extend(HashValue::new([2; 32]), tip),
+            Err(ClientError::OutdatedLayer { .. })
+        ));
+
+        // Retrying the same window would fail the same way
+        assert!(tip
+            .extend(HashValue::new([3; 32]), next)
+            .unwrap_err()
+            .is_permanent());
+    }
+}
@@ -3,6 +3,7 @@
 
 #![no_main]
 
+use kadena_lc_core::types::header::compact::CompactLayerHeaders;
 use kadena_lc_core::types::header::layer::ChainwebLayerHeader;
 
 sphinx_zkvm::entrypoint!(main);
@@ -18,13 +19,25 @@ pub fn main() {
     sphinx_zkvm::precompiles::unconstrained! {
                 println!("cycle-tracker-start: deserialize_inputs");
     }
-    let layer_headers = ChainwebLayerHeader::deserialize_list(&layer_headers_bytes)
-        .expect("Failed to deserialize layer headers");
+    let compact_layer_headers = CompactLayerHeaders::from_bytes(&layer_headers_bytes)
+        .expect("Failed to deserialize compact layer headers");
     sphinx_zkvm::precompiles::unconstrained! {
                 println!("cycle-tracker-end: deserialize_inputs");
     }
 
-    let (first_layer_hash, target_layer_hash, confirmation_work) =
+    // Reconstruct the full headers from the compact ones, checking the hashes of the last layer
+    sphinx_zkvm::precompiles::unconstrained! {
+                println!("cycle-tracker-start: reconstruct_headers");
+    }
+    let layer_headers = compact_layer_headers
+        .to_layer_headers()
+        .expect("Failed to reconstruct layer headers");
+    sphinx_zkvm::precompiles::unconstrained! {
+                println!("cycle-tracker-end: reconstruct_headers");
+    }
+
+    // Verify the headers, including the difficulty adjustment of their targets
+    let (first_layer_hash, target_layer_hash, confirmation_work, target_layer_weight) =
         ChainwebLayerHeader::verify(&layer_headers).expect("Failed to verify layer headers");
 
     let mut confirmation_work_buf: [u8; 32] = [0; 32];
@@ -37,4 +50,9 @@ pub fn main() {
 
     // Target block hash
     sphinx_zkvm::io::commit(target_layer_hash.as_ref());
+
+    let mut target_layer_weight_buf: [u8; 32] = [0; 32];
+    target_layer_weight.to_little_endian(&mut target_layer_weight_buf);
+    // Target block total weight
+    sphinx_zkvm::io::commit(&target_layer_weight_buf);
 }
@@ -0,0 +1,61 @@
+// Copyright (c) Argument Computer Corporation
+// SPDX-License-Identifier: Apache-2.0
+
+#![no_main]
+
+use kadena_lc_core::types::header::layer::ChainwebLayerHeader;
+use kadena_lc_core::types::spv::Spv;
+
+sphinx_zkvm::entrypoint!(main);
+
+pub fn main() {
+    sphinx_zkvm::precompiles::unconstrained! {
+                println!("cycle-tracker-start: read_inputs");
+    }
+    let layer_headers_bytes = sphinx_zkvm::io::read::<Vec<u8>>();
+    let spv_bytes = sphinx_zkvm::io::read::<Vec<u8>>();
+    sphinx_zkvm::precompiles::unconstrained! {
+                println!("cycle-tracker-end: read_inputs");
+    }
+    sphinx_zkvm::precompiles::unconstrained! {
+                println!("cycle-tracker-start: deserialize_inputs");
+    }
+    let layer_headers = ChainwebLayerHeader::deserialize_list(&layer_headers_bytes)
+        .expect("Failed to deserialize layer headers");
+    let spv = Spv::from_bytes(&spv_bytes).expect("Failed to deserialize SPV");
+    sphinx_zkvm::precompiles::unconstrained! {
+                println!("cycle-tracker-end: deserialize_inputs");
+    }
+
+    // Verify the headers, including the difficulty adjustment of their targets
+    let (first_layer_hash, target_layer_hash, confirmation_work, _) =
+        ChainwebLayerHeader::verify(&layer_headers).expect("Failed to verify layer headers");
+
+    // Verify the transaction output is included in the block of the target layer
+    sphinx_zkvm::precompiles::unconstrained! {
+                println!("cycle-tracker-start: verify_spv");
+    }
+    let subject_hash = spv
+        .verify(&layer_headers[layer_headers.len() / 2])
+        .expect("Failed to verify SPV");
+    sphinx_zkvm::precompiles::unconstrained! {
+                println!("cycle-tracker-end: verify_spv");
+    }
+
+    let mut confirmation_work_buf: [u8; 32] = [0; 32];
+    confirmation_work.to_little_endian(&mut confirmation_work_buf);
+    // Confirmation cumulative work as an output
+    sphinx_zkvm::io::commit(&confirmation_work_buf);
+
+    // Base block hash as an output
+    sphinx_zkvm::io::commit(first_layer_hash.as_ref());
+
+    // Target block hash
+    sphinx_zkvm::io::commit(target_layer_hash.as_ref());
+
+    // Chain of the block containing the transaction
+    sphinx_zkvm::io::commit(&spv.chain());
+
+    // Hash of the transaction output
+    sphinx_zkvm::io::commit(subject_hash.as_ref());
+}